@@ -6,9 +6,23 @@
 //!
 
 pub(crate) mod r#candidate_account;
+pub(crate) mod r#contest;
+pub(crate) mod r#irv_result;
+pub(crate) mod r#nft_receipt;
 pub(crate) mod r#poll_account;
+pub(crate) mod r#poll_counter;
+pub(crate) mod r#poll_result;
+pub(crate) mod r#ranked_ballot;
+pub(crate) mod r#voter_pass;
 pub(crate) mod r#voter_receipt;
 
 pub use self::r#candidate_account::*;
+pub use self::r#contest::*;
+pub use self::r#irv_result::*;
+pub use self::r#nft_receipt::*;
 pub use self::r#poll_account::*;
+pub use self::r#poll_counter::*;
+pub use self::r#poll_result::*;
+pub use self::r#ranked_ballot::*;
+pub use self::r#voter_pass::*;
 pub use self::r#voter_receipt::*;
@@ -5,6 +5,9 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::types::PollAdmin;
+use crate::types::PollOptions;
+use crate::types::PollStatus;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_pubkey::Pubkey;
@@ -22,12 +25,24 @@ pub struct PollAccount {
     pub description: String,
     pub start_time: u64,
     pub end_time: u64,
-    pub candidate_count: u8,
+    pub candidate_count: u32,
+    pub legacy_candidate_count: u8,
+    pub total_votes: u64,
+    pub options: PollOptions,
+    pub status: PollStatus,
+    pub tallied_count: u32,
+    pub closed_candidate_count: u32,
+    pub escrowed_amount: u64,
+    pub commit_count: u64,
+    pub reveal_count: u64,
+    pub contest_count: u32,
+    pub contest_candidate_count: u32,
     #[cfg_attr(
         feature = "serde",
-        serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
+        serde(with = "serde_with::As::<Option<serde_with::DisplayFromStr>>")
     )]
-    pub candidates: Vec<Pubkey>,
+    pub pending_authority: Option<Pubkey>,
+    pub admins: Vec<PollAdmin>,
 }
 
 impl PollAccount {
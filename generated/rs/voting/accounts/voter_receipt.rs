@@ -23,11 +23,26 @@ pub struct VoterReceipt {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub poll: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub candidate: Pubkey,
+    pub voted_at: i64,
+    pub slot: u64,
+    pub weight: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
+    )]
+    pub selections: Vec<Pubkey>,
+    pub allocations: Vec<u64>,
+    pub credits_spent: u64,
+    pub commitment: Option<[u8; 32]>,
+    pub revealed: bool,
 }
 
 impl VoterReceipt {
-    pub const LEN: usize = 72;
-
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
         let mut data = data;
@@ -0,0 +1,237 @@
+//! 白名单 merkle 树，哈希规则必须与 `programs/voting` 中 `vote` 的校验保持一致：
+//!
+//! - 叶子：`sha256(0x00 || voter || weight)`，`weight` 为小端序 u64
+//! - 父节点：`sha256(0x01 || 较小的子节点 || 较大的子节点)`
+//!
+//! 每层节点数为奇数时，最后一个节点直接提升到上一层。
+
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use solana_program::hash::hashv;
+use solana_pubkey::Pubkey;
+
+use crate::{
+    instructions::{CastBallotBuilder, CommitVoteBuilder, VoteBuilder},
+    types::AllowlistProof,
+};
+
+/// 白名单中的一个投票者及其票数
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AllowlistEntry {
+    pub voter: Pubkey,
+    pub weight: u64,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AllowlistError {
+    #[error("line {0}: invalid voter address")]
+    InvalidVoter(usize),
+    #[error("line {0}: weight must be a positive integer")]
+    InvalidWeight(usize),
+    #[error("voter {0} appears more than once")]
+    DuplicateVoter(Pubkey),
+    #[error("allowlist is empty")]
+    Empty,
+    #[error("voter {0} is not on the allowlist")]
+    NotOnAllowlist(Pubkey),
+}
+
+/// 解析 `voter[,weight]` 格式的 CSV，省略 weight 时为 1；跳过空行、`#` 注释和 `voter` 表头
+pub fn parse_allowlist_csv(csv: &str) -> Result<Vec<AllowlistEntry>, AllowlistError> {
+    let mut entries = Vec::new();
+    let mut seen = HashSet::new();
+    for (index, line) in csv.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with("voter") {
+            continue;
+        }
+        let mut columns = line.split(',').map(str::trim);
+        let voter = columns
+            .next()
+            .and_then(|voter| Pubkey::from_str(voter).ok())
+            .ok_or(AllowlistError::InvalidVoter(index + 1))?;
+        let weight = match columns.next() {
+            Some(weight) => weight
+                .parse::<u64>()
+                .ok()
+                .filter(|weight| *weight > 0)
+                .ok_or(AllowlistError::InvalidWeight(index + 1))?,
+            None => 1,
+        };
+        if !seen.insert(voter) {
+            return Err(AllowlistError::DuplicateVoter(voter));
+        }
+        entries.push(AllowlistEntry { voter, weight });
+    }
+    Ok(entries)
+}
+
+/// 叶子哈希：`sha256(0x00 || voter || weight)`
+pub fn allowlist_leaf(voter: &Pubkey, weight: u64) -> [u8; 32] {
+    hashv(&[&[0], voter.as_ref(), &weight.to_le_bytes()]).to_bytes()
+}
+
+fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[&[1], left, right]).to_bytes()
+}
+
+/// 由白名单构建的 merkle 树，`root()` 即 `PollOptions::allowlist_root`
+#[derive(Clone, Debug)]
+pub struct AllowlistTree {
+    entries: Vec<AllowlistEntry>,
+    // layers[0] 为叶子，最后一层只有根
+    layers: Vec<Vec<[u8; 32]>>,
+}
+
+impl AllowlistTree {
+    pub fn new(entries: Vec<AllowlistEntry>) -> Result<Self, AllowlistError> {
+        if entries.is_empty() {
+            return Err(AllowlistError::Empty);
+        }
+        let mut layers = vec![entries
+            .iter()
+            .map(|entry| allowlist_leaf(&entry.voter, entry.weight))
+            .collect::<Vec<_>>()];
+        while layers.last().is_some_and(|layer| layer.len() > 1) {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_pair(left, right),
+                    [node] => *node,
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(next);
+        }
+        Ok(Self { entries, layers })
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.layers.last().unwrap()[0]
+    }
+
+    pub fn entries(&self) -> &[AllowlistEntry] {
+        &self.entries
+    }
+
+    /// `voter` 的票数及从叶子到根的兄弟节点；不在白名单中时返回 `None`
+    pub fn proof(&self, voter: &Pubkey) -> Option<AllowlistProof> {
+        let mut index = self
+            .entries
+            .iter()
+            .position(|entry| entry.voter == *voter)?;
+        let weight = self.entries[index].weight;
+        let mut proof = Vec::new();
+        for layer in &self.layers[..self.layers.len() - 1] {
+            if let Some(sibling) = layer.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            index /= 2;
+        }
+        Some(AllowlistProof { weight, proof })
+    }
+
+    // 供各 builder 使用：不在白名单中时返回错误而不是 `None`
+    fn require_proof(&self, voter: &Pubkey) -> Result<AllowlistProof, AllowlistError> {
+        self.proof(voter)
+            .ok_or(AllowlistError::NotOnAllowlist(*voter))
+    }
+}
+
+impl VoteBuilder {
+    /// 附加 `voter` 在白名单中的证明，用于设置了 `allowlist_root` 的投票活动
+    pub fn allowlist(
+        &mut self,
+        tree: &AllowlistTree,
+        voter: &Pubkey,
+    ) -> Result<&mut Self, AllowlistError> {
+        Ok(self.allowlist_proof(tree.require_proof(voter)?))
+    }
+}
+
+impl CommitVoteBuilder {
+    /// 附加 `voter` 在白名单中的证明，用于设置了 `allowlist_root` 的秘密投票
+    pub fn allowlist(
+        &mut self,
+        tree: &AllowlistTree,
+        voter: &Pubkey,
+    ) -> Result<&mut Self, AllowlistError> {
+        Ok(self.allowlist_proof(tree.require_proof(voter)?))
+    }
+}
+
+impl CastBallotBuilder {
+    /// 附加 `voter` 在白名单中的证明，用于设置了 `allowlist_root` 的多分项投票
+    pub fn allowlist(
+        &mut self,
+        tree: &AllowlistTree,
+        voter: &Pubkey,
+    ) -> Result<&mut Self, AllowlistError> {
+        Ok(self.allowlist_proof(tree.require_proof(voter)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 与 `programs/voting` 中 `verify_allowlist_proof` 相同的校验
+    fn verify_allowlist_proof(root: [u8; 32], leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+        let computed = proof.iter().fold(leaf, |node, sibling| {
+            let (left, right) = if node <= *sibling {
+                (node, *sibling)
+            } else {
+                (*sibling, node)
+            };
+            hashv(&[&[1], &left, &right]).to_bytes()
+        });
+        computed == root
+    }
+
+    fn entries(count: u8) -> Vec<AllowlistEntry> {
+        (1..=count)
+            .map(|index| AllowlistEntry {
+                voter: Pubkey::new_from_array([index; 32]),
+                weight: index as u64,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn every_proof_verifies_against_the_root() {
+        // 奇数个叶子时最后一个节点直接提升，需覆盖奇偶两种情况
+        for count in 1..=9 {
+            let tree = AllowlistTree::new(entries(count)).unwrap();
+            for entry in tree.entries() {
+                let proof = tree.proof(&entry.voter).unwrap();
+                assert_eq!(proof.weight, entry.weight);
+                let leaf = allowlist_leaf(&entry.voter, proof.weight);
+                assert!(
+                    verify_allowlist_proof(tree.root(), leaf, &proof.proof),
+                    "entry {} of {count}",
+                    entry.weight
+                );
+                // 票数不符时证明失效
+                let forged = allowlist_leaf(&entry.voter, proof.weight + 1);
+                assert!(!verify_allowlist_proof(tree.root(), forged, &proof.proof));
+            }
+        }
+    }
+
+    #[test]
+    fn builders_reject_voters_not_on_the_allowlist() {
+        let tree = AllowlistTree::new(entries(3)).unwrap();
+        let outsider = Pubkey::new_from_array([0xff; 32]);
+        assert!(matches!(
+            VoteBuilder::new().allowlist(&tree, &outsider),
+            Err(AllowlistError::NotOnAllowlist(voter)) if voter == outsider
+        ));
+        assert!(VoteBuilder::new()
+            .allowlist(&tree, &entries(3)[0].voter)
+            .is_ok());
+    }
+}
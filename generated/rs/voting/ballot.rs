@@ -0,0 +1,53 @@
+//! 复选（approval）选票、多分项选票和秘密投票的辅助方法
+//!
+//! `vote` 的 `candidate_account` 是第一个选择，其余选择的候选人账户
+//! 按顺序作为可写的 remaining accounts 传入。
+//!
+//! `cast_ballot` 按 `choices` 的顺序依次传入每个分项账户及其所选的候选人账户。
+//!
+//! 秘密投票的承诺必须与 `programs/voting` 中 `reveal_vote` 的校验保持一致：
+//! `sha256(candidate_index || salt)`，`candidate_index` 为小端序 u32。
+
+use solana_instruction::AccountMeta;
+use solana_program::hash::hashv;
+use solana_pubkey::Pubkey;
+
+use crate::{
+    instructions::{CastBallotBuilder, VoteBuilder},
+    pdas::{find_contest_candidate_pda, find_contest_pda},
+    types::ContestChoice,
+};
+
+impl VoteBuilder {
+    /// 附加复选选票中除 `candidate_account` 之外的其他候选人账户
+    pub fn approvals(&mut self, candidates: &[Pubkey]) -> &mut Self {
+        let accounts: Vec<AccountMeta> = candidates
+            .iter()
+            .map(|candidate| AccountMeta::new(*candidate, false))
+            .collect();
+        self.add_remaining_accounts(&accounts)
+    }
+}
+
+impl CastBallotBuilder {
+    /// 设置选票中各分项的选择，并按顺序附加分项账户及所选的候选人账户
+    ///
+    /// `choices` 必须按 `contest_index` 升序排列，每个分项内的候选人索引也须升序。
+    pub fn contests(&mut self, poll: &Pubkey, choices: Vec<ContestChoice>) -> &mut Self {
+        let mut accounts = Vec::new();
+        for choice in &choices {
+            let (contest, _) = find_contest_pda(poll, choice.contest_index);
+            accounts.push(AccountMeta::new(contest, false));
+            for &candidate_index in &choice.candidate_indices {
+                let (candidate, _) = find_contest_candidate_pda(&contest, candidate_index);
+                accounts.push(AccountMeta::new(candidate, false));
+            }
+        }
+        self.add_remaining_accounts(&accounts).choices(choices)
+    }
+}
+
+/// 计算 `commit_vote` 提交的承诺，揭示时需要传入相同的 `candidate_index` 和 `salt`
+pub fn vote_commitment(candidate_index: u32, salt: &[u8; 32]) -> [u8; 32] {
+    hashv(&[&candidate_index.to_le_bytes(), salt]).to_bytes()
+}
@@ -25,6 +25,204 @@ pub enum VotingError {
     /// 6004 - This candidate is not valid for this poll.
     #[error("This candidate is not valid for this poll.")]
     InvalidCandidateForPoll = 0x1774,
+    /// 6005 - Poll has not ended yet
+    #[error("Poll has not ended yet")]
+    PollNotEnded = 0x1775,
+    /// 6006 - Candidate accounts do not match the poll's candidates.
+    #[error("Candidate accounts do not match the poll's candidates.")]
+    InvalidCandidateAccounts = 0x1776,
+    /// 6007 - Arithmetic overflow.
+    #[error("Arithmetic overflow.")]
+    Overflow = 0x1777,
+    /// 6008 - Poll account uses a legacy layout, run migrate_poll first.
+    #[error("Poll account uses a legacy layout, run migrate_poll first.")]
+    PollMigrationRequired = 0x1778,
+    /// 6009 - Poll account is already migrated.
+    #[error("Poll account is already migrated.")]
+    PollAlreadyMigrated = 0x1779,
+    /// 6010 - Poll is already finalized.
+    #[error("Poll is already finalized.")]
+    PollAlreadyFinalized = 0x177a,
+    /// 6011 - Poll is finalized and no longer accepts votes.
+    #[error("Poll is finalized and no longer accepts votes.")]
+    PollFinalized = 0x177b,
+    /// 6012 - Poll has already started, only the end time can be extended.
+    #[error("Poll has already started, only the end time can be extended.")]
+    PollAlreadyStarted = 0x177c,
+    /// 6013 - New end time must be later than the current end time.
+    #[error("New end time must be later than the current end time.")]
+    EndTimeNotExtended = 0x177d,
+    /// 6014 - Start time must be before end time, and end time must be in the future.
+    #[error("Start time must be before end time, and end time must be in the future.")]
+    InvalidSchedule = 0x177e,
+    /// 6015 - Name exceeds 32 bytes.
+    #[error("Name exceeds 32 bytes.")]
+    NameTooLong = 0x177f,
+    /// 6016 - Description exceeds 280 bytes.
+    #[error("Description exceeds 280 bytes.")]
+    DescriptionTooLong = 0x1780,
+    /// 6017 - Name must not be empty.
+    #[error("Name must not be empty.")]
+    EmptyName = 0x1781,
+    /// 6018 - Poll id must equal the authority's current poll count.
+    #[error("Poll id must equal the authority's current poll count.")]
+    InvalidPollId = 0x1782,
+    /// 6019 - Close all candidate accounts before closing the poll.
+    #[error("Close all candidate accounts before closing the poll.")]
+    CandidatesNotClosed = 0x1783,
+    /// 6020 - Poll result account must be closed together with the poll.
+    #[error("Poll result account must be closed together with the poll.")]
+    PollResultRequired = 0x1784,
+    /// 6021 - Changing votes is not allowed for this poll.
+    #[error("Changing votes is not allowed for this poll.")]
+    VoteChangeDisabled = 0x1785,
+    /// 6022 - The new candidate is the same as the current one.
+    #[error("The new candidate is the same as the current one.")]
+    SameCandidate = 0x1786,
+    /// 6023 - Retracting votes is not allowed for this poll.
+    #[error("Retracting votes is not allowed for this poll.")]
+    VoteRetractionDisabled = 0x1787,
+    /// 6024 - Voter does not hold enough of the poll's gate token.
+    #[error("Voter does not hold enough of the poll's gate token.")]
+    InsufficientTokenBalance = 0x1788,
+    /// 6025 - Token account is not the voter's account for the required mint.
+    #[error("Token account is not the voter's account for the required mint.")]
+    InvalidTokenAccount = 0x1789,
+    /// 6026 - Weighted polls only accept vote_weighted.
+    #[error("Weighted polls only accept vote_weighted.")]
+    WeightedVoteRequired = 0x178a,
+    /// 6027 - Poll is not a weighted poll.
+    #[error("Poll is not a weighted poll.")]
+    PollNotWeighted = 0x178b,
+    /// 6028 - Poll options are incompatible with each other.
+    #[error("Poll options are incompatible with each other.")]
+    InvalidPollOptions = 0x178c,
+    /// 6029 - Escrowed tokens must be withdrawn first.
+    #[error("Escrowed tokens must be withdrawn first.")]
+    TokensNotWithdrawn = 0x178d,
+    /// 6030 - Collection polls only accept vote_with_nft.
+    #[error("Collection polls only accept vote_with_nft.")]
+    NftVoteRequired = 0x178e,
+    /// 6031 - Poll is not gated by an NFT collection.
+    #[error("Poll is not gated by an NFT collection.")]
+    PollNotCollectionGated = 0x178f,
+    /// 6032 - Account is not a valid NFT or NFT metadata.
+    #[error("Account is not a valid NFT or NFT metadata.")]
+    InvalidNft = 0x1790,
+    /// 6033 - NFT is not a verified member of the poll's collection.
+    #[error("NFT is not a verified member of the poll's collection.")]
+    NftNotInCollection = 0x1791,
+    /// 6034 - Voter is not on the poll's allowlist.
+    #[error("Voter is not on the poll's allowlist.")]
+    NotOnAllowlist = 0x1792,
+    /// 6035 - Poll requires a voter pass.
+    #[error("Poll requires a voter pass.")]
+    VoterPassRequired = 0x1793,
+    /// 6036 - Voter pass has already been used and cannot be revoked before the poll ends.
+    #[error("Voter pass has already been used and cannot be revoked before the poll ends.")]
+    VoterPassUsed = 0x1794,
+    /// 6037 - Voter pass weight must be greater than zero.
+    #[error("Voter pass weight must be greater than zero.")]
+    InvalidPassWeight = 0x1795,
+    /// 6038 - Voter pass accounts do not match the passes being issued.
+    #[error("Voter pass accounts do not match the passes being issued.")]
+    InvalidVoterPassAccounts = 0x1796,
+    /// 6039 - Too many candidates selected for this ballot.
+    #[error("Too many candidates selected for this ballot.")]
+    TooManySelections = 0x1797,
+    /// 6040 - The same candidate was selected more than once.
+    #[error("The same candidate was selected more than once.")]
+    DuplicateSelection = 0x1798,
+    /// 6041 - Ranked polls only accept vote_ranked.
+    #[error("Ranked polls only accept vote_ranked.")]
+    RankedVoteRequired = 0x1799,
+    /// 6042 - Poll does not use ranked ballots.
+    #[error("Poll does not use ranked ballots.")]
+    PollNotRanked = 0x179a,
+    /// 6043 - Rankings must list at least one of the poll's candidates.
+    #[error("Rankings must list at least one of the poll's candidates.")]
+    InvalidRankings = 0x179b,
+    /// 6044 - Ranked polls must be tallied with tally_irv first.
+    #[error("Ranked polls must be tallied with tally_irv first.")]
+    IrvTallyRequired = 0x179c,
+    /// 6045 - Ballot has already been counted in this round.
+    #[error("Ballot has already been counted in this round.")]
+    BallotAlreadyCounted = 0x179d,
+    /// 6046 - Ballot does not belong to this poll.
+    #[error("Ballot does not belong to this poll.")]
+    InvalidBallot = 0x179e,
+    /// 6047 - IRV result account must be closed together with the poll.
+    #[error("IRV result account must be closed together with the poll.")]
+    IrvResultRequired = 0x179f,
+    /// 6048 - Quadratic polls only accept vote_quadratic.
+    #[error("Quadratic polls only accept vote_quadratic.")]
+    QuadraticVoteRequired = 0x17a0,
+    /// 6049 - Poll is not a quadratic poll.
+    #[error("Poll is not a quadratic poll.")]
+    PollNotQuadratic = 0x17a1,
+    /// 6050 - Vote count must be greater than zero.
+    #[error("Vote count must be greater than zero.")]
+    InvalidVoteCount = 0x17a2,
+    /// 6051 - Not enough voting credits left.
+    #[error("Not enough voting credits left.")]
+    InsufficientCredits = 0x17a3,
+    /// 6052 - Commit-reveal polls only accept commit_vote.
+    #[error("Commit-reveal polls only accept commit_vote.")]
+    CommitRevealRequired = 0x17a4,
+    /// 6053 - Poll does not use commit-reveal voting.
+    #[error("Poll does not use commit-reveal voting.")]
+    PollNotCommitReveal = 0x17a5,
+    /// 6054 - Reveal window opens after the poll ends.
+    #[error("Reveal window opens after the poll ends.")]
+    RevealWindowNotOpen = 0x17a6,
+    /// 6055 - Reveal window has closed.
+    #[error("Reveal window has closed.")]
+    RevealWindowClosed = 0x17a7,
+    /// 6056 - Reveal window is still open.
+    #[error("Reveal window is still open.")]
+    RevealWindowOpen = 0x17a8,
+    /// 6057 - Vote has already been revealed.
+    #[error("Vote has already been revealed.")]
+    VoteAlreadyRevealed = 0x17a9,
+    /// 6058 - Candidate index and salt do not match the commitment.
+    #[error("Candidate index and salt do not match the commitment.")]
+    CommitmentMismatch = 0x17aa,
+    /// 6059 - Polls with contests only accept cast_ballot.
+    #[error("Polls with contests only accept cast_ballot.")]
+    ContestBallotRequired = 0x17ab,
+    /// 6060 - Poll has no contests.
+    #[error("Poll has no contests.")]
+    PollHasNoContests = 0x17ac,
+    /// 6061 - Contest choices must be sorted, unique and non-empty.
+    #[error("Contest choices must be sorted, unique and non-empty.")]
+    InvalidContestChoices = 0x17ad,
+    /// 6062 - Contest or candidate accounts do not match the ballot.
+    #[error("Contest or candidate accounts do not match the ballot.")]
+    InvalidContestAccounts = 0x17ae,
+    /// 6063 - Polls with contests are counted per contest and cannot be finalized.
+    #[error("Polls with contests are counted per contest and cannot be finalized.")]
+    ContestTallyUnsupported = 0x17af,
+    /// 6064 - Signer is not the pending authority.
+    #[error("Signer is not the pending authority.")]
+    NotPendingAuthority = 0x17b0,
+    /// 6065 - Signer does not have the admin role required for this instruction.
+    #[error("Signer does not have the admin role required for this instruction.")]
+    MissingAdminRole = 0x17b1,
+    /// 6066 - Poll already has the maximum number of admins.
+    #[error("Poll already has the maximum number of admins.")]
+    TooManyAdmins = 0x17b2,
+    /// 6067 - Poll is paused and does not accept votes.
+    #[error("Poll is paused and does not accept votes.")]
+    PollPaused = 0x17b3,
+    /// 6068 - Poll has been cancelled.
+    #[error("Poll has been cancelled.")]
+    PollCancelled = 0x17b4,
+    /// 6069 - Poll is not paused.
+    #[error("Poll is not paused.")]
+    PollNotPaused = 0x17b5,
+    /// 6070 - Poll tally has already started.
+    #[error("Poll tally has already started.")]
+    TallyInProgress = 0x17b6,
 }
 
 impl solana_program_error::PrintProgramError for VotingError {
@@ -18,6 +18,10 @@ pub struct AddCandidate {
     pub candidate_account: solana_pubkey::Pubkey,
 
     pub system_program: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl AddCandidate {
@@ -34,7 +38,7 @@ impl AddCandidate {
         args: AddCandidateInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.signer, true));
         accounts.push(solana_instruction::AccountMeta::new(
             self.poll_account,
@@ -48,6 +52,14 @@ impl AddCandidate {
             self.system_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&AddCandidateInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -95,12 +107,16 @@ pub struct AddCandidateInstructionArgs {
 ///   1. `[writable]` poll_account
 ///   2. `[writable]` candidate_account
 ///   3. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   4. `[]` event_authority
+///   5. `[]` program
 #[derive(Clone, Debug, Default)]
 pub struct AddCandidateBuilder {
     signer: Option<solana_pubkey::Pubkey>,
     poll_account: Option<solana_pubkey::Pubkey>,
     candidate_account: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     candidate_name: Option<String>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -131,6 +147,16 @@ impl AddCandidateBuilder {
         self
     }
     #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn candidate_name(&mut self, candidate_name: String) -> &mut Self {
         self.candidate_name = Some(candidate_name);
         self
@@ -161,6 +187,8 @@ impl AddCandidateBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            event_authority: self.event_authority.expect("event_authority is not set"),
+            program: self.program.expect("program is not set"),
         };
         let args = AddCandidateInstructionArgs {
             candidate_name: self
@@ -182,6 +210,10 @@ pub struct AddCandidateCpiAccounts<'a, 'b> {
     pub candidate_account: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `add_candidate` CPI instruction.
@@ -196,6 +228,10 @@ pub struct AddCandidateCpi<'a, 'b> {
     pub candidate_account: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: AddCandidateInstructionArgs,
 }
@@ -212,6 +248,8 @@ impl<'a, 'b> AddCandidateCpi<'a, 'b> {
             poll_account: accounts.poll_account,
             candidate_account: accounts.candidate_account,
             system_program: accounts.system_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -241,7 +279,7 @@ impl<'a, 'b> AddCandidateCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.signer.key, true));
         accounts.push(solana_instruction::AccountMeta::new(
             *self.poll_account.key,
@@ -255,6 +293,14 @@ impl<'a, 'b> AddCandidateCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -271,12 +317,14 @@ impl<'a, 'b> AddCandidateCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.signer.clone());
         account_infos.push(self.poll_account.clone());
         account_infos.push(self.candidate_account.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -297,6 +345,8 @@ impl<'a, 'b> AddCandidateCpi<'a, 'b> {
 ///   1. `[writable]` poll_account
 ///   2. `[writable]` candidate_account
 ///   3. `[]` system_program
+///   4. `[]` event_authority
+///   5. `[]` program
 #[derive(Clone, Debug)]
 pub struct AddCandidateCpiBuilder<'a, 'b> {
     instruction: Box<AddCandidateCpiBuilderInstruction<'a, 'b>>,
@@ -310,6 +360,8 @@ impl<'a, 'b> AddCandidateCpiBuilder<'a, 'b> {
             poll_account: None,
             candidate_account: None,
             system_program: None,
+            event_authority: None,
+            program: None,
             candidate_name: None,
             __remaining_accounts: Vec::new(),
         });
@@ -345,6 +397,19 @@ impl<'a, 'b> AddCandidateCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn candidate_name(&mut self, candidate_name: String) -> &mut Self {
         self.instruction.candidate_name = Some(candidate_name);
         self
@@ -412,6 +477,13 @@ impl<'a, 'b> AddCandidateCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -428,6 +500,8 @@ struct AddCandidateCpiBuilderInstruction<'a, 'b> {
     poll_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     candidate_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     candidate_name: Option<String>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
@@ -5,6 +5,7 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::types::PollOptions;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 
@@ -15,7 +16,13 @@ pub struct InitializePoll {
 
     pub poll_account: solana_pubkey::Pubkey,
 
+    pub poll_counter: solana_pubkey::Pubkey,
+
     pub system_program: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl InitializePoll {
@@ -32,16 +39,28 @@ impl InitializePoll {
         args: InitializePollInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.signer, true));
         accounts.push(solana_instruction::AccountMeta::new(
             self.poll_account,
-            true,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.poll_counter,
+            false,
         ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&InitializePollInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -78,10 +97,12 @@ impl Default for InitializePollInstructionData {
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializePollInstructionArgs {
+    pub poll_id: u64,
     pub name: String,
     pub description: String,
     pub start_time: u64,
     pub end_time: u64,
+    pub options: PollOptions,
 }
 
 /// Instruction builder for `InitializePoll`.
@@ -89,17 +110,25 @@ pub struct InitializePollInstructionArgs {
 /// ### Accounts:
 ///
 ///   0. `[writable, signer]` signer
-///   1. `[writable, signer]` poll_account
-///   2. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   1. `[writable]` poll_account
+///   2. `[writable]` poll_counter
+///   3. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   4. `[]` event_authority
+///   5. `[]` program
 #[derive(Clone, Debug, Default)]
 pub struct InitializePollBuilder {
     signer: Option<solana_pubkey::Pubkey>,
     poll_account: Option<solana_pubkey::Pubkey>,
+    poll_counter: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
+    poll_id: Option<u64>,
     name: Option<String>,
     description: Option<String>,
     start_time: Option<u64>,
     end_time: Option<u64>,
+    options: Option<PollOptions>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -117,6 +146,11 @@ impl InitializePollBuilder {
         self.poll_account = Some(poll_account);
         self
     }
+    #[inline(always)]
+    pub fn poll_counter(&mut self, poll_counter: solana_pubkey::Pubkey) -> &mut Self {
+        self.poll_counter = Some(poll_counter);
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
@@ -124,6 +158,21 @@ impl InitializePollBuilder {
         self
     }
     #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    #[inline(always)]
+    pub fn poll_id(&mut self, poll_id: u64) -> &mut Self {
+        self.poll_id = Some(poll_id);
+        self
+    }
+    #[inline(always)]
     pub fn name(&mut self, name: String) -> &mut Self {
         self.name = Some(name);
         self
@@ -143,6 +192,11 @@ impl InitializePollBuilder {
         self.end_time = Some(end_time);
         self
     }
+    #[inline(always)]
+    pub fn options(&mut self, options: PollOptions) -> &mut Self {
+        self.options = Some(options);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -163,15 +217,20 @@ impl InitializePollBuilder {
         let accounts = InitializePoll {
             signer: self.signer.expect("signer is not set"),
             poll_account: self.poll_account.expect("poll_account is not set"),
+            poll_counter: self.poll_counter.expect("poll_counter is not set"),
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            event_authority: self.event_authority.expect("event_authority is not set"),
+            program: self.program.expect("program is not set"),
         };
         let args = InitializePollInstructionArgs {
+            poll_id: self.poll_id.clone().expect("poll_id is not set"),
             name: self.name.clone().expect("name is not set"),
             description: self.description.clone().expect("description is not set"),
             start_time: self.start_time.clone().expect("start_time is not set"),
             end_time: self.end_time.clone().expect("end_time is not set"),
+            options: self.options.clone().expect("options is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -184,7 +243,13 @@ pub struct InitializePollCpiAccounts<'a, 'b> {
 
     pub poll_account: &'b solana_account_info::AccountInfo<'a>,
 
+    pub poll_counter: &'b solana_account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `initialize_poll` CPI instruction.
@@ -196,7 +261,13 @@ pub struct InitializePollCpi<'a, 'b> {
 
     pub poll_account: &'b solana_account_info::AccountInfo<'a>,
 
+    pub poll_counter: &'b solana_account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: InitializePollInstructionArgs,
 }
@@ -211,7 +282,10 @@ impl<'a, 'b> InitializePollCpi<'a, 'b> {
             __program: program,
             signer: accounts.signer,
             poll_account: accounts.poll_account,
+            poll_counter: accounts.poll_counter,
             system_program: accounts.system_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -241,16 +315,28 @@ impl<'a, 'b> InitializePollCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.signer.key, true));
         accounts.push(solana_instruction::AccountMeta::new(
             *self.poll_account.key,
-            true,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.poll_counter.key,
+            false,
         ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -267,11 +353,14 @@ impl<'a, 'b> InitializePollCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.signer.clone());
         account_infos.push(self.poll_account.clone());
+        account_infos.push(self.poll_counter.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -289,8 +378,11 @@ impl<'a, 'b> InitializePollCpi<'a, 'b> {
 /// ### Accounts:
 ///
 ///   0. `[writable, signer]` signer
-///   1. `[writable, signer]` poll_account
-///   2. `[]` system_program
+///   1. `[writable]` poll_account
+///   2. `[writable]` poll_counter
+///   3. `[]` system_program
+///   4. `[]` event_authority
+///   5. `[]` program
 #[derive(Clone, Debug)]
 pub struct InitializePollCpiBuilder<'a, 'b> {
     instruction: Box<InitializePollCpiBuilderInstruction<'a, 'b>>,
@@ -302,11 +394,16 @@ impl<'a, 'b> InitializePollCpiBuilder<'a, 'b> {
             __program: program,
             signer: None,
             poll_account: None,
+            poll_counter: None,
             system_program: None,
+            event_authority: None,
+            program: None,
+            poll_id: None,
             name: None,
             description: None,
             start_time: None,
             end_time: None,
+            options: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -325,6 +422,14 @@ impl<'a, 'b> InitializePollCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn poll_counter(
+        &mut self,
+        poll_counter: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.poll_counter = Some(poll_counter);
+        self
+    }
+    #[inline(always)]
     pub fn system_program(
         &mut self,
         system_program: &'b solana_account_info::AccountInfo<'a>,
@@ -333,6 +438,24 @@ impl<'a, 'b> InitializePollCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    #[inline(always)]
+    pub fn poll_id(&mut self, poll_id: u64) -> &mut Self {
+        self.instruction.poll_id = Some(poll_id);
+        self
+    }
+    #[inline(always)]
     pub fn name(&mut self, name: String) -> &mut Self {
         self.instruction.name = Some(name);
         self
@@ -352,6 +475,11 @@ impl<'a, 'b> InitializePollCpiBuilder<'a, 'b> {
         self.instruction.end_time = Some(end_time);
         self
     }
+    #[inline(always)]
+    pub fn options(&mut self, options: PollOptions) -> &mut Self {
+        self.instruction.options = Some(options);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -390,6 +518,11 @@ impl<'a, 'b> InitializePollCpiBuilder<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program_entrypoint::ProgramResult {
         let args = InitializePollInstructionArgs {
+            poll_id: self
+                .instruction
+                .poll_id
+                .clone()
+                .expect("poll_id is not set"),
             name: self.instruction.name.clone().expect("name is not set"),
             description: self
                 .instruction
@@ -406,6 +539,11 @@ impl<'a, 'b> InitializePollCpiBuilder<'a, 'b> {
                 .end_time
                 .clone()
                 .expect("end_time is not set"),
+            options: self
+                .instruction
+                .options
+                .clone()
+                .expect("options is not set"),
         };
         let instruction = InitializePollCpi {
             __program: self.instruction.__program,
@@ -417,10 +555,22 @@ impl<'a, 'b> InitializePollCpiBuilder<'a, 'b> {
                 .poll_account
                 .expect("poll_account is not set"),
 
+            poll_counter: self
+                .instruction
+                .poll_counter
+                .expect("poll_counter is not set"),
+
             system_program: self
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -435,11 +585,16 @@ struct InitializePollCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_account_info::AccountInfo<'a>,
     signer: Option<&'b solana_account_info::AccountInfo<'a>>,
     poll_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    poll_counter: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    poll_id: Option<u64>,
     name: Option<String>,
     description: Option<String>,
     start_time: Option<u64>,
     end_time: Option<u64>,
+    options: Option<PollOptions>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -5,10 +5,70 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#accept_authority;
 pub(crate) mod r#add_candidate;
+pub(crate) mod r#add_contest;
+pub(crate) mod r#add_contest_candidate;
+pub(crate) mod r#cancel_poll;
+pub(crate) mod r#cast_ballot;
+pub(crate) mod r#change_vote;
+pub(crate) mod r#close_ballot;
+pub(crate) mod r#close_candidates;
+pub(crate) mod r#close_nft_receipt;
+pub(crate) mod r#close_poll;
+pub(crate) mod r#close_receipt;
+pub(crate) mod r#commit_vote;
+pub(crate) mod r#finalize_poll;
 pub(crate) mod r#initialize_poll;
+pub(crate) mod r#issue_voter_pass;
+pub(crate) mod r#issue_voter_passes;
+pub(crate) mod r#migrate_poll;
+pub(crate) mod r#pause_poll;
+pub(crate) mod r#propose_authority;
+pub(crate) mod r#resume_poll;
+pub(crate) mod r#retract_vote;
+pub(crate) mod r#reveal_vote;
+pub(crate) mod r#revoke_voter_pass;
+pub(crate) mod r#set_admin;
+pub(crate) mod r#tally_irv;
+pub(crate) mod r#update_poll;
 pub(crate) mod r#vote;
+pub(crate) mod r#vote_quadratic;
+pub(crate) mod r#vote_ranked;
+pub(crate) mod r#vote_weighted;
+pub(crate) mod r#vote_with_nft;
+pub(crate) mod r#withdraw_tokens;
 
+pub use self::r#accept_authority::*;
 pub use self::r#add_candidate::*;
+pub use self::r#add_contest::*;
+pub use self::r#add_contest_candidate::*;
+pub use self::r#cancel_poll::*;
+pub use self::r#cast_ballot::*;
+pub use self::r#change_vote::*;
+pub use self::r#close_ballot::*;
+pub use self::r#close_candidates::*;
+pub use self::r#close_nft_receipt::*;
+pub use self::r#close_poll::*;
+pub use self::r#close_receipt::*;
+pub use self::r#commit_vote::*;
+pub use self::r#finalize_poll::*;
 pub use self::r#initialize_poll::*;
+pub use self::r#issue_voter_pass::*;
+pub use self::r#issue_voter_passes::*;
+pub use self::r#migrate_poll::*;
+pub use self::r#pause_poll::*;
+pub use self::r#propose_authority::*;
+pub use self::r#resume_poll::*;
+pub use self::r#retract_vote::*;
+pub use self::r#reveal_vote::*;
+pub use self::r#revoke_voter_pass::*;
+pub use self::r#set_admin::*;
+pub use self::r#tally_irv::*;
+pub use self::r#update_poll::*;
 pub use self::r#vote::*;
+pub use self::r#vote_quadratic::*;
+pub use self::r#vote_ranked::*;
+pub use self::r#vote_weighted::*;
+pub use self::r#vote_with_nft::*;
+pub use self::r#withdraw_tokens::*;
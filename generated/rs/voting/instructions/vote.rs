@@ -5,6 +5,7 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::types::AllowlistProof;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 
@@ -19,20 +20,29 @@ pub struct Vote {
 
     pub voter_receipt: solana_pubkey::Pubkey,
 
+    pub voter_token_account: Option<solana_pubkey::Pubkey>,
+
+    pub voter_pass: Option<solana_pubkey::Pubkey>,
+
     pub system_program: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl Vote {
-    pub fn instruction(&self) -> solana_instruction::Instruction {
-        self.instruction_with_remaining_accounts(&[])
+    pub fn instruction(&self, args: VoteInstructionArgs) -> solana_instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
     }
     #[allow(clippy::arithmetic_side_effects)]
     #[allow(clippy::vec_init_then_push)]
     pub fn instruction_with_remaining_accounts(
         &self,
+        args: VoteInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.signer, true));
         accounts.push(solana_instruction::AccountMeta::new(
             self.poll_account,
@@ -46,12 +56,41 @@ impl Vote {
             self.voter_receipt,
             false,
         ));
+        if let Some(voter_token_account) = self.voter_token_account {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                voter_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::VOTING_ID,
+                false,
+            ));
+        }
+        if let Some(voter_pass) = self.voter_pass {
+            accounts.push(solana_instruction::AccountMeta::new(voter_pass, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::VOTING_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
-        let data = borsh::to_vec(&VoteInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&VoteInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
 
         solana_instruction::Instruction {
             program_id: crate::VOTING_ID,
@@ -81,6 +120,13 @@ impl Default for VoteInstructionData {
     }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VoteInstructionArgs {
+    pub candidate_index: u32,
+    pub allowlist_proof: Option<AllowlistProof>,
+}
+
 /// Instruction builder for `Vote`.
 ///
 /// ### Accounts:
@@ -89,14 +135,24 @@ impl Default for VoteInstructionData {
 ///   1. `[writable]` poll_account
 ///   2. `[writable]` candidate_account
 ///   3. `[writable]` voter_receipt
-///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   4. `[optional]` voter_token_account
+///   5. `[writable, optional]` voter_pass
+///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   7. `[]` event_authority
+///   8. `[]` program
 #[derive(Clone, Debug, Default)]
 pub struct VoteBuilder {
     signer: Option<solana_pubkey::Pubkey>,
     poll_account: Option<solana_pubkey::Pubkey>,
     candidate_account: Option<solana_pubkey::Pubkey>,
     voter_receipt: Option<solana_pubkey::Pubkey>,
+    voter_token_account: Option<solana_pubkey::Pubkey>,
+    voter_pass: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
+    candidate_index: Option<u32>,
+    allowlist_proof: Option<AllowlistProof>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -124,12 +180,48 @@ impl VoteBuilder {
         self.voter_receipt = Some(voter_receipt);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn voter_token_account(
+        &mut self,
+        voter_token_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.voter_token_account = voter_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn voter_pass(&mut self, voter_pass: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.voter_pass = voter_pass;
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
         self.system_program = Some(system_program);
         self
     }
+    #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    #[inline(always)]
+    pub fn candidate_index(&mut self, candidate_index: u32) -> &mut Self {
+        self.candidate_index = Some(candidate_index);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn allowlist_proof(&mut self, allowlist_proof: AllowlistProof) -> &mut Self {
+        self.allowlist_proof = Some(allowlist_proof);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -154,12 +246,23 @@ impl VoteBuilder {
                 .candidate_account
                 .expect("candidate_account is not set"),
             voter_receipt: self.voter_receipt.expect("voter_receipt is not set"),
+            voter_token_account: self.voter_token_account,
+            voter_pass: self.voter_pass,
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            event_authority: self.event_authority.expect("event_authority is not set"),
+            program: self.program.expect("program is not set"),
+        };
+        let args = VoteInstructionArgs {
+            candidate_index: self
+                .candidate_index
+                .clone()
+                .expect("candidate_index is not set"),
+            allowlist_proof: self.allowlist_proof.clone(),
         };
 
-        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
     }
 }
 
@@ -173,7 +276,15 @@ pub struct VoteCpiAccounts<'a, 'b> {
 
     pub voter_receipt: &'b solana_account_info::AccountInfo<'a>,
 
+    pub voter_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub voter_pass: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `vote` CPI instruction.
@@ -189,13 +300,24 @@ pub struct VoteCpi<'a, 'b> {
 
     pub voter_receipt: &'b solana_account_info::AccountInfo<'a>,
 
+    pub voter_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub voter_pass: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: VoteInstructionArgs,
 }
 
 impl<'a, 'b> VoteCpi<'a, 'b> {
     pub fn new(
         program: &'b solana_account_info::AccountInfo<'a>,
         accounts: VoteCpiAccounts<'a, 'b>,
+        args: VoteInstructionArgs,
     ) -> Self {
         Self {
             __program: program,
@@ -203,7 +325,12 @@ impl<'a, 'b> VoteCpi<'a, 'b> {
             poll_account: accounts.poll_account,
             candidate_account: accounts.candidate_account,
             voter_receipt: accounts.voter_receipt,
+            voter_token_account: accounts.voter_token_account,
+            voter_pass: accounts.voter_pass,
             system_program: accounts.system_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
+            __args: args,
         }
     }
     #[inline(always)]
@@ -232,7 +359,7 @@ impl<'a, 'b> VoteCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.signer.key, true));
         accounts.push(solana_instruction::AccountMeta::new(
             *self.poll_account.key,
@@ -246,10 +373,37 @@ impl<'a, 'b> VoteCpi<'a, 'b> {
             *self.voter_receipt.key,
             false,
         ));
+        if let Some(voter_token_account) = self.voter_token_account {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *voter_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::VOTING_ID,
+                false,
+            ));
+        }
+        if let Some(voter_pass) = self.voter_pass {
+            accounts.push(solana_instruction::AccountMeta::new(*voter_pass.key, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::VOTING_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -257,20 +411,30 @@ impl<'a, 'b> VoteCpi<'a, 'b> {
                 is_writable: remaining_account.2,
             })
         });
-        let data = borsh::to_vec(&VoteInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&VoteInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
 
         let instruction = solana_instruction::Instruction {
             program_id: crate::VOTING_ID,
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(10 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.signer.clone());
         account_infos.push(self.poll_account.clone());
         account_infos.push(self.candidate_account.clone());
         account_infos.push(self.voter_receipt.clone());
+        if let Some(voter_token_account) = self.voter_token_account {
+            account_infos.push(voter_token_account.clone());
+        }
+        if let Some(voter_pass) = self.voter_pass {
+            account_infos.push(voter_pass.clone());
+        }
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -291,7 +455,11 @@ impl<'a, 'b> VoteCpi<'a, 'b> {
 ///   1. `[writable]` poll_account
 ///   2. `[writable]` candidate_account
 ///   3. `[writable]` voter_receipt
-///   4. `[]` system_program
+///   4. `[optional]` voter_token_account
+///   5. `[writable, optional]` voter_pass
+///   6. `[]` system_program
+///   7. `[]` event_authority
+///   8. `[]` program
 #[derive(Clone, Debug)]
 pub struct VoteCpiBuilder<'a, 'b> {
     instruction: Box<VoteCpiBuilderInstruction<'a, 'b>>,
@@ -305,7 +473,13 @@ impl<'a, 'b> VoteCpiBuilder<'a, 'b> {
             poll_account: None,
             candidate_account: None,
             voter_receipt: None,
+            voter_token_account: None,
+            voter_pass: None,
             system_program: None,
+            event_authority: None,
+            program: None,
+            candidate_index: None,
+            allowlist_proof: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -339,6 +513,24 @@ impl<'a, 'b> VoteCpiBuilder<'a, 'b> {
         self.instruction.voter_receipt = Some(voter_receipt);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn voter_token_account(
+        &mut self,
+        voter_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.voter_token_account = voter_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn voter_pass(
+        &mut self,
+        voter_pass: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.voter_pass = voter_pass;
+        self
+    }
     #[inline(always)]
     pub fn system_program(
         &mut self,
@@ -347,6 +539,30 @@ impl<'a, 'b> VoteCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    #[inline(always)]
+    pub fn candidate_index(&mut self, candidate_index: u32) -> &mut Self {
+        self.instruction.candidate_index = Some(candidate_index);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn allowlist_proof(&mut self, allowlist_proof: AllowlistProof) -> &mut Self {
+        self.instruction.allowlist_proof = Some(allowlist_proof);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -384,6 +600,14 @@ impl<'a, 'b> VoteCpiBuilder<'a, 'b> {
         &self,
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program_entrypoint::ProgramResult {
+        let args = VoteInstructionArgs {
+            candidate_index: self
+                .instruction
+                .candidate_index
+                .clone()
+                .expect("candidate_index is not set"),
+            allowlist_proof: self.instruction.allowlist_proof.clone(),
+        };
         let instruction = VoteCpi {
             __program: self.instruction.__program,
 
@@ -404,10 +628,22 @@ impl<'a, 'b> VoteCpiBuilder<'a, 'b> {
                 .voter_receipt
                 .expect("voter_receipt is not set"),
 
+            voter_token_account: self.instruction.voter_token_account,
+
+            voter_pass: self.instruction.voter_pass,
+
             system_program: self
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
+            __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -423,7 +659,13 @@ struct VoteCpiBuilderInstruction<'a, 'b> {
     poll_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     candidate_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     voter_receipt: Option<&'b solana_account_info::AccountInfo<'a>>,
+    voter_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    voter_pass: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    candidate_index: Option<u32>,
+    allowlist_proof: Option<AllowlistProof>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
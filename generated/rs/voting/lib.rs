@@ -0,0 +1,13 @@
+//! voting_client 入口
+//!
+//! `mod.rs` 及其子模块由 codama 生成，重新生成时会被覆盖；
+//! 手写的辅助代码放在本文件引入的其他模块中。
+
+#[path = "mod.rs"]
+mod generated;
+
+pub mod allowlist;
+pub mod ballot;
+pub mod pdas;
+
+pub use generated::*;
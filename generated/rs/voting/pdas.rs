@@ -0,0 +1,130 @@
+//! 手写的 PDA 推导辅助函数，seeds 必须与 `programs/voting` 中的约束保持一致。
+
+use solana_pubkey::Pubkey;
+
+use crate::VOTING_ID;
+
+/// authority 的投票活动计数器：`[b"poll_counter", authority]`
+pub fn find_poll_counter_pda(authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"poll_counter", authority.as_ref()], &VOTING_ID)
+}
+
+/// 投票活动账户：`[b"poll", authority, poll_id]`，`poll_id` 为小端序 u64
+pub fn find_poll_pda(authority: &Pubkey, poll_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"poll", authority.as_ref(), &poll_id.to_le_bytes()],
+        &VOTING_ID,
+    )
+}
+
+/// authority 创建过的所有投票活动地址，按 poll_id 升序排列
+///
+/// `poll_count` 取自 [`crate::accounts::PollCounter`]；计数器不存在时传 0。
+pub fn find_authority_poll_pdas(authority: &Pubkey, poll_count: u64) -> Vec<Pubkey> {
+    (0..poll_count)
+        .map(|poll_id| find_poll_pda(authority, poll_id).0)
+        .collect()
+}
+
+/// 候选人账户：`[b"candidate", poll, candidate_index]`，`candidate_index` 为小端序 u32
+pub fn find_candidate_pda(poll: &Pubkey, candidate_index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"candidate", poll.as_ref(), &candidate_index.to_le_bytes()],
+        &VOTING_ID,
+    )
+}
+
+/// 候选人账户地址，兼容从旧版布局迁移的投票活动
+///
+/// 索引小于 `legacy_candidate_count` 的候选人创建于迁移前，seeds 中的索引为单字节 u8。
+pub fn find_poll_candidate_pda(
+    poll: &Pubkey,
+    legacy_candidate_count: u8,
+    candidate_index: u32,
+) -> (Pubkey, u8) {
+    if candidate_index < legacy_candidate_count as u32 {
+        Pubkey::find_program_address(
+            &[b"candidate", poll.as_ref(), &[candidate_index as u8]],
+            &VOTING_ID,
+        )
+    } else {
+        find_candidate_pda(poll, candidate_index)
+    }
+}
+
+/// 投票活动的所有候选人地址，按索引升序排列
+///
+/// 参数取自 [`crate::accounts::PollAccount`] 的 `legacy_candidate_count` 和 `candidate_count`。
+pub fn find_poll_candidate_pdas(
+    poll: &Pubkey,
+    legacy_candidate_count: u8,
+    candidate_count: u32,
+) -> Vec<Pubkey> {
+    (0..candidate_count)
+        .map(|candidate_index| {
+            find_poll_candidate_pda(poll, legacy_candidate_count, candidate_index).0
+        })
+        .collect()
+}
+
+/// 结算结果账户：`[b"result", poll]`
+pub fn find_poll_result_pda(poll: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"result", poll.as_ref()], &VOTING_ID)
+}
+
+/// 投票通行证：`[b"pass", poll, voter]`
+pub fn find_voter_pass_pda(poll: &Pubkey, voter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"pass", poll.as_ref(), voter.as_ref()], &VOTING_ID)
+}
+
+/// 加权投票的代币金库：`[b"vault", poll]`
+pub fn find_vault_pda(poll: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"vault", poll.as_ref()], &VOTING_ID)
+}
+
+/// NFT 投票回执：`[b"nft_receipt", poll, nft_mint]`
+pub fn find_nft_receipt_pda(poll: &Pubkey, nft_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"nft_receipt", poll.as_ref(), nft_mint.as_ref()],
+        &VOTING_ID,
+    )
+}
+
+/// 排序投票的选票：`[b"ballot", poll, voter]`
+pub fn find_ranked_ballot_pda(poll: &Pubkey, voter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"ballot", poll.as_ref(), voter.as_ref()], &VOTING_ID)
+}
+
+/// 排序投票的开票结果：`[b"irv", poll]`
+pub fn find_irv_result_pda(poll: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"irv", poll.as_ref()], &VOTING_ID)
+}
+
+/// 投票活动下的分项：`[b"contest", poll, contest_index]`，`contest_index` 为小端序 u32
+pub fn find_contest_pda(poll: &Pubkey, contest_index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"contest", poll.as_ref(), &contest_index.to_le_bytes()],
+        &VOTING_ID,
+    )
+}
+
+/// 分项的候选人账户：`[b"candidate", contest, candidate_index]`，`candidate_index` 为小端序 u32
+pub fn find_contest_candidate_pda(contest: &Pubkey, candidate_index: u32) -> (Pubkey, u8) {
+    find_candidate_pda(contest, candidate_index)
+}
+
+/// Metaplex Token Metadata 程序
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey =
+    solana_pubkey::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// NFT 的元数据账户（属于 Metaplex 程序）：`[b"metadata", TOKEN_METADATA_PROGRAM_ID, mint]`
+pub fn find_metadata_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"metadata",
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            mint.as_ref(),
+        ],
+        &TOKEN_METADATA_PROGRAM_ID,
+    )
+}
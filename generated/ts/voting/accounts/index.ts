@@ -7,5 +7,12 @@
  */
 
 export * from "./candidateAccount";
+export * from "./contest";
+export * from "./irvResult";
+export * from "./nftReceipt";
 export * from "./pollAccount";
+export * from "./pollCounter";
+export * from "./pollResult";
+export * from "./rankedBallot";
+export * from "./voterPass";
 export * from "./voterReceipt";
@@ -23,6 +23,8 @@ import {
   getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU32Decoder,
@@ -44,8 +46,24 @@ import {
   type FetchAccountsConfig,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from "@solana/kit";
+import {
+  getPollAdminDecoder,
+  getPollAdminEncoder,
+  getPollOptionsDecoder,
+  getPollOptionsEncoder,
+  getPollStatusDecoder,
+  getPollStatusEncoder,
+  type PollAdmin,
+  type PollAdminArgs,
+  type PollOptions,
+  type PollOptionsArgs,
+  type PollStatus,
+  type PollStatusArgs,
+} from "../types";
 
 export const POLL_ACCOUNT_DISCRIMINATOR = new Uint8Array([
   109, 254, 117, 41, 232, 74, 172, 45,
@@ -65,7 +83,19 @@ export type PollAccount = {
   startTime: bigint;
   endTime: bigint;
   candidateCount: number;
-  candidates: Array<Address>;
+  legacyCandidateCount: number;
+  totalVotes: bigint;
+  options: PollOptions;
+  status: PollStatus;
+  talliedCount: number;
+  closedCandidateCount: number;
+  escrowedAmount: bigint;
+  commitCount: bigint;
+  revealCount: bigint;
+  contestCount: number;
+  contestCandidateCount: number;
+  pendingAuthority: Option<Address>;
+  admins: Array<PollAdmin>;
 };
 
 export type PollAccountArgs = {
@@ -75,7 +105,19 @@ export type PollAccountArgs = {
   startTime: number | bigint;
   endTime: number | bigint;
   candidateCount: number;
-  candidates: Array<Address>;
+  legacyCandidateCount: number;
+  totalVotes: number | bigint;
+  options: PollOptionsArgs;
+  status: PollStatusArgs;
+  talliedCount: number;
+  closedCandidateCount: number;
+  escrowedAmount: number | bigint;
+  commitCount: number | bigint;
+  revealCount: number | bigint;
+  contestCount: number;
+  contestCandidateCount: number;
+  pendingAuthority: OptionOrNullable<Address>;
+  admins: Array<PollAdminArgs>;
 };
 
 export function getPollAccountEncoder(): Encoder<PollAccountArgs> {
@@ -87,8 +129,20 @@ export function getPollAccountEncoder(): Encoder<PollAccountArgs> {
       ["description", addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ["startTime", getU64Encoder()],
       ["endTime", getU64Encoder()],
-      ["candidateCount", getU8Encoder()],
-      ["candidates", getArrayEncoder(getAddressEncoder())],
+      ["candidateCount", getU32Encoder()],
+      ["legacyCandidateCount", getU8Encoder()],
+      ["totalVotes", getU64Encoder()],
+      ["options", getPollOptionsEncoder()],
+      ["status", getPollStatusEncoder()],
+      ["talliedCount", getU32Encoder()],
+      ["closedCandidateCount", getU32Encoder()],
+      ["escrowedAmount", getU64Encoder()],
+      ["commitCount", getU64Encoder()],
+      ["revealCount", getU64Encoder()],
+      ["contestCount", getU32Encoder()],
+      ["contestCandidateCount", getU32Encoder()],
+      ["pendingAuthority", getOptionEncoder(getAddressEncoder())],
+      ["admins", getArrayEncoder(getPollAdminEncoder())],
     ]),
     (value) => ({ ...value, discriminator: POLL_ACCOUNT_DISCRIMINATOR }),
   );
@@ -102,8 +156,20 @@ export function getPollAccountDecoder(): Decoder<PollAccount> {
     ["description", addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ["startTime", getU64Decoder()],
     ["endTime", getU64Decoder()],
-    ["candidateCount", getU8Decoder()],
-    ["candidates", getArrayDecoder(getAddressDecoder())],
+    ["candidateCount", getU32Decoder()],
+    ["legacyCandidateCount", getU8Decoder()],
+    ["totalVotes", getU64Decoder()],
+    ["options", getPollOptionsDecoder()],
+    ["status", getPollStatusDecoder()],
+    ["talliedCount", getU32Decoder()],
+    ["closedCandidateCount", getU32Decoder()],
+    ["escrowedAmount", getU64Decoder()],
+    ["commitCount", getU64Decoder()],
+    ["revealCount", getU64Decoder()],
+    ["contestCount", getU32Decoder()],
+    ["contestCandidateCount", getU32Decoder()],
+    ["pendingAuthority", getOptionDecoder(getAddressDecoder())],
+    ["admins", getArrayDecoder(getPollAdminDecoder())],
   ]);
 }
 
@@ -17,21 +17,35 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type Account,
   type Address,
+  type Codec,
+  type Decoder,
   type EncodedAccount,
+  type Encoder,
   type FetchAccountConfig,
   type FetchAccountsConfig,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from "@solana/kit";
 
@@ -49,33 +63,75 @@ export type VoterReceipt = {
   discriminator: ReadonlyUint8Array;
   voter: Address;
   poll: Address;
+  candidate: Address;
+  votedAt: bigint;
+  slot: bigint;
+  weight: bigint;
+  selections: Array<Address>;
+  allocations: Array<bigint>;
+  creditsSpent: bigint;
+  commitment: Option<Array<number>>;
+  revealed: boolean;
 };
 
-export type VoterReceiptArgs = { voter: Address; poll: Address };
+export type VoterReceiptArgs = {
+  voter: Address;
+  poll: Address;
+  candidate: Address;
+  votedAt: number | bigint;
+  slot: number | bigint;
+  weight: number | bigint;
+  selections: Array<Address>;
+  allocations: Array<number | bigint>;
+  creditsSpent: number | bigint;
+  commitment: OptionOrNullable<Array<number>>;
+  revealed: boolean;
+};
 
-export function getVoterReceiptEncoder(): FixedSizeEncoder<VoterReceiptArgs> {
+export function getVoterReceiptEncoder(): Encoder<VoterReceiptArgs> {
   return transformEncoder(
     getStructEncoder([
       ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
       ["voter", getAddressEncoder()],
       ["poll", getAddressEncoder()],
+      ["candidate", getAddressEncoder()],
+      ["votedAt", getI64Encoder()],
+      ["slot", getU64Encoder()],
+      ["weight", getU64Encoder()],
+      ["selections", getArrayEncoder(getAddressEncoder())],
+      ["allocations", getArrayEncoder(getU64Encoder())],
+      ["creditsSpent", getU64Encoder()],
+      [
+        "commitment",
+        getOptionEncoder(getArrayEncoder(getU8Encoder(), { size: 32 })),
+      ],
+      ["revealed", getBooleanEncoder()],
     ]),
     (value) => ({ ...value, discriminator: VOTER_RECEIPT_DISCRIMINATOR }),
   );
 }
 
-export function getVoterReceiptDecoder(): FixedSizeDecoder<VoterReceipt> {
+export function getVoterReceiptDecoder(): Decoder<VoterReceipt> {
   return getStructDecoder([
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
     ["voter", getAddressDecoder()],
     ["poll", getAddressDecoder()],
+    ["candidate", getAddressDecoder()],
+    ["votedAt", getI64Decoder()],
+    ["slot", getU64Decoder()],
+    ["weight", getU64Decoder()],
+    ["selections", getArrayDecoder(getAddressDecoder())],
+    ["allocations", getArrayDecoder(getU64Decoder())],
+    ["creditsSpent", getU64Decoder()],
+    [
+      "commitment",
+      getOptionDecoder(getArrayDecoder(getU8Decoder(), { size: 32 })),
+    ],
+    ["revealed", getBooleanDecoder()],
   ]);
 }
 
-export function getVoterReceiptCodec(): FixedSizeCodec<
-  VoterReceiptArgs,
-  VoterReceipt
-> {
+export function getVoterReceiptCodec(): Codec<VoterReceiptArgs, VoterReceipt> {
   return combineCodec(getVoterReceiptEncoder(), getVoterReceiptDecoder());
 }
 
@@ -131,7 +187,3 @@ export async function fetchAllMaybeVoterReceipt(
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
   return maybeAccounts.map((maybeAccount) => decodeVoterReceipt(maybeAccount));
 }
-
-export function getVoterReceiptSize(): number {
-  return 72;
-}
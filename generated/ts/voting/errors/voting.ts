@@ -24,22 +24,286 @@ export const VOTING_ERROR__UNAUTHORIZED = 0x1772; // 6002
 export const VOTING_ERROR__MAX_CANDIDATES_REACHED = 0x1773; // 6003
 /** InvalidCandidateForPoll: This candidate is not valid for this poll. */
 export const VOTING_ERROR__INVALID_CANDIDATE_FOR_POLL = 0x1774; // 6004
+/** PollNotEnded: Poll has not ended yet */
+export const VOTING_ERROR__POLL_NOT_ENDED = 0x1775; // 6005
+/** InvalidCandidateAccounts: Candidate accounts do not match the poll's candidates. */
+export const VOTING_ERROR__INVALID_CANDIDATE_ACCOUNTS = 0x1776; // 6006
+/** Overflow: Arithmetic overflow. */
+export const VOTING_ERROR__OVERFLOW = 0x1777; // 6007
+/** PollMigrationRequired: Poll account uses a legacy layout, run migrate_poll first. */
+export const VOTING_ERROR__POLL_MIGRATION_REQUIRED = 0x1778; // 6008
+/** PollAlreadyMigrated: Poll account is already migrated. */
+export const VOTING_ERROR__POLL_ALREADY_MIGRATED = 0x1779; // 6009
+/** PollAlreadyFinalized: Poll is already finalized. */
+export const VOTING_ERROR__POLL_ALREADY_FINALIZED = 0x177a; // 6010
+/** PollFinalized: Poll is finalized and no longer accepts votes. */
+export const VOTING_ERROR__POLL_FINALIZED = 0x177b; // 6011
+/** PollAlreadyStarted: Poll has already started, only the end time can be extended. */
+export const VOTING_ERROR__POLL_ALREADY_STARTED = 0x177c; // 6012
+/** EndTimeNotExtended: New end time must be later than the current end time. */
+export const VOTING_ERROR__END_TIME_NOT_EXTENDED = 0x177d; // 6013
+/** InvalidSchedule: Start time must be before end time, and end time must be in the future. */
+export const VOTING_ERROR__INVALID_SCHEDULE = 0x177e; // 6014
+/** NameTooLong: Name exceeds 32 bytes. */
+export const VOTING_ERROR__NAME_TOO_LONG = 0x177f; // 6015
+/** DescriptionTooLong: Description exceeds 280 bytes. */
+export const VOTING_ERROR__DESCRIPTION_TOO_LONG = 0x1780; // 6016
+/** EmptyName: Name must not be empty. */
+export const VOTING_ERROR__EMPTY_NAME = 0x1781; // 6017
+/** InvalidPollId: Poll id must equal the authority's current poll count. */
+export const VOTING_ERROR__INVALID_POLL_ID = 0x1782; // 6018
+/** CandidatesNotClosed: Close all candidate accounts before closing the poll. */
+export const VOTING_ERROR__CANDIDATES_NOT_CLOSED = 0x1783; // 6019
+/** PollResultRequired: Poll result account must be closed together with the poll. */
+export const VOTING_ERROR__POLL_RESULT_REQUIRED = 0x1784; // 6020
+/** VoteChangeDisabled: Changing votes is not allowed for this poll. */
+export const VOTING_ERROR__VOTE_CHANGE_DISABLED = 0x1785; // 6021
+/** SameCandidate: The new candidate is the same as the current one. */
+export const VOTING_ERROR__SAME_CANDIDATE = 0x1786; // 6022
+/** VoteRetractionDisabled: Retracting votes is not allowed for this poll. */
+export const VOTING_ERROR__VOTE_RETRACTION_DISABLED = 0x1787; // 6023
+/** InsufficientTokenBalance: Voter does not hold enough of the poll's gate token. */
+export const VOTING_ERROR__INSUFFICIENT_TOKEN_BALANCE = 0x1788; // 6024
+/** InvalidTokenAccount: Token account is not the voter's account for the required mint. */
+export const VOTING_ERROR__INVALID_TOKEN_ACCOUNT = 0x1789; // 6025
+/** WeightedVoteRequired: Weighted polls only accept vote_weighted. */
+export const VOTING_ERROR__WEIGHTED_VOTE_REQUIRED = 0x178a; // 6026
+/** PollNotWeighted: Poll is not a weighted poll. */
+export const VOTING_ERROR__POLL_NOT_WEIGHTED = 0x178b; // 6027
+/** InvalidPollOptions: Poll options are incompatible with each other. */
+export const VOTING_ERROR__INVALID_POLL_OPTIONS = 0x178c; // 6028
+/** TokensNotWithdrawn: Escrowed tokens must be withdrawn first. */
+export const VOTING_ERROR__TOKENS_NOT_WITHDRAWN = 0x178d; // 6029
+/** NftVoteRequired: Collection polls only accept vote_with_nft. */
+export const VOTING_ERROR__NFT_VOTE_REQUIRED = 0x178e; // 6030
+/** PollNotCollectionGated: Poll is not gated by an NFT collection. */
+export const VOTING_ERROR__POLL_NOT_COLLECTION_GATED = 0x178f; // 6031
+/** InvalidNft: Account is not a valid NFT or NFT metadata. */
+export const VOTING_ERROR__INVALID_NFT = 0x1790; // 6032
+/** NftNotInCollection: NFT is not a verified member of the poll's collection. */
+export const VOTING_ERROR__NFT_NOT_IN_COLLECTION = 0x1791; // 6033
+/** NotOnAllowlist: Voter is not on the poll's allowlist. */
+export const VOTING_ERROR__NOT_ON_ALLOWLIST = 0x1792; // 6034
+/** VoterPassRequired: Poll requires a voter pass. */
+export const VOTING_ERROR__VOTER_PASS_REQUIRED = 0x1793; // 6035
+/** VoterPassUsed: Voter pass has already been used and cannot be revoked before the poll ends. */
+export const VOTING_ERROR__VOTER_PASS_USED = 0x1794; // 6036
+/** InvalidPassWeight: Voter pass weight must be greater than zero. */
+export const VOTING_ERROR__INVALID_PASS_WEIGHT = 0x1795; // 6037
+/** InvalidVoterPassAccounts: Voter pass accounts do not match the passes being issued. */
+export const VOTING_ERROR__INVALID_VOTER_PASS_ACCOUNTS = 0x1796; // 6038
+/** TooManySelections: Too many candidates selected for this ballot. */
+export const VOTING_ERROR__TOO_MANY_SELECTIONS = 0x1797; // 6039
+/** DuplicateSelection: The same candidate was selected more than once. */
+export const VOTING_ERROR__DUPLICATE_SELECTION = 0x1798; // 6040
+/** RankedVoteRequired: Ranked polls only accept vote_ranked. */
+export const VOTING_ERROR__RANKED_VOTE_REQUIRED = 0x1799; // 6041
+/** PollNotRanked: Poll does not use ranked ballots. */
+export const VOTING_ERROR__POLL_NOT_RANKED = 0x179a; // 6042
+/** InvalidRankings: Rankings must list at least one of the poll's candidates. */
+export const VOTING_ERROR__INVALID_RANKINGS = 0x179b; // 6043
+/** IrvTallyRequired: Ranked polls must be tallied with tally_irv first. */
+export const VOTING_ERROR__IRV_TALLY_REQUIRED = 0x179c; // 6044
+/** BallotAlreadyCounted: Ballot has already been counted in this round. */
+export const VOTING_ERROR__BALLOT_ALREADY_COUNTED = 0x179d; // 6045
+/** InvalidBallot: Ballot does not belong to this poll. */
+export const VOTING_ERROR__INVALID_BALLOT = 0x179e; // 6046
+/** IrvResultRequired: IRV result account must be closed together with the poll. */
+export const VOTING_ERROR__IRV_RESULT_REQUIRED = 0x179f; // 6047
+/** QuadraticVoteRequired: Quadratic polls only accept vote_quadratic. */
+export const VOTING_ERROR__QUADRATIC_VOTE_REQUIRED = 0x17a0; // 6048
+/** PollNotQuadratic: Poll is not a quadratic poll. */
+export const VOTING_ERROR__POLL_NOT_QUADRATIC = 0x17a1; // 6049
+/** InvalidVoteCount: Vote count must be greater than zero. */
+export const VOTING_ERROR__INVALID_VOTE_COUNT = 0x17a2; // 6050
+/** InsufficientCredits: Not enough voting credits left. */
+export const VOTING_ERROR__INSUFFICIENT_CREDITS = 0x17a3; // 6051
+/** CommitRevealRequired: Commit-reveal polls only accept commit_vote. */
+export const VOTING_ERROR__COMMIT_REVEAL_REQUIRED = 0x17a4; // 6052
+/** PollNotCommitReveal: Poll does not use commit-reveal voting. */
+export const VOTING_ERROR__POLL_NOT_COMMIT_REVEAL = 0x17a5; // 6053
+/** RevealWindowNotOpen: Reveal window opens after the poll ends. */
+export const VOTING_ERROR__REVEAL_WINDOW_NOT_OPEN = 0x17a6; // 6054
+/** RevealWindowClosed: Reveal window has closed. */
+export const VOTING_ERROR__REVEAL_WINDOW_CLOSED = 0x17a7; // 6055
+/** RevealWindowOpen: Reveal window is still open. */
+export const VOTING_ERROR__REVEAL_WINDOW_OPEN = 0x17a8; // 6056
+/** VoteAlreadyRevealed: Vote has already been revealed. */
+export const VOTING_ERROR__VOTE_ALREADY_REVEALED = 0x17a9; // 6057
+/** CommitmentMismatch: Candidate index and salt do not match the commitment. */
+export const VOTING_ERROR__COMMITMENT_MISMATCH = 0x17aa; // 6058
+/** ContestBallotRequired: Polls with contests only accept cast_ballot. */
+export const VOTING_ERROR__CONTEST_BALLOT_REQUIRED = 0x17ab; // 6059
+/** PollHasNoContests: Poll has no contests. */
+export const VOTING_ERROR__POLL_HAS_NO_CONTESTS = 0x17ac; // 6060
+/** InvalidContestChoices: Contest choices must be sorted, unique and non-empty. */
+export const VOTING_ERROR__INVALID_CONTEST_CHOICES = 0x17ad; // 6061
+/** InvalidContestAccounts: Contest or candidate accounts do not match the ballot. */
+export const VOTING_ERROR__INVALID_CONTEST_ACCOUNTS = 0x17ae; // 6062
+/** ContestTallyUnsupported: Polls with contests are counted per contest and cannot be finalized. */
+export const VOTING_ERROR__CONTEST_TALLY_UNSUPPORTED = 0x17af; // 6063
+/** NotPendingAuthority: Signer is not the pending authority. */
+export const VOTING_ERROR__NOT_PENDING_AUTHORITY = 0x17b0; // 6064
+/** MissingAdminRole: Signer does not have the admin role required for this instruction. */
+export const VOTING_ERROR__MISSING_ADMIN_ROLE = 0x17b1; // 6065
+/** TooManyAdmins: Poll already has the maximum number of admins. */
+export const VOTING_ERROR__TOO_MANY_ADMINS = 0x17b2; // 6066
+/** PollPaused: Poll is paused and does not accept votes. */
+export const VOTING_ERROR__POLL_PAUSED = 0x17b3; // 6067
+/** PollCancelled: Poll has been cancelled. */
+export const VOTING_ERROR__POLL_CANCELLED = 0x17b4; // 6068
+/** PollNotPaused: Poll is not paused. */
+export const VOTING_ERROR__POLL_NOT_PAUSED = 0x17b5; // 6069
+/** TallyInProgress: Poll tally has already started. */
+export const VOTING_ERROR__TALLY_IN_PROGRESS = 0x17b6; // 6070
 
 export type VotingError =
+  | typeof VOTING_ERROR__BALLOT_ALREADY_COUNTED
+  | typeof VOTING_ERROR__CANDIDATES_NOT_CLOSED
+  | typeof VOTING_ERROR__COMMITMENT_MISMATCH
+  | typeof VOTING_ERROR__COMMIT_REVEAL_REQUIRED
+  | typeof VOTING_ERROR__CONTEST_BALLOT_REQUIRED
+  | typeof VOTING_ERROR__CONTEST_TALLY_UNSUPPORTED
+  | typeof VOTING_ERROR__DESCRIPTION_TOO_LONG
+  | typeof VOTING_ERROR__DUPLICATE_SELECTION
+  | typeof VOTING_ERROR__EMPTY_NAME
+  | typeof VOTING_ERROR__END_TIME_NOT_EXTENDED
+  | typeof VOTING_ERROR__INSUFFICIENT_CREDITS
+  | typeof VOTING_ERROR__INSUFFICIENT_TOKEN_BALANCE
+  | typeof VOTING_ERROR__INVALID_BALLOT
+  | typeof VOTING_ERROR__INVALID_CANDIDATE_ACCOUNTS
   | typeof VOTING_ERROR__INVALID_CANDIDATE_FOR_POLL
+  | typeof VOTING_ERROR__INVALID_CONTEST_ACCOUNTS
+  | typeof VOTING_ERROR__INVALID_CONTEST_CHOICES
+  | typeof VOTING_ERROR__INVALID_NFT
+  | typeof VOTING_ERROR__INVALID_PASS_WEIGHT
+  | typeof VOTING_ERROR__INVALID_POLL_ID
+  | typeof VOTING_ERROR__INVALID_POLL_OPTIONS
+  | typeof VOTING_ERROR__INVALID_RANKINGS
+  | typeof VOTING_ERROR__INVALID_SCHEDULE
+  | typeof VOTING_ERROR__INVALID_TOKEN_ACCOUNT
+  | typeof VOTING_ERROR__INVALID_VOTER_PASS_ACCOUNTS
+  | typeof VOTING_ERROR__INVALID_VOTE_COUNT
+  | typeof VOTING_ERROR__IRV_RESULT_REQUIRED
+  | typeof VOTING_ERROR__IRV_TALLY_REQUIRED
   | typeof VOTING_ERROR__MAX_CANDIDATES_REACHED
+  | typeof VOTING_ERROR__MISSING_ADMIN_ROLE
+  | typeof VOTING_ERROR__NAME_TOO_LONG
+  | typeof VOTING_ERROR__NFT_NOT_IN_COLLECTION
+  | typeof VOTING_ERROR__NFT_VOTE_REQUIRED
+  | typeof VOTING_ERROR__NOT_ON_ALLOWLIST
+  | typeof VOTING_ERROR__NOT_PENDING_AUTHORITY
+  | typeof VOTING_ERROR__OVERFLOW
+  | typeof VOTING_ERROR__POLL_ALREADY_FINALIZED
+  | typeof VOTING_ERROR__POLL_ALREADY_MIGRATED
+  | typeof VOTING_ERROR__POLL_ALREADY_STARTED
+  | typeof VOTING_ERROR__POLL_CANCELLED
   | typeof VOTING_ERROR__POLL_ENDED
+  | typeof VOTING_ERROR__POLL_FINALIZED
+  | typeof VOTING_ERROR__POLL_HAS_NO_CONTESTS
+  | typeof VOTING_ERROR__POLL_MIGRATION_REQUIRED
+  | typeof VOTING_ERROR__POLL_NOT_COLLECTION_GATED
+  | typeof VOTING_ERROR__POLL_NOT_COMMIT_REVEAL
+  | typeof VOTING_ERROR__POLL_NOT_ENDED
+  | typeof VOTING_ERROR__POLL_NOT_PAUSED
+  | typeof VOTING_ERROR__POLL_NOT_QUADRATIC
+  | typeof VOTING_ERROR__POLL_NOT_RANKED
   | typeof VOTING_ERROR__POLL_NOT_STARTED
-  | typeof VOTING_ERROR__UNAUTHORIZED;
+  | typeof VOTING_ERROR__POLL_NOT_WEIGHTED
+  | typeof VOTING_ERROR__POLL_PAUSED
+  | typeof VOTING_ERROR__POLL_RESULT_REQUIRED
+  | typeof VOTING_ERROR__QUADRATIC_VOTE_REQUIRED
+  | typeof VOTING_ERROR__RANKED_VOTE_REQUIRED
+  | typeof VOTING_ERROR__REVEAL_WINDOW_CLOSED
+  | typeof VOTING_ERROR__REVEAL_WINDOW_NOT_OPEN
+  | typeof VOTING_ERROR__REVEAL_WINDOW_OPEN
+  | typeof VOTING_ERROR__SAME_CANDIDATE
+  | typeof VOTING_ERROR__TALLY_IN_PROGRESS
+  | typeof VOTING_ERROR__TOKENS_NOT_WITHDRAWN
+  | typeof VOTING_ERROR__TOO_MANY_ADMINS
+  | typeof VOTING_ERROR__TOO_MANY_SELECTIONS
+  | typeof VOTING_ERROR__UNAUTHORIZED
+  | typeof VOTING_ERROR__VOTER_PASS_REQUIRED
+  | typeof VOTING_ERROR__VOTER_PASS_USED
+  | typeof VOTING_ERROR__VOTE_ALREADY_REVEALED
+  | typeof VOTING_ERROR__VOTE_CHANGE_DISABLED
+  | typeof VOTING_ERROR__VOTE_RETRACTION_DISABLED
+  | typeof VOTING_ERROR__WEIGHTED_VOTE_REQUIRED;
 
 let votingErrorMessages: Record<VotingError, string> | undefined;
 if (process.env.NODE_ENV !== "production") {
   votingErrorMessages = {
+    [VOTING_ERROR__BALLOT_ALREADY_COUNTED]: `Ballot has already been counted in this round.`,
+    [VOTING_ERROR__CANDIDATES_NOT_CLOSED]: `Close all candidate accounts before closing the poll.`,
+    [VOTING_ERROR__COMMITMENT_MISMATCH]: `Candidate index and salt do not match the commitment.`,
+    [VOTING_ERROR__COMMIT_REVEAL_REQUIRED]: `Commit-reveal polls only accept commit_vote.`,
+    [VOTING_ERROR__CONTEST_BALLOT_REQUIRED]: `Polls with contests only accept cast_ballot.`,
+    [VOTING_ERROR__CONTEST_TALLY_UNSUPPORTED]: `Polls with contests are counted per contest and cannot be finalized.`,
+    [VOTING_ERROR__DESCRIPTION_TOO_LONG]: `Description exceeds 280 bytes.`,
+    [VOTING_ERROR__DUPLICATE_SELECTION]: `The same candidate was selected more than once.`,
+    [VOTING_ERROR__EMPTY_NAME]: `Name must not be empty.`,
+    [VOTING_ERROR__END_TIME_NOT_EXTENDED]: `New end time must be later than the current end time.`,
+    [VOTING_ERROR__INSUFFICIENT_CREDITS]: `Not enough voting credits left.`,
+    [VOTING_ERROR__INSUFFICIENT_TOKEN_BALANCE]: `Voter does not hold enough of the poll's gate token.`,
+    [VOTING_ERROR__INVALID_BALLOT]: `Ballot does not belong to this poll.`,
+    [VOTING_ERROR__INVALID_CANDIDATE_ACCOUNTS]: `Candidate accounts do not match the poll's candidates.`,
     [VOTING_ERROR__INVALID_CANDIDATE_FOR_POLL]: `This candidate is not valid for this poll.`,
+    [VOTING_ERROR__INVALID_CONTEST_ACCOUNTS]: `Contest or candidate accounts do not match the ballot.`,
+    [VOTING_ERROR__INVALID_CONTEST_CHOICES]: `Contest choices must be sorted, unique and non-empty.`,
+    [VOTING_ERROR__INVALID_NFT]: `Account is not a valid NFT or NFT metadata.`,
+    [VOTING_ERROR__INVALID_PASS_WEIGHT]: `Voter pass weight must be greater than zero.`,
+    [VOTING_ERROR__INVALID_POLL_ID]: `Poll id must equal the authority's current poll count.`,
+    [VOTING_ERROR__INVALID_POLL_OPTIONS]: `Poll options are incompatible with each other.`,
+    [VOTING_ERROR__INVALID_RANKINGS]: `Rankings must list at least one of the poll's candidates.`,
+    [VOTING_ERROR__INVALID_SCHEDULE]: `Start time must be before end time, and end time must be in the future.`,
+    [VOTING_ERROR__INVALID_TOKEN_ACCOUNT]: `Token account is not the voter's account for the required mint.`,
+    [VOTING_ERROR__INVALID_VOTER_PASS_ACCOUNTS]: `Voter pass accounts do not match the passes being issued.`,
+    [VOTING_ERROR__INVALID_VOTE_COUNT]: `Vote count must be greater than zero.`,
+    [VOTING_ERROR__IRV_RESULT_REQUIRED]: `IRV result account must be closed together with the poll.`,
+    [VOTING_ERROR__IRV_TALLY_REQUIRED]: `Ranked polls must be tallied with tally_irv first.`,
     [VOTING_ERROR__MAX_CANDIDATES_REACHED]: `Maximum number of candidates reached.`,
+    [VOTING_ERROR__MISSING_ADMIN_ROLE]: `Signer does not have the admin role required for this instruction.`,
+    [VOTING_ERROR__NAME_TOO_LONG]: `Name exceeds 32 bytes.`,
+    [VOTING_ERROR__NFT_NOT_IN_COLLECTION]: `NFT is not a verified member of the poll's collection.`,
+    [VOTING_ERROR__NFT_VOTE_REQUIRED]: `Collection polls only accept vote_with_nft.`,
+    [VOTING_ERROR__NOT_ON_ALLOWLIST]: `Voter is not on the poll's allowlist.`,
+    [VOTING_ERROR__NOT_PENDING_AUTHORITY]: `Signer is not the pending authority.`,
+    [VOTING_ERROR__OVERFLOW]: `Arithmetic overflow.`,
+    [VOTING_ERROR__POLL_ALREADY_FINALIZED]: `Poll is already finalized.`,
+    [VOTING_ERROR__POLL_ALREADY_MIGRATED]: `Poll account is already migrated.`,
+    [VOTING_ERROR__POLL_ALREADY_STARTED]: `Poll has already started, only the end time can be extended.`,
+    [VOTING_ERROR__POLL_CANCELLED]: `Poll has been cancelled.`,
     [VOTING_ERROR__POLL_ENDED]: `Poll ended`,
+    [VOTING_ERROR__POLL_FINALIZED]: `Poll is finalized and no longer accepts votes.`,
+    [VOTING_ERROR__POLL_HAS_NO_CONTESTS]: `Poll has no contests.`,
+    [VOTING_ERROR__POLL_MIGRATION_REQUIRED]: `Poll account uses a legacy layout, run migrate_poll first.`,
+    [VOTING_ERROR__POLL_NOT_COLLECTION_GATED]: `Poll is not gated by an NFT collection.`,
+    [VOTING_ERROR__POLL_NOT_COMMIT_REVEAL]: `Poll does not use commit-reveal voting.`,
+    [VOTING_ERROR__POLL_NOT_ENDED]: `Poll has not ended yet`,
+    [VOTING_ERROR__POLL_NOT_PAUSED]: `Poll is not paused.`,
+    [VOTING_ERROR__POLL_NOT_QUADRATIC]: `Poll is not a quadratic poll.`,
+    [VOTING_ERROR__POLL_NOT_RANKED]: `Poll does not use ranked ballots.`,
     [VOTING_ERROR__POLL_NOT_STARTED]: `Poll not started yet`,
+    [VOTING_ERROR__POLL_NOT_WEIGHTED]: `Poll is not a weighted poll.`,
+    [VOTING_ERROR__POLL_PAUSED]: `Poll is paused and does not accept votes.`,
+    [VOTING_ERROR__POLL_RESULT_REQUIRED]: `Poll result account must be closed together with the poll.`,
+    [VOTING_ERROR__QUADRATIC_VOTE_REQUIRED]: `Quadratic polls only accept vote_quadratic.`,
+    [VOTING_ERROR__RANKED_VOTE_REQUIRED]: `Ranked polls only accept vote_ranked.`,
+    [VOTING_ERROR__REVEAL_WINDOW_CLOSED]: `Reveal window has closed.`,
+    [VOTING_ERROR__REVEAL_WINDOW_NOT_OPEN]: `Reveal window opens after the poll ends.`,
+    [VOTING_ERROR__REVEAL_WINDOW_OPEN]: `Reveal window is still open.`,
+    [VOTING_ERROR__SAME_CANDIDATE]: `The new candidate is the same as the current one.`,
+    [VOTING_ERROR__TALLY_IN_PROGRESS]: `Poll tally has already started.`,
+    [VOTING_ERROR__TOKENS_NOT_WITHDRAWN]: `Escrowed tokens must be withdrawn first.`,
+    [VOTING_ERROR__TOO_MANY_ADMINS]: `Poll already has the maximum number of admins.`,
+    [VOTING_ERROR__TOO_MANY_SELECTIONS]: `Too many candidates selected for this ballot.`,
     [VOTING_ERROR__UNAUTHORIZED]: `Unauthorized: Only the poll authority can perform this action.`,
+    [VOTING_ERROR__VOTER_PASS_REQUIRED]: `Poll requires a voter pass.`,
+    [VOTING_ERROR__VOTER_PASS_USED]: `Voter pass has already been used and cannot be revoked before the poll ends.`,
+    [VOTING_ERROR__VOTE_ALREADY_REVEALED]: `Vote has already been revealed.`,
+    [VOTING_ERROR__VOTE_CHANGE_DISABLED]: `Changing votes is not allowed for this poll.`,
+    [VOTING_ERROR__VOTE_RETRACTION_DISABLED]: `Retracting votes is not allowed for this poll.`,
+    [VOTING_ERROR__WEIGHTED_VOTE_REQUIRED]: `Weighted polls only accept vote_weighted.`,
   };
 }
 
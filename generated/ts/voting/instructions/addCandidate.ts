@@ -14,6 +14,7 @@ import {
   fixEncoderSize,
   getBytesDecoder,
   getBytesEncoder,
+  getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   getU32Decoder,
@@ -57,6 +58,8 @@ export type AddCandidateInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = "11111111111111111111111111111111",
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -75,6 +78,12 @@ export type AddCandidateInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -116,16 +125,135 @@ export function getAddCandidateInstructionDataCodec(): Codec<
   );
 }
 
+export type AddCandidateAsyncInput<
+  TAccountSigner extends string = string,
+  TAccountPollAccount extends string = string,
+  TAccountCandidateAccount extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
+> = {
+  signer: TransactionSigner<TAccountSigner>;
+  pollAccount: Address<TAccountPollAccount>;
+  candidateAccount: Address<TAccountCandidateAccount>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
+  candidateName: AddCandidateInstructionDataArgs["candidateName"];
+};
+
+export async function getAddCandidateInstructionAsync<
+  TAccountSigner extends string,
+  TAccountPollAccount extends string,
+  TAccountCandidateAccount extends string,
+  TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
+  TProgramAddress extends Address = typeof VOTING_PROGRAM_ADDRESS,
+>(
+  input: AddCandidateAsyncInput<
+    TAccountSigner,
+    TAccountPollAccount,
+    TAccountCandidateAccount,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
+  >,
+  config?: { programAddress?: TProgramAddress },
+): Promise<
+  AddCandidateInstruction<
+    TProgramAddress,
+    TAccountSigner,
+    TAccountPollAccount,
+    TAccountCandidateAccount,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
+  >
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? VOTING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    signer: { value: input.signer ?? null, isWritable: true },
+    pollAccount: { value: input.pollAccount ?? null, isWritable: true },
+    candidateAccount: {
+      value: input.candidateAccount ?? null,
+      isWritable: true,
+    },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      "11111111111111111111111111111111" as Address<"11111111111111111111111111111111">;
+  }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ]),
+        ),
+      ],
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.signer),
+      getAccountMeta(accounts.pollAccount),
+      getAccountMeta(accounts.candidateAccount),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
+    ],
+    programAddress,
+    data: getAddCandidateInstructionDataEncoder().encode(
+      args as AddCandidateInstructionDataArgs,
+    ),
+  } as AddCandidateInstruction<
+    TProgramAddress,
+    TAccountSigner,
+    TAccountPollAccount,
+    TAccountCandidateAccount,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
+  >;
+
+  return instruction;
+}
+
 export type AddCandidateInput<
   TAccountSigner extends string = string,
   TAccountPollAccount extends string = string,
   TAccountCandidateAccount extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   pollAccount: Address<TAccountPollAccount>;
   candidateAccount: Address<TAccountCandidateAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   candidateName: AddCandidateInstructionDataArgs["candidateName"];
 };
 
@@ -134,13 +262,17 @@ export function getAddCandidateInstruction<
   TAccountPollAccount extends string,
   TAccountCandidateAccount extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof VOTING_PROGRAM_ADDRESS,
 >(
   input: AddCandidateInput<
     TAccountSigner,
     TAccountPollAccount,
     TAccountCandidateAccount,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): AddCandidateInstruction<
@@ -148,7 +280,9 @@ export function getAddCandidateInstruction<
   TAccountSigner,
   TAccountPollAccount,
   TAccountCandidateAccount,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? VOTING_PROGRAM_ADDRESS;
@@ -162,6 +296,8 @@ export function getAddCandidateInstruction<
       isWritable: true,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -184,6 +320,8 @@ export function getAddCandidateInstruction<
       getAccountMeta(accounts.pollAccount),
       getAccountMeta(accounts.candidateAccount),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     programAddress,
     data: getAddCandidateInstructionDataEncoder().encode(
@@ -194,7 +332,9 @@ export function getAddCandidateInstruction<
     TAccountSigner,
     TAccountPollAccount,
     TAccountCandidateAccount,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >;
 
   return instruction;
@@ -210,6 +350,8 @@ export type ParsedAddCandidateInstruction<
     pollAccount: TAccountMetas[1];
     candidateAccount: TAccountMetas[2];
     systemProgram: TAccountMetas[3];
+    eventAuthority: TAccountMetas[4];
+    program: TAccountMetas[5];
   };
   data: AddCandidateInstructionData;
 };
@@ -222,7 +364,7 @@ export function parseAddCandidateInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedAddCandidateInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -239,6 +381,8 @@ export function parseAddCandidateInstruction<
       pollAccount: getNextAccount(),
       candidateAccount: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getAddCandidateInstructionDataDecoder().decode(instruction.data),
   };
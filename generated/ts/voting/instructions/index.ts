@@ -6,6 +6,36 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from "./acceptAuthority";
 export * from "./addCandidate";
+export * from "./addContest";
+export * from "./addContestCandidate";
+export * from "./cancelPoll";
+export * from "./castBallot";
+export * from "./changeVote";
+export * from "./closeBallot";
+export * from "./closeCandidates";
+export * from "./closeNftReceipt";
+export * from "./closePoll";
+export * from "./closeReceipt";
+export * from "./commitVote";
+export * from "./finalizePoll";
 export * from "./initializePoll";
+export * from "./issueVoterPass";
+export * from "./issueVoterPasses";
+export * from "./migratePoll";
+export * from "./pausePoll";
+export * from "./proposeAuthority";
+export * from "./resumePoll";
+export * from "./retractVote";
+export * from "./revealVote";
+export * from "./revokeVoterPass";
+export * from "./setAdmin";
+export * from "./tallyIrv";
+export * from "./updatePoll";
 export * from "./vote";
+export * from "./voteQuadratic";
+export * from "./voteRanked";
+export * from "./voteWeighted";
+export * from "./voteWithNft";
+export * from "./withdrawTokens";
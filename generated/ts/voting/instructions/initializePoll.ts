@@ -12,8 +12,10 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   getU32Decoder,
@@ -35,10 +37,22 @@ import {
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
+  type WritableAccount,
   type WritableSignerAccount,
 } from "@solana/kit";
 import { VOTING_PROGRAM_ADDRESS } from "../programs";
-import { getAccountMetaFactory, type ResolvedAccount } from "../shared";
+import {
+  expectAddress,
+  expectSome,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from "../shared";
+import {
+  getPollOptionsDecoder,
+  getPollOptionsEncoder,
+  type PollOptions,
+  type PollOptionsArgs,
+} from "../types";
 
 export const INITIALIZE_POLL_DISCRIMINATOR = new Uint8Array([
   193, 22, 99, 197, 18, 33, 115, 117,
@@ -54,9 +68,12 @@ export type InitializePollInstruction<
   TProgram extends string = typeof VOTING_PROGRAM_ADDRESS,
   TAccountSigner extends string | AccountMeta<string> = string,
   TAccountPollAccount extends string | AccountMeta<string> = string,
+  TAccountPollCounter extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = "11111111111111111111111111111111",
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -67,39 +84,53 @@ export type InitializePollInstruction<
             AccountSignerMeta<TAccountSigner>
         : TAccountSigner,
       TAccountPollAccount extends string
-        ? WritableSignerAccount<TAccountPollAccount> &
-            AccountSignerMeta<TAccountPollAccount>
+        ? WritableAccount<TAccountPollAccount>
         : TAccountPollAccount,
+      TAccountPollCounter extends string
+        ? WritableAccount<TAccountPollCounter>
+        : TAccountPollCounter,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
 
 export type InitializePollInstructionData = {
   discriminator: ReadonlyUint8Array;
+  pollId: bigint;
   name: string;
   description: string;
   startTime: bigint;
   endTime: bigint;
+  options: PollOptions;
 };
 
 export type InitializePollInstructionDataArgs = {
+  pollId: number | bigint;
   name: string;
   description: string;
   startTime: number | bigint;
   endTime: number | bigint;
+  options: PollOptionsArgs;
 };
 
 export function getInitializePollInstructionDataEncoder(): Encoder<InitializePollInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
+      ["pollId", getU64Encoder()],
       ["name", addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ["description", addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ["startTime", getU64Encoder()],
       ["endTime", getU64Encoder()],
+      ["options", getPollOptionsEncoder()],
     ]),
     (value) => ({ ...value, discriminator: INITIALIZE_POLL_DISCRIMINATOR }),
   );
@@ -108,10 +139,12 @@ export function getInitializePollInstructionDataEncoder(): Encoder<InitializePol
 export function getInitializePollInstructionDataDecoder(): Decoder<InitializePollInstructionData> {
   return getStructDecoder([
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
+    ["pollId", getU64Decoder()],
     ["name", addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ["description", addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ["startTime", getU64Decoder()],
     ["endTime", getU64Decoder()],
+    ["options", getPollOptionsDecoder()],
   ]);
 }
 
@@ -125,37 +158,194 @@ export function getInitializePollInstructionDataCodec(): Codec<
   );
 }
 
+export type InitializePollAsyncInput<
+  TAccountSigner extends string = string,
+  TAccountPollAccount extends string = string,
+  TAccountPollCounter extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
+> = {
+  signer: TransactionSigner<TAccountSigner>;
+  pollAccount?: Address<TAccountPollAccount>;
+  pollCounter?: Address<TAccountPollCounter>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
+  pollId: InitializePollInstructionDataArgs["pollId"];
+  name: InitializePollInstructionDataArgs["name"];
+  description: InitializePollInstructionDataArgs["description"];
+  startTime: InitializePollInstructionDataArgs["startTime"];
+  endTime: InitializePollInstructionDataArgs["endTime"];
+  options: InitializePollInstructionDataArgs["options"];
+};
+
+export async function getInitializePollInstructionAsync<
+  TAccountSigner extends string,
+  TAccountPollAccount extends string,
+  TAccountPollCounter extends string,
+  TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
+  TProgramAddress extends Address = typeof VOTING_PROGRAM_ADDRESS,
+>(
+  input: InitializePollAsyncInput<
+    TAccountSigner,
+    TAccountPollAccount,
+    TAccountPollCounter,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
+  >,
+  config?: { programAddress?: TProgramAddress },
+): Promise<
+  InitializePollInstruction<
+    TProgramAddress,
+    TAccountSigner,
+    TAccountPollAccount,
+    TAccountPollCounter,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
+  >
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? VOTING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    signer: { value: input.signer ?? null, isWritable: true },
+    pollAccount: { value: input.pollAccount ?? null, isWritable: true },
+    pollCounter: { value: input.pollCounter ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.pollAccount.value) {
+    accounts.pollAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(new Uint8Array([112, 111, 108, 108])),
+        getAddressEncoder().encode(expectAddress(accounts.signer.value)),
+        getU64Encoder().encode(expectSome(args.pollId)),
+      ],
+    });
+  }
+  if (!accounts.pollCounter.value) {
+    accounts.pollCounter.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            112, 111, 108, 108, 95, 99, 111, 117, 110, 116, 101, 114,
+          ]),
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.signer.value)),
+      ],
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      "11111111111111111111111111111111" as Address<"11111111111111111111111111111111">;
+  }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ]),
+        ),
+      ],
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.signer),
+      getAccountMeta(accounts.pollAccount),
+      getAccountMeta(accounts.pollCounter),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
+    ],
+    programAddress,
+    data: getInitializePollInstructionDataEncoder().encode(
+      args as InitializePollInstructionDataArgs,
+    ),
+  } as InitializePollInstruction<
+    TProgramAddress,
+    TAccountSigner,
+    TAccountPollAccount,
+    TAccountPollCounter,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
+  >;
+
+  return instruction;
+}
+
 export type InitializePollInput<
   TAccountSigner extends string = string,
   TAccountPollAccount extends string = string,
+  TAccountPollCounter extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
-  pollAccount: TransactionSigner<TAccountPollAccount>;
+  pollAccount: Address<TAccountPollAccount>;
+  pollCounter: Address<TAccountPollCounter>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
+  pollId: InitializePollInstructionDataArgs["pollId"];
   name: InitializePollInstructionDataArgs["name"];
   description: InitializePollInstructionDataArgs["description"];
   startTime: InitializePollInstructionDataArgs["startTime"];
   endTime: InitializePollInstructionDataArgs["endTime"];
+  options: InitializePollInstructionDataArgs["options"];
 };
 
 export function getInitializePollInstruction<
   TAccountSigner extends string,
   TAccountPollAccount extends string,
+  TAccountPollCounter extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof VOTING_PROGRAM_ADDRESS,
 >(
   input: InitializePollInput<
     TAccountSigner,
     TAccountPollAccount,
-    TAccountSystemProgram
+    TAccountPollCounter,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): InitializePollInstruction<
   TProgramAddress,
   TAccountSigner,
   TAccountPollAccount,
-  TAccountSystemProgram
+  TAccountPollCounter,
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? VOTING_PROGRAM_ADDRESS;
@@ -164,7 +354,10 @@ export function getInitializePollInstruction<
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
     pollAccount: { value: input.pollAccount ?? null, isWritable: true },
+    pollCounter: { value: input.pollCounter ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -185,7 +378,10 @@ export function getInitializePollInstruction<
     accounts: [
       getAccountMeta(accounts.signer),
       getAccountMeta(accounts.pollAccount),
+      getAccountMeta(accounts.pollCounter),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     programAddress,
     data: getInitializePollInstructionDataEncoder().encode(
@@ -195,7 +391,10 @@ export function getInitializePollInstruction<
     TProgramAddress,
     TAccountSigner,
     TAccountPollAccount,
-    TAccountSystemProgram
+    TAccountPollCounter,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >;
 
   return instruction;
@@ -209,7 +408,10 @@ export type ParsedInitializePollInstruction<
   accounts: {
     signer: TAccountMetas[0];
     pollAccount: TAccountMetas[1];
-    systemProgram: TAccountMetas[2];
+    pollCounter: TAccountMetas[2];
+    systemProgram: TAccountMetas[3];
+    eventAuthority: TAccountMetas[4];
+    program: TAccountMetas[5];
   };
   data: InitializePollInstructionData;
 };
@@ -222,7 +424,7 @@ export function parseInitializePollInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedInitializePollInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -237,7 +439,10 @@ export function parseInitializePollInstruction<
     accounts: {
       signer: getNextAccount(),
       pollAccount: getNextAccount(),
+      pollCounter: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getInitializePollInstructionDataDecoder().decode(instruction.data),
   };
@@ -13,19 +13,25 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -38,6 +44,12 @@ import {
   getAccountMetaFactory,
   type ResolvedAccount,
 } from "../shared";
+import {
+  getAllowlistProofDecoder,
+  getAllowlistProofEncoder,
+  type AllowlistProof,
+  type AllowlistProofArgs,
+} from "../types";
 
 export const VOTE_DISCRIMINATOR = new Uint8Array([
   227, 110, 155, 23, 136, 126, 172, 25,
@@ -53,9 +65,13 @@ export type VoteInstruction<
   TAccountPollAccount extends string | AccountMeta<string> = string,
   TAccountCandidateAccount extends string | AccountMeta<string> = string,
   TAccountVoterReceipt extends string | AccountMeta<string> = string,
+  TAccountVoterTokenAccount extends string | AccountMeta<string> = string,
+  TAccountVoterPass extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = "11111111111111111111111111111111",
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -74,31 +90,56 @@ export type VoteInstruction<
       TAccountVoterReceipt extends string
         ? WritableAccount<TAccountVoterReceipt>
         : TAccountVoterReceipt,
+      TAccountVoterTokenAccount extends string
+        ? ReadonlyAccount<TAccountVoterTokenAccount>
+        : TAccountVoterTokenAccount,
+      TAccountVoterPass extends string
+        ? WritableAccount<TAccountVoterPass>
+        : TAccountVoterPass,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
 
-export type VoteInstructionData = { discriminator: ReadonlyUint8Array };
+export type VoteInstructionData = {
+  discriminator: ReadonlyUint8Array;
+  candidateIndex: number;
+  allowlistProof: Option<AllowlistProof>;
+};
 
-export type VoteInstructionDataArgs = {};
+export type VoteInstructionDataArgs = {
+  candidateIndex: number;
+  allowlistProof: OptionOrNullable<AllowlistProofArgs>;
+};
 
-export function getVoteInstructionDataEncoder(): FixedSizeEncoder<VoteInstructionDataArgs> {
+export function getVoteInstructionDataEncoder(): Encoder<VoteInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([["discriminator", fixEncoderSize(getBytesEncoder(), 8)]]),
+    getStructEncoder([
+      ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
+      ["candidateIndex", getU32Encoder()],
+      ["allowlistProof", getOptionEncoder(getAllowlistProofEncoder())],
+    ]),
     (value) => ({ ...value, discriminator: VOTE_DISCRIMINATOR }),
   );
 }
 
-export function getVoteInstructionDataDecoder(): FixedSizeDecoder<VoteInstructionData> {
+export function getVoteInstructionDataDecoder(): Decoder<VoteInstructionData> {
   return getStructDecoder([
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
+    ["candidateIndex", getU32Decoder()],
+    ["allowlistProof", getOptionDecoder(getAllowlistProofDecoder())],
   ]);
 }
 
-export function getVoteInstructionDataCodec(): FixedSizeCodec<
+export function getVoteInstructionDataCodec(): Codec<
   VoteInstructionDataArgs,
   VoteInstructionData
 > {
@@ -113,13 +154,23 @@ export type VoteAsyncInput<
   TAccountPollAccount extends string = string,
   TAccountCandidateAccount extends string = string,
   TAccountVoterReceipt extends string = string,
+  TAccountVoterTokenAccount extends string = string,
+  TAccountVoterPass extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   pollAccount: Address<TAccountPollAccount>;
   candidateAccount: Address<TAccountCandidateAccount>;
   voterReceipt?: Address<TAccountVoterReceipt>;
+  voterTokenAccount?: Address<TAccountVoterTokenAccount>;
+  voterPass?: Address<TAccountVoterPass>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
+  candidateIndex: VoteInstructionDataArgs["candidateIndex"];
+  allowlistProof: VoteInstructionDataArgs["allowlistProof"];
 };
 
 export async function getVoteInstructionAsync<
@@ -127,7 +178,11 @@ export async function getVoteInstructionAsync<
   TAccountPollAccount extends string,
   TAccountCandidateAccount extends string,
   TAccountVoterReceipt extends string,
+  TAccountVoterTokenAccount extends string,
+  TAccountVoterPass extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof VOTING_PROGRAM_ADDRESS,
 >(
   input: VoteAsyncInput<
@@ -135,7 +190,11 @@ export async function getVoteInstructionAsync<
     TAccountPollAccount,
     TAccountCandidateAccount,
     TAccountVoterReceipt,
-    TAccountSystemProgram
+    TAccountVoterTokenAccount,
+    TAccountVoterPass,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): Promise<
@@ -145,7 +204,11 @@ export async function getVoteInstructionAsync<
     TAccountPollAccount,
     TAccountCandidateAccount,
     TAccountVoterReceipt,
-    TAccountSystemProgram
+    TAccountVoterTokenAccount,
+    TAccountVoterPass,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -160,13 +223,23 @@ export async function getVoteInstructionAsync<
       isWritable: true,
     },
     voterReceipt: { value: input.voterReceipt ?? null, isWritable: true },
+    voterTokenAccount: {
+      value: input.voterTokenAccount ?? null,
+      isWritable: false,
+    },
+    voterPass: { value: input.voterPass ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.voterReceipt.value) {
     accounts.voterReceipt.value = await getProgramDerivedAddress({
@@ -180,10 +253,33 @@ export async function getVoteInstructionAsync<
       ],
     });
   }
+  if (!accounts.voterPass.value) {
+    accounts.voterPass.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(new Uint8Array([112, 97, 115, 115])),
+        getAddressEncoder().encode(expectAddress(accounts.pollAccount.value)),
+        getAddressEncoder().encode(expectAddress(accounts.signer.value)),
+      ],
+    });
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       "11111111111111111111111111111111" as Address<"11111111111111111111111111111111">;
   }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ]),
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
   const instruction = {
@@ -192,17 +288,27 @@ export async function getVoteInstructionAsync<
       getAccountMeta(accounts.pollAccount),
       getAccountMeta(accounts.candidateAccount),
       getAccountMeta(accounts.voterReceipt),
+      getAccountMeta(accounts.voterTokenAccount),
+      getAccountMeta(accounts.voterPass),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     programAddress,
-    data: getVoteInstructionDataEncoder().encode({}),
+    data: getVoteInstructionDataEncoder().encode(
+      args as VoteInstructionDataArgs,
+    ),
   } as VoteInstruction<
     TProgramAddress,
     TAccountSigner,
     TAccountPollAccount,
     TAccountCandidateAccount,
     TAccountVoterReceipt,
-    TAccountSystemProgram
+    TAccountVoterTokenAccount,
+    TAccountVoterPass,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >;
 
   return instruction;
@@ -213,13 +319,23 @@ export type VoteInput<
   TAccountPollAccount extends string = string,
   TAccountCandidateAccount extends string = string,
   TAccountVoterReceipt extends string = string,
+  TAccountVoterTokenAccount extends string = string,
+  TAccountVoterPass extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   pollAccount: Address<TAccountPollAccount>;
   candidateAccount: Address<TAccountCandidateAccount>;
   voterReceipt: Address<TAccountVoterReceipt>;
+  voterTokenAccount?: Address<TAccountVoterTokenAccount>;
+  voterPass?: Address<TAccountVoterPass>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
+  candidateIndex: VoteInstructionDataArgs["candidateIndex"];
+  allowlistProof: VoteInstructionDataArgs["allowlistProof"];
 };
 
 export function getVoteInstruction<
@@ -227,7 +343,11 @@ export function getVoteInstruction<
   TAccountPollAccount extends string,
   TAccountCandidateAccount extends string,
   TAccountVoterReceipt extends string,
+  TAccountVoterTokenAccount extends string,
+  TAccountVoterPass extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof VOTING_PROGRAM_ADDRESS,
 >(
   input: VoteInput<
@@ -235,7 +355,11 @@ export function getVoteInstruction<
     TAccountPollAccount,
     TAccountCandidateAccount,
     TAccountVoterReceipt,
-    TAccountSystemProgram
+    TAccountVoterTokenAccount,
+    TAccountVoterPass,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): VoteInstruction<
@@ -244,7 +368,11 @@ export function getVoteInstruction<
   TAccountPollAccount,
   TAccountCandidateAccount,
   TAccountVoterReceipt,
-  TAccountSystemProgram
+  TAccountVoterTokenAccount,
+  TAccountVoterPass,
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? VOTING_PROGRAM_ADDRESS;
@@ -258,13 +386,23 @@ export function getVoteInstruction<
       isWritable: true,
     },
     voterReceipt: { value: input.voterReceipt ?? null, isWritable: true },
+    voterTokenAccount: {
+      value: input.voterTokenAccount ?? null,
+      isWritable: false,
+    },
+    voterPass: { value: input.voterPass ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
@@ -278,17 +416,27 @@ export function getVoteInstruction<
       getAccountMeta(accounts.pollAccount),
       getAccountMeta(accounts.candidateAccount),
       getAccountMeta(accounts.voterReceipt),
+      getAccountMeta(accounts.voterTokenAccount),
+      getAccountMeta(accounts.voterPass),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     programAddress,
-    data: getVoteInstructionDataEncoder().encode({}),
+    data: getVoteInstructionDataEncoder().encode(
+      args as VoteInstructionDataArgs,
+    ),
   } as VoteInstruction<
     TProgramAddress,
     TAccountSigner,
     TAccountPollAccount,
     TAccountCandidateAccount,
     TAccountVoterReceipt,
-    TAccountSystemProgram
+    TAccountVoterTokenAccount,
+    TAccountVoterPass,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >;
 
   return instruction;
@@ -304,7 +452,11 @@ export type ParsedVoteInstruction<
     pollAccount: TAccountMetas[1];
     candidateAccount: TAccountMetas[2];
     voterReceipt: TAccountMetas[3];
-    systemProgram: TAccountMetas[4];
+    voterTokenAccount?: TAccountMetas[4] | undefined;
+    voterPass?: TAccountMetas[5] | undefined;
+    systemProgram: TAccountMetas[6];
+    eventAuthority: TAccountMetas[7];
+    program: TAccountMetas[8];
   };
   data: VoteInstructionData;
 };
@@ -317,7 +469,7 @@ export function parseVoteInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedVoteInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -327,6 +479,12 @@ export function parseVoteInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === VOTING_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -334,7 +492,11 @@ export function parseVoteInstruction<
       pollAccount: getNextAccount(),
       candidateAccount: getNextAccount(),
       voterReceipt: getNextAccount(),
+      voterTokenAccount: getNextOptionalAccount(),
+      voterPass: getNextOptionalAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getVoteInstructionDataDecoder().decode(instruction.data),
   };
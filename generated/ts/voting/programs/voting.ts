@@ -14,9 +14,39 @@ import {
   type ReadonlyUint8Array,
 } from "@solana/kit";
 import {
+  type ParsedAcceptAuthorityInstruction,
   type ParsedAddCandidateInstruction,
+  type ParsedAddContestCandidateInstruction,
+  type ParsedAddContestInstruction,
+  type ParsedCancelPollInstruction,
+  type ParsedCastBallotInstruction,
+  type ParsedChangeVoteInstruction,
+  type ParsedCloseBallotInstruction,
+  type ParsedCloseCandidatesInstruction,
+  type ParsedCloseNftReceiptInstruction,
+  type ParsedClosePollInstruction,
+  type ParsedCloseReceiptInstruction,
+  type ParsedCommitVoteInstruction,
+  type ParsedFinalizePollInstruction,
   type ParsedInitializePollInstruction,
+  type ParsedIssueVoterPassesInstruction,
+  type ParsedIssueVoterPassInstruction,
+  type ParsedMigratePollInstruction,
+  type ParsedPausePollInstruction,
+  type ParsedProposeAuthorityInstruction,
+  type ParsedResumePollInstruction,
+  type ParsedRetractVoteInstruction,
+  type ParsedRevealVoteInstruction,
+  type ParsedRevokeVoterPassInstruction,
+  type ParsedSetAdminInstruction,
+  type ParsedTallyIrvInstruction,
+  type ParsedUpdatePollInstruction,
   type ParsedVoteInstruction,
+  type ParsedVoteQuadraticInstruction,
+  type ParsedVoteRankedInstruction,
+  type ParsedVoteWeightedInstruction,
+  type ParsedVoteWithNftInstruction,
+  type ParsedWithdrawTokensInstruction,
 } from "../instructions";
 
 export const VOTING_PROGRAM_ADDRESS =
@@ -24,7 +54,14 @@ export const VOTING_PROGRAM_ADDRESS =
 
 export enum VotingAccount {
   CandidateAccount,
+  Contest,
+  IrvResult,
+  NftReceipt,
   PollAccount,
+  PollCounter,
+  PollResult,
+  RankedBallot,
+  VoterPass,
   VoterReceipt,
 }
 
@@ -43,6 +80,39 @@ export function identifyVotingAccount(
   ) {
     return VotingAccount.CandidateAccount;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([216, 26, 88, 18, 251, 80, 201, 96]),
+      ),
+      0,
+    )
+  ) {
+    return VotingAccount.Contest;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([44, 85, 73, 214, 156, 135, 73, 125]),
+      ),
+      0,
+    )
+  ) {
+    return VotingAccount.IrvResult;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([153, 40, 201, 0, 124, 73, 47, 210]),
+      ),
+      0,
+    )
+  ) {
+    return VotingAccount.NftReceipt;
+  }
   if (
     containsBytes(
       data,
@@ -54,6 +124,50 @@ export function identifyVotingAccount(
   ) {
     return VotingAccount.PollAccount;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([196, 1, 77, 116, 60, 205, 237, 189]),
+      ),
+      0,
+    )
+  ) {
+    return VotingAccount.PollCounter;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([139, 201, 153, 117, 71, 38, 98, 60]),
+      ),
+      0,
+    )
+  ) {
+    return VotingAccount.PollResult;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([226, 22, 203, 244, 78, 66, 115, 132]),
+      ),
+      0,
+    )
+  ) {
+    return VotingAccount.RankedBallot;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([161, 186, 81, 177, 116, 104, 37, 182]),
+      ),
+      0,
+    )
+  ) {
+    return VotingAccount.VoterPass;
+  }
   if (
     containsBytes(
       data,
@@ -71,15 +185,56 @@ export function identifyVotingAccount(
 }
 
 export enum VotingInstruction {
+  AcceptAuthority,
   AddCandidate,
+  AddContest,
+  AddContestCandidate,
+  CancelPoll,
+  CastBallot,
+  ChangeVote,
+  CloseBallot,
+  CloseCandidates,
+  CloseNftReceipt,
+  ClosePoll,
+  CloseReceipt,
+  CommitVote,
+  FinalizePoll,
   InitializePoll,
+  IssueVoterPass,
+  IssueVoterPasses,
+  MigratePoll,
+  PausePoll,
+  ProposeAuthority,
+  ResumePoll,
+  RetractVote,
+  RevealVote,
+  RevokeVoterPass,
+  SetAdmin,
+  TallyIrv,
+  UpdatePoll,
   Vote,
+  VoteQuadratic,
+  VoteRanked,
+  VoteWeighted,
+  VoteWithNft,
+  WithdrawTokens,
 }
 
 export function identifyVotingInstruction(
   instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array,
 ): VotingInstruction {
   const data = "data" in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([107, 86, 198, 91, 33, 12, 107, 160]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.AcceptAuthority;
+  }
   if (
     containsBytes(
       data,
@@ -91,6 +246,138 @@ export function identifyVotingInstruction(
   ) {
     return VotingInstruction.AddCandidate;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([122, 166, 206, 111, 163, 4, 177, 23]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.AddContest;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([135, 61, 102, 42, 62, 30, 226, 185]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.AddContestCandidate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([189, 15, 87, 113, 77, 135, 75, 171]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.CancelPoll;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([43, 147, 64, 196, 22, 104, 194, 180]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.CastBallot;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([184, 39, 97, 137, 83, 108, 185, 75]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.ChangeVote;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([243, 82, 194, 215, 41, 255, 65, 98]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.CloseBallot;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([216, 252, 144, 120, 188, 232, 70, 204]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.CloseCandidates;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([2, 121, 52, 193, 29, 53, 60, 20]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.CloseNftReceipt;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([139, 213, 162, 65, 172, 150, 123, 67]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.ClosePoll;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([126, 254, 244, 203, 124, 164, 134, 89]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.CloseReceipt;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([134, 97, 90, 126, 91, 66, 16, 26]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.CommitVote;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([90, 57, 229, 211, 20, 47, 151, 93]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.FinalizePoll;
+  }
   if (
     containsBytes(
       data,
@@ -102,6 +389,138 @@ export function identifyVotingInstruction(
   ) {
     return VotingInstruction.InitializePoll;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([197, 119, 192, 42, 241, 252, 98, 62]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.IssueVoterPass;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([38, 98, 0, 48, 150, 102, 147, 31]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.IssueVoterPasses;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([60, 168, 231, 11, 26, 219, 196, 192]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.MigratePoll;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([41, 68, 96, 33, 228, 53, 145, 162]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.PausePoll;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([20, 148, 236, 198, 76, 119, 99, 142]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.ProposeAuthority;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([210, 200, 15, 73, 62, 226, 83, 136]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.ResumePoll;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([227, 0, 85, 234, 243, 42, 133, 162]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.RetractVote;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([100, 157, 139, 17, 186, 75, 185, 149]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.RevealVote;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([53, 128, 190, 164, 132, 223, 209, 11]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.RevokeVoterPass;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([251, 163, 0, 52, 91, 194, 187, 92]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.SetAdmin;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([236, 178, 181, 219, 220, 219, 217, 94]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.TallyIrv;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([188, 131, 217, 106, 140, 114, 130, 5]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.UpdatePoll;
+  }
   if (
     containsBytes(
       data,
@@ -113,6 +532,61 @@ export function identifyVotingInstruction(
   ) {
     return VotingInstruction.Vote;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([66, 209, 68, 36, 236, 210, 183, 238]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.VoteQuadratic;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([153, 132, 225, 108, 5, 236, 172, 200]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.VoteRanked;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([171, 248, 225, 46, 157, 157, 173, 122]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.VoteWeighted;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([227, 176, 202, 155, 157, 128, 179, 32]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.VoteWithNft;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([2, 4, 225, 61, 19, 182, 106, 170]),
+      ),
+      0,
+    )
+  ) {
+    return VotingInstruction.WithdrawTokens;
+  }
   throw new Error(
     "The provided instruction could not be identified as a voting instruction.",
   );
@@ -121,12 +595,102 @@ export function identifyVotingInstruction(
 export type ParsedVotingInstruction<
   TProgram extends string = "Doo2arLUifZbfqGVS5Uh7nexAMmsMzaQH5zcwZhSoijz",
 > =
+  | ({
+      instructionType: VotingInstruction.AcceptAuthority;
+    } & ParsedAcceptAuthorityInstruction<TProgram>)
   | ({
       instructionType: VotingInstruction.AddCandidate;
     } & ParsedAddCandidateInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.AddContest;
+    } & ParsedAddContestInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.AddContestCandidate;
+    } & ParsedAddContestCandidateInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.CancelPoll;
+    } & ParsedCancelPollInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.CastBallot;
+    } & ParsedCastBallotInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.ChangeVote;
+    } & ParsedChangeVoteInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.CloseBallot;
+    } & ParsedCloseBallotInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.CloseCandidates;
+    } & ParsedCloseCandidatesInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.CloseNftReceipt;
+    } & ParsedCloseNftReceiptInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.ClosePoll;
+    } & ParsedClosePollInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.CloseReceipt;
+    } & ParsedCloseReceiptInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.CommitVote;
+    } & ParsedCommitVoteInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.FinalizePoll;
+    } & ParsedFinalizePollInstruction<TProgram>)
   | ({
       instructionType: VotingInstruction.InitializePoll;
     } & ParsedInitializePollInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.IssueVoterPass;
+    } & ParsedIssueVoterPassInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.IssueVoterPasses;
+    } & ParsedIssueVoterPassesInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.MigratePoll;
+    } & ParsedMigratePollInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.PausePoll;
+    } & ParsedPausePollInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.ProposeAuthority;
+    } & ParsedProposeAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.ResumePoll;
+    } & ParsedResumePollInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.RetractVote;
+    } & ParsedRetractVoteInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.RevealVote;
+    } & ParsedRevealVoteInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.RevokeVoterPass;
+    } & ParsedRevokeVoterPassInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.SetAdmin;
+    } & ParsedSetAdminInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.TallyIrv;
+    } & ParsedTallyIrvInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.UpdatePoll;
+    } & ParsedUpdatePollInstruction<TProgram>)
   | ({
       instructionType: VotingInstruction.Vote;
-    } & ParsedVoteInstruction<TProgram>);
+    } & ParsedVoteInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.VoteQuadratic;
+    } & ParsedVoteQuadraticInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.VoteRanked;
+    } & ParsedVoteRankedInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.VoteWeighted;
+    } & ParsedVoteWeightedInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.VoteWithNft;
+    } & ParsedVoteWithNftInstruction<TProgram>)
+  | ({
+      instructionType: VotingInstruction.WithdrawTokens;
+    } & ParsedWithdrawTokensInstruction<TProgram>);
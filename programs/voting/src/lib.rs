@@ -1,6 +1,16 @@
 #![allow(unexpected_cfgs, deprecated)]
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::system_program;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{transfer_fee::TransferFeeAmount, BaseStateWithExtensions, StateWithExtensions},
+};
+use anchor_spl::token_interface::{
+    self, CloseAccount, HarvestWithheldTokensToMint, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
 
 declare_id!("Doo2arLUifZbfqGVS5Uh7nexAMmsMzaQH5zcwZhSoijz");
 
@@ -8,62 +18,659 @@ declare_id!("Doo2arLUifZbfqGVS5Uh7nexAMmsMzaQH5zcwZhSoijz");
 pub mod voting {
     use super::*;
 
-    // 初始化投票活动
+    // 初始化投票活动，投票账户是由 authority 和 poll_id 派生的 PDA
     pub fn initialize_poll(
         ctx: Context<InitializePoll>,
+        poll_id: u64,
         name: String,
         description: String,
         start_time: u64,
         end_time: u64,
+        options: PollOptions,
     ) -> Result<()> {
+        // 提前校验输入，避免超长字段在序列化时才报出难以理解的错误
+        validate_name(&name)?;
+        require!(description.len() <= 280, ErrorCode::DescriptionTooLong);
+        require!(start_time < end_time, ErrorCode::InvalidSchedule);
+        require!(
+            end_time as i64 > Clock::get()?.unix_timestamp,
+            ErrorCode::InvalidSchedule
+        );
+        // 代币门槛、加权投票和 NFT 集合投票三者互斥；加权投票的代币托管到投票结束，不支持撤回投票；
+        // NFT 回执按 NFT 记录而不是按投票者记录，NFT 投票不支持改票和撤回
+        let gate_count = [
+            options.gate_mint,
+            options.weight_mint,
+            options.collection_mint,
+        ]
+        .iter()
+        .filter(|mint| mint.is_some())
+        .count();
+        require!(
+            gate_count <= 1
+                && !(options.weight_mint.is_some() && options.allow_vote_retraction)
+                && !(options.collection_mint.is_some()
+                    && (options.allow_vote_change || options.allow_vote_retraction)),
+            ErrorCode::InvalidPollOptions
+        );
+        // 赞成投票的每张选票涉及多个候选人，不支持改票、撤回，也不能与 vote_weighted、vote_with_nft 搭配
+        if let BallotType::Approval { max_selections } = options.ballot_type {
+            require!(
+                max_selections > 0
+                    && !options.allow_vote_change
+                    && !options.allow_vote_retraction
+                    && options.weight_mint.is_none()
+                    && options.collection_mint.is_none(),
+                ErrorCode::InvalidPollOptions
+            );
+        }
+        // 排序投票由 vote_ranked 投出、tally_irv 开票，每张选票计一票，只能与代币门槛搭配
+        if let BallotType::Ranked { max_rankings } = options.ballot_type {
+            require!(
+                max_rankings > 0
+                    && !options.allow_vote_change
+                    && !options.allow_vote_retraction
+                    && options.weight_mint.is_none()
+                    && options.collection_mint.is_none()
+                    && options.allowlist_root.is_none()
+                    && !options.requires_pass,
+                ErrorCode::InvalidPollOptions
+            );
+        }
+        // 二次方投票由 vote_quadratic 多次投出，回执记录已花费的额度，只能与代币门槛或投票通行证搭配
+        if let BallotType::Quadratic { credits_per_voter } = options.ballot_type {
+            require!(
+                credits_per_voter > 0
+                    && !options.allow_vote_change
+                    && !options.allow_vote_retraction
+                    && options.weight_mint.is_none()
+                    && options.collection_mint.is_none()
+                    && options.allowlist_root.is_none(),
+                ErrorCode::InvalidPollOptions
+            );
+        }
+        // 秘密投票由 commit_vote 提交承诺、reveal_vote 揭示，只支持单选，不能改票、撤回，也不能与加权和 NFT 投票搭配
+        if let Some(reveal_duration) = options.reveal_duration {
+            require!(
+                reveal_duration > 0
+                    && options.ballot_type == BallotType::Single
+                    && !options.allow_vote_change
+                    && !options.allow_vote_retraction
+                    && options.weight_mint.is_none()
+                    && options.collection_mint.is_none(),
+                ErrorCode::InvalidPollOptions
+            );
+        }
+        // 白名单和投票通行证二选一，且只在 vote 中校验，不能与 vote_weighted、vote_with_nft 搭配
+        let roll_count = options.allowlist_root.is_some() as u8 + options.requires_pass as u8;
+        require!(
+            roll_count == 0
+                || (roll_count == 1
+                    && options.weight_mint.is_none()
+                    && options.collection_mint.is_none()),
+            ErrorCode::InvalidPollOptions
+        );
+
+        // poll_id 必须等于 authority 当前的计数，保证其所有投票活动可以按 0..poll_count 依次找到
+        let poll_counter = &mut ctx.accounts.poll_counter;
+        require!(poll_id == poll_counter.poll_count, ErrorCode::InvalidPollId);
+        poll_counter.authority = ctx.accounts.signer.key();
+        poll_counter.poll_count = poll_counter
+            .poll_count
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+
         let poll_account = &mut ctx.accounts.poll_account;
         poll_account.name = name;
         poll_account.description = description;
         poll_account.start_time = start_time;
         poll_account.end_time = end_time;
         poll_account.authority = ctx.accounts.signer.key();
-        poll_account.candidates = Vec::new();
+        poll_account.total_votes = 0;
+        // 平票规则等选项在创建时确定，结算时按此规则产生获胜者
+        poll_account.options = options;
+        poll_account.status = PollStatus::Active;
+        poll_account.tallied_count = 0;
+        poll_account.closed_candidate_count = 0;
+        poll_account.escrowed_amount = 0;
+        poll_account.commit_count = 0;
+        poll_account.reveal_count = 0;
+        poll_account.contest_count = 0;
+        poll_account.contest_candidate_count = 0;
+        poll_account.pending_authority = None;
+        poll_account.admins = Vec::new();
 
         // 关键修复：使用专门的计数器，避免 Vec.len() 的解释错误
         poll_account.candidate_count = 0;
+        poll_account.legacy_candidate_count = 0;
+
+        emit_cpi!(PollCreated {
+            poll: poll_account.key(),
+            authority: poll_account.authority,
+            name: poll_account.name.clone(),
+            description: poll_account.description.clone(),
+            start_time,
+            end_time,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
 
         Ok(())
     }
 
     // 添加候选人
     pub fn add_candidate(ctx: Context<AddCandidate>, candidate_name: String) -> Result<()> {
-        require_keys_eq!(
-            ctx.accounts.poll_account.authority,
-            ctx.accounts.signer.key(),
-            ErrorCode::Unauthorized
+        require_role(
+            &ctx.accounts.poll_account,
+            &ctx.accounts.signer.key(),
+            AdminRole::CandidateManager,
+        )?;
+
+        validate_name(&candidate_name)?;
+
+        let poll_account = &mut ctx.accounts.poll_account;
+        let candidate_account = &mut ctx.accounts.candidate_account;
+
+        // 投票结束、结算、暂停或取消后不能再添加候选人，否则已开始的开票会漏掉新候选人
+        require_accepting_votes(poll_account)?;
+        require!(
+            Clock::get()?.unix_timestamp <= poll_account.end_time as i64,
+            ErrorCode::PollEnded
+        );
+
+        let candidate_index = poll_account.candidate_count;
+
+        candidate_account.name = candidate_name;
+        candidate_account.poll = poll_account.key();
+        candidate_account.votes = 0;
+
+        // 在成功添加后，手动增加计数器；候选人不再存放在投票账户中，数量只受 u32 限制
+        poll_account.candidate_count = poll_account
+            .candidate_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MaxCandidatesReached)?;
+
+        emit_cpi!(CandidateAdded {
+            poll: poll_account.key(),
+            candidate: candidate_account.key(),
+            candidate_index,
+            name: candidate_account.name.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // 添加分项（例如主席、财务或一项动议），投票开始前才能添加；max_selections 为该分项最多可选的候选人数量
+    pub fn add_contest(ctx: Context<AddContest>, name: String, max_selections: u8) -> Result<()> {
+        require_role(
+            &ctx.accounts.poll_account,
+            &ctx.accounts.signer.key(),
+            AdminRole::CandidateManager,
+        )?;
+
+        require_current_layout(&ctx.accounts.poll_account.to_account_info())?;
+        validate_name(&name)?;
+
+        let clock = Clock::get()?;
+        let poll_account = &mut ctx.accounts.poll_account;
+
+        require!(
+            clock.unix_timestamp < poll_account.start_time as i64,
+            ErrorCode::PollAlreadyStarted
+        );
+        // 分项由 cast_ballot 投票，只支持单选类型的普通投票，不能与加权、NFT、秘密投票或改票、撤回搭配
+        let options = &poll_account.options;
+        require!(
+            max_selections > 0
+                && options.ballot_type == BallotType::Single
+                && options.weight_mint.is_none()
+                && options.collection_mint.is_none()
+                && options.reveal_duration.is_none()
+                && !options.allow_vote_change
+                && !options.allow_vote_retraction,
+            ErrorCode::InvalidPollOptions
         );
 
+        let contest_index = poll_account.contest_count;
+
+        let contest = &mut ctx.accounts.contest;
+        contest.poll = poll_account.key();
+        contest.index = contest_index;
+        contest.name = name;
+        contest.max_selections = max_selections;
+        contest.candidate_count = 0;
+        contest.total_votes = 0;
+
+        poll_account.contest_count = poll_account
+            .contest_count
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+
+        emit_cpi!(ContestAdded {
+            poll: poll_account.key(),
+            contest: contest.key(),
+            contest_index,
+            name: contest.name.clone(),
+            max_selections,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // 为分项添加候选人，候选人 PDA 由分项和分项内的索引派生
+    pub fn add_contest_candidate(
+        ctx: Context<AddContestCandidate>,
+        candidate_name: String,
+    ) -> Result<()> {
+        require_role(
+            &ctx.accounts.poll_account,
+            &ctx.accounts.signer.key(),
+            AdminRole::CandidateManager,
+        )?;
+
+        validate_name(&candidate_name)?;
+
+        let clock = Clock::get()?;
         let poll_account = &mut ctx.accounts.poll_account;
+        let contest = &mut ctx.accounts.contest;
         let candidate_account = &mut ctx.accounts.candidate_account;
 
-        // 检查专门的计数器，而不是 Vec.len()
         require!(
-            poll_account.candidate_count < 15,
-            ErrorCode::MaxCandidatesReached
+            clock.unix_timestamp < poll_account.start_time as i64,
+            ErrorCode::PollAlreadyStarted
         );
 
+        let candidate_index = contest.candidate_count;
+
+        // 候选人仍记录所属的投票活动，以便 close_candidates 统一回收
         candidate_account.name = candidate_name;
         candidate_account.poll = poll_account.key();
         candidate_account.votes = 0;
 
-        poll_account.candidates.push(candidate_account.key());
-        // 在成功添加后，手动增加计数器
-        poll_account.candidate_count += 1;
+        contest.candidate_count = contest
+            .candidate_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MaxCandidatesReached)?;
+        poll_account.contest_candidate_count = poll_account
+            .contest_candidate_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MaxCandidatesReached)?;
+
+        emit_cpi!(ContestCandidateAdded {
+            poll: poll_account.key(),
+            contest: contest.key(),
+            candidate: candidate_account.key(),
+            candidate_index,
+            name: candidate_account.name.clone(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // 修改投票活动：开始前可修改名称、描述和起止时间，开始后只能延长结束时间
+    pub fn update_poll(
+        ctx: Context<UpdatePoll>,
+        name: Option<String>,
+        description: Option<String>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+    ) -> Result<()> {
+        require_role(
+            &ctx.accounts.poll_account,
+            &ctx.accounts.signer.key(),
+            AdminRole::ScheduleManager,
+        )?;
+
+        require_current_layout(&ctx.accounts.poll_account.to_account_info())?;
+
+        let clock = Clock::get()?;
+        let poll_account = &mut ctx.accounts.poll_account;
+
+        if clock.unix_timestamp < poll_account.start_time as i64 {
+            if let Some(name) = name {
+                validate_name(&name)?;
+                poll_account.name = name;
+            }
+            if let Some(description) = description {
+                require!(description.len() <= 280, ErrorCode::DescriptionTooLong);
+                poll_account.description = description;
+            }
+            if let Some(start_time) = start_time {
+                poll_account.start_time = start_time;
+            }
+            if let Some(end_time) = end_time {
+                poll_account.end_time = end_time;
+            }
+
+            // 修改后的时间段必须有效且尚未结束
+            require!(
+                poll_account.start_time < poll_account.end_time
+                    && poll_account.end_time as i64 > clock.unix_timestamp,
+                ErrorCode::InvalidSchedule
+            );
+        } else {
+            // 投票已开始，只允许延长结束时间
+            require!(
+                name.is_none() && description.is_none() && start_time.is_none(),
+                ErrorCode::PollAlreadyStarted
+            );
+
+            if clock.unix_timestamp > poll_account.end_time as i64 {
+                return err!(ErrorCode::PollEnded);
+            }
+
+            if let Some(end_time) = end_time {
+                require!(
+                    end_time > poll_account.end_time,
+                    ErrorCode::EndTimeNotExtended
+                );
+                poll_account.end_time = end_time;
+            }
+        }
+
+        emit_cpi!(PollUpdated {
+            poll: poll_account.key(),
+            name: poll_account.name.clone(),
+            description: poll_account.description.clone(),
+            start_time: poll_account.start_time,
+            end_time: poll_account.end_time,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // 提名新的 authority，需由被提名者执行 accept_authority 后才生效；传 None 取消提名
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        new_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        require_keys_eq!(
+            ctx.accounts.poll_account.authority,
+            ctx.accounts.signer.key(),
+            ErrorCode::Unauthorized
+        );
+
+        require_current_layout(&ctx.accounts.poll_account.to_account_info())?;
+
+        let poll_account = &mut ctx.accounts.poll_account;
+        poll_account.pending_authority = new_authority;
+
+        emit_cpi!(AuthorityProposed {
+            poll: poll_account.key(),
+            authority: poll_account.authority,
+            pending_authority: new_authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // 被提名者签名接受，成为投票活动新的 authority；投票账户地址仍由创建者派生，不会改变
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        require_current_layout(&ctx.accounts.poll_account.to_account_info())?;
+
+        let poll_account = &mut ctx.accounts.poll_account;
+        require!(
+            poll_account.pending_authority == Some(ctx.accounts.signer.key()),
+            ErrorCode::NotPendingAuthority
+        );
+
+        let previous_authority = poll_account.authority;
+        poll_account.authority = ctx.accounts.signer.key();
+        poll_account.pending_authority = None;
+
+        emit_cpi!(AuthorityTransferred {
+            poll: poll_account.key(),
+            previous_authority,
+            new_authority: poll_account.authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // 设置管理员的角色，role 为 None 时移除该管理员；只有 authority 或 Owner 角色可以管理
+    pub fn set_admin(ctx: Context<SetAdmin>, admin: Pubkey, role: Option<AdminRole>) -> Result<()> {
+        require_role(
+            &ctx.accounts.poll_account,
+            &ctx.accounts.signer.key(),
+            AdminRole::Owner,
+        )?;
+
+        require_current_layout(&ctx.accounts.poll_account.to_account_info())?;
+
+        let poll_account = &mut ctx.accounts.poll_account;
+        poll_account.admins.retain(|entry| entry.admin != admin);
+        if let Some(role) = role {
+            require!(
+                poll_account.admins.len() < MAX_POLL_ADMINS,
+                ErrorCode::TooManyAdmins
+            );
+            poll_account.admins.push(PollAdmin { admin, role });
+        }
+
+        emit_cpi!(AdminUpdated {
+            poll: poll_account.key(),
+            admin,
+            role,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // 暂停投票活动，暂停期间不接受投票；结束时间不会因暂停而顺延
+    pub fn pause_poll(ctx: Context<SetPollStatus>) -> Result<()> {
+        require_role(
+            &ctx.accounts.poll_account,
+            &ctx.accounts.signer.key(),
+            AdminRole::Owner,
+        )?;
+        require_current_layout(&ctx.accounts.poll_account.to_account_info())?;
+
+        let clock = Clock::get()?;
+        let poll_account = &mut ctx.accounts.poll_account;
+        require_accepting_votes(poll_account)?;
+        require_before_tally(poll_account, &clock)?;
+        poll_account.status = PollStatus::Paused;
+
+        emit_cpi!(PollStatusChanged {
+            poll: poll_account.key(),
+            status: poll_account.status,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // 恢复被暂停的投票活动
+    pub fn resume_poll(ctx: Context<SetPollStatus>) -> Result<()> {
+        require_role(
+            &ctx.accounts.poll_account,
+            &ctx.accounts.signer.key(),
+            AdminRole::Owner,
+        )?;
+        require_current_layout(&ctx.accounts.poll_account.to_account_info())?;
+
+        let poll_account = &mut ctx.accounts.poll_account;
+        require!(
+            poll_account.status == PollStatus::Paused,
+            ErrorCode::PollNotPaused
+        );
+        poll_account.status = PollStatus::Active;
+
+        emit_cpi!(PollStatusChanged {
+            poll: poll_account.key(),
+            status: poll_account.status,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // 取消投票活动：不再接受投票也不能结算，可以立即关闭并回收所有账户的租金
+    pub fn cancel_poll(ctx: Context<SetPollStatus>) -> Result<()> {
+        require_role(
+            &ctx.accounts.poll_account,
+            &ctx.accounts.signer.key(),
+            AdminRole::Owner,
+        )?;
+        require_current_layout(&ctx.accounts.poll_account.to_account_info())?;
+
+        let clock = Clock::get()?;
+        let poll_account = &mut ctx.accounts.poll_account;
+        match poll_account.status {
+            PollStatus::Active | PollStatus::Paused => {}
+            PollStatus::Cancelled => return err!(ErrorCode::PollCancelled),
+            PollStatus::Finalized => return err!(ErrorCode::PollAlreadyFinalized),
+        }
+        require_before_tally(poll_account, &clock)?;
+        poll_account.status = PollStatus::Cancelled;
+
+        emit_cpi!(PollStatusChanged {
+            poll: poll_account.key(),
+            status: poll_account.status,
+            timestamp: clock.unix_timestamp,
+        });
 
         Ok(())
     }
 
-    // 投票
-    pub fn vote(ctx: Context<Vote>) -> Result<()> {
+    // 为投票者签发投票通行证，weight 为空时票数为 1；投票结束前都可以签发
+    pub fn issue_voter_pass(
+        ctx: Context<IssueVoterPass>,
+        voter: Pubkey,
+        weight: Option<u64>,
+    ) -> Result<()> {
+        let poll_account = &ctx.accounts.poll_account;
+        require_role(poll_account, &ctx.accounts.signer.key(), AdminRole::Owner)?;
+        require_current_layout(&poll_account.to_account_info())?;
+
         let clock = Clock::get()?;
+        require!(
+            clock.unix_timestamp <= poll_account.end_time as i64,
+            ErrorCode::PollEnded
+        );
+
+        let voter_pass = &mut ctx.accounts.voter_pass;
+        voter_pass.poll = poll_account.key();
+        voter_pass.voter = voter;
+        voter_pass.weight = pass_weight(weight)?;
+        voter_pass.used = false;
+
+        emit_cpi!(VoterPassIssued {
+            poll: poll_account.key(),
+            voter,
+            weight: voter_pass.weight,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // 批量签发投票通行证，remaining_accounts 依次传入与 passes 对应的通行证 PDA
+    pub fn issue_voter_passes<'info>(
+        ctx: Context<'_, '_, '_, 'info, IssueVoterPasses<'info>>,
+        passes: Vec<VoterPassEntry>,
+    ) -> Result<()> {
         let poll_account = &ctx.accounts.poll_account;
+        require_role(poll_account, &ctx.accounts.signer.key(), AdminRole::Owner)?;
+        require_current_layout(&poll_account.to_account_info())?;
+        require!(
+            ctx.remaining_accounts.len() == passes.len(),
+            ErrorCode::InvalidVoterPassAccounts
+        );
+
+        let clock = Clock::get()?;
+        require!(
+            clock.unix_timestamp <= poll_account.end_time as i64,
+            ErrorCode::PollEnded
+        );
+
+        let poll_key = poll_account.key();
+        for (entry, pass_info) in passes.iter().zip(ctx.remaining_accounts) {
+            let (address, bump) = Pubkey::find_program_address(
+                &[b"pass", poll_key.as_ref(), entry.voter.as_ref()],
+                ctx.program_id,
+            );
+            require_keys_eq!(
+                pass_info.key(),
+                address,
+                ErrorCode::InvalidVoterPassAccounts
+            );
+
+            create_pda_account(
+                pass_info,
+                &ctx.accounts.signer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                8 + VoterPass::INIT_SPACE,
+                &[&[b"pass", poll_key.as_ref(), entry.voter.as_ref(), &[bump]]],
+            )?;
+
+            let voter_pass = VoterPass {
+                poll: poll_key,
+                voter: entry.voter,
+                weight: pass_weight(entry.weight)?,
+                used: false,
+            };
+            voter_pass.try_serialize(&mut &mut pass_info.try_borrow_mut_data()?[..])?;
+
+            emit_cpi!(VoterPassIssued {
+                poll: poll_key,
+                voter: entry.voter,
+                weight: voter_pass.weight,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+
+        Ok(())
+    }
+
+    // 撤销尚未使用的投票通行证，租金退还给执行撤销的管理员；
+    // 投票活动结束或被取消后，已使用的通行证也可以关闭
+    pub fn revoke_voter_pass(ctx: Context<RevokeVoterPass>) -> Result<()> {
+        require_role(
+            &ctx.accounts.poll_account,
+            &ctx.accounts.signer.key(),
+            AdminRole::Owner,
+        )?;
+        let clock = Clock::get()?;
+        require!(
+            !ctx.accounts.voter_pass.used || poll_closable(&ctx.accounts.poll_account, &clock),
+            ErrorCode::VoterPassUsed
+        );
+
+        emit_cpi!(VoterPassRevoked {
+            poll: ctx.accounts.poll_account.key(),
+            voter: ctx.accounts.voter_pass.voter,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // 投票，candidate_index 为所选候选人的索引
+    // 设置了 allowlist_root 的投票活动需要传入投票者在白名单中的 merkle 证明；
+    // 赞成投票时 remaining_accounts 依次传入其余批准的候选人账户
+    pub fn vote(
+        ctx: Context<Vote>,
+        candidate_index: u32,
+        allowlist_proof: Option<AllowlistProof>,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+
+        // 旧版布局的投票账户需先执行 migrate_poll
+        require_current_layout(&ctx.accounts.poll_account.to_account_info())?;
+
+        let poll_account = &mut ctx.accounts.poll_account;
         let candidate_account = &mut ctx.accounts.candidate_account;
 
+        // 已结算的投票活动不再接受投票
+        require_accepting_votes(poll_account)?;
+
         if clock.unix_timestamp < poll_account.start_time as i64 {
             return err!(ErrorCode::PollNotStarted);
         }
@@ -72,85 +679,2611 @@ pub mod voting {
             return err!(ErrorCode::PollEnded);
         }
 
+        // 加权投票活动需使用 vote_weighted，NFT 集合投票活动需使用 vote_with_nft
+        require!(
+            poll_account.options.weight_mint.is_none(),
+            ErrorCode::WeightedVoteRequired
+        );
+        require!(
+            poll_account.options.collection_mint.is_none(),
+            ErrorCode::NftVoteRequired
+        );
+        require!(
+            !poll_account.options.ballot_type.is_ranked(),
+            ErrorCode::RankedVoteRequired
+        );
+        require!(
+            !matches!(
+                poll_account.options.ballot_type,
+                BallotType::Quadratic { .. }
+            ),
+            ErrorCode::QuadraticVoteRequired
+        );
+        require!(
+            poll_account.options.reveal_duration.is_none(),
+            ErrorCode::CommitRevealRequired
+        );
+        require!(
+            poll_account.contest_count == 0,
+            ErrorCode::ContestBallotRequired
+        );
+
+        check_gate_balance(
+            &poll_account.options,
+            ctx.accounts.voter_token_account.as_ref(),
+            &ctx.accounts.signer.key(),
+        )?;
+
+        let weight = voter_weight(
+            &poll_account.options,
+            &ctx.accounts.signer.key(),
+            allowlist_proof,
+            ctx.accounts.voter_pass.as_mut(),
+        )?;
+
         require_keys_eq!(
             candidate_account.poll,
             poll_account.key(),
             ErrorCode::InvalidCandidateForPoll
         );
 
-        candidate_account.votes += 1;
+        // 候选人地址必须与索引对应
+        require_keys_eq!(
+            candidate_account.key(),
+            candidate_address(
+                &poll_account.key(),
+                poll_account.legacy_candidate_count,
+                candidate_index
+            ),
+            ErrorCode::InvalidCandidateForPoll
+        );
+
+        // 其余批准的候选人不能重复，且不能超过 max_selections
+        let mut selections = vec![candidate_account.key()];
+        for candidate_info in ctx.remaining_accounts {
+            require!(
+                !selections.contains(candidate_info.key),
+                ErrorCode::DuplicateSelection
+            );
+            selections.push(candidate_info.key());
+        }
+        require!(
+            selections.len() <= poll_account.options.ballot_type.max_selections(),
+            ErrorCode::TooManySelections
+        );
+        for candidate_info in ctx.remaining_accounts {
+            require_keys_eq!(
+                *candidate_info.owner,
+                crate::ID,
+                anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram
+            );
+            let mut candidate =
+                CandidateAccount::try_deserialize(&mut &candidate_info.try_borrow_data()?[..])?;
+            require_keys_eq!(
+                candidate.poll,
+                poll_account.key(),
+                ErrorCode::InvalidCandidateForPoll
+            );
+            candidate.votes = candidate
+                .votes
+                .checked_add(weight)
+                .ok_or(ErrorCode::Overflow)?;
+            candidate.try_serialize(&mut &mut candidate_info.try_borrow_mut_data()?[..])?;
+        }
 
+        candidate_account.votes = candidate_account
+            .votes
+            .checked_add(weight)
+            .ok_or(ErrorCode::Overflow)?;
+        poll_account.total_votes = (selections.len() as u64)
+            .checked_mul(weight)
+            .and_then(|votes| poll_account.total_votes.checked_add(votes))
+            .ok_or(ErrorCode::Overflow)?;
+
+        // 回执记录所投的候选人及投票时间，便于事后审计
         let receipt = &mut ctx.accounts.voter_receipt;
         receipt.voter = ctx.accounts.signer.key();
         receipt.poll = poll_account.key();
+        receipt.candidate = candidate_account.key();
+        receipt.voted_at = clock.unix_timestamp;
+        receipt.slot = clock.slot;
+        receipt.weight = weight;
+        receipt.selections = selections.clone();
+
+        emit_cpi!(VoteCast {
+            poll: poll_account.key(),
+            candidate: candidate_account.key(),
+            candidate_index,
+            voter: ctx.accounts.signer.key(),
+            votes: candidate_account.votes,
+            timestamp: clock.unix_timestamp,
+        });
+
+        if selections.len() > 1 {
+            emit_cpi!(ApprovalVoteCast {
+                poll: poll_account.key(),
+                voter: ctx.accounts.signer.key(),
+                candidates: selections,
+                weight,
+                timestamp: clock.unix_timestamp,
+            });
+        }
 
         Ok(())
     }
-}
 
-#[derive(Accounts)]
-pub struct InitializePoll<'info> {
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    #[account(init, payer = signer, space = 8 + PollAccount::INIT_SPACE)]
-    pub poll_account: Account<'info, PollAccount>,
-    pub system_program: Program<'info, System>,
-}
+    // 一张选票同时为多个分项投票，共用一份回执、投票资格和投票时间；
+    // remaining_accounts 按 choices 的顺序依次传入每个分项账户及其所选的候选人账户
+    pub fn cast_ballot(
+        ctx: Context<CastBallot>,
+        choices: Vec<ContestChoice>,
+        allowlist_proof: Option<AllowlistProof>,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
 
-#[derive(Accounts)]
-pub struct AddCandidate<'info> {
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    #[account(mut)]
-    pub poll_account: Account<'info, PollAccount>,
-    #[account(
-        init,
-        payer = signer,
-        space = 8 + CandidateAccount::INIT_SPACE,
-        // seeds 现在使用更可靠的计数器
-        seeds = [b"candidate", poll_account.key().as_ref(), poll_account.candidate_count.to_le_bytes().as_ref()],
-        bump
-    )]
-    pub candidate_account: Account<'info, CandidateAccount>,
-    pub system_program: Program<'info, System>,
-}
+        require_current_layout(&ctx.accounts.poll_account.to_account_info())?;
 
-#[derive(Accounts)]
-pub struct Vote<'info> {
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    #[account(mut)]
-    pub poll_account: Account<'info, PollAccount>,
-    #[account(
-        mut,
-        constraint = candidate_account.poll == poll_account.key() @ ErrorCode::InvalidCandidateForPoll
-    )]
-    pub candidate_account: Account<'info, CandidateAccount>,
-    #[account(
-        init,
-        payer = signer,
-        space = 8 + VoterReceipt::INIT_SPACE,
-        seeds = [b"receipt", poll_account.key().as_ref(), signer.key().as_ref()],
-        bump
-    )]
-    pub voter_receipt: Account<'info, VoterReceipt>,
-    pub system_program: Program<'info, System>,
-}
+        let poll_account = &mut ctx.accounts.poll_account;
 
-#[account]
-#[derive(InitSpace)]
-pub struct PollAccount {
-    pub authority: Pubkey,
-    #[max_len(32)]
-    pub name: String,
-    #[max_len(280)]
-    pub description: String,
+        require_accepting_votes(poll_account)?;
+
+        if clock.unix_timestamp < poll_account.start_time as i64 {
+            return err!(ErrorCode::PollNotStarted);
+        }
+
+        if clock.unix_timestamp > poll_account.end_time as i64 {
+            return err!(ErrorCode::PollEnded);
+        }
+
+        require!(poll_account.contest_count > 0, ErrorCode::PollHasNoContests);
+
+        check_gate_balance(
+            &poll_account.options,
+            ctx.accounts.voter_token_account.as_ref(),
+            &ctx.accounts.signer.key(),
+        )?;
+
+        let weight = voter_weight(
+            &poll_account.options,
+            &ctx.accounts.signer.key(),
+            allowlist_proof,
+            ctx.accounts.voter_pass.as_mut(),
+        )?;
+
+        // 分项按索引严格递增，每个分项至少选一人，所选候选人同样严格递增，避免重复计票
+        require!(
+            !choices.is_empty()
+                && choices
+                    .windows(2)
+                    .all(|w| w[0].contest_index < w[1].contest_index),
+            ErrorCode::InvalidContestChoices
+        );
+        require!(
+            ctx.remaining_accounts.len() == ContestChoice::account_count(&choices),
+            ErrorCode::InvalidContestAccounts
+        );
+
+        let poll_key = poll_account.key();
+        let mut accounts = ctx.remaining_accounts.iter();
+        let mut selections = Vec::with_capacity(ContestChoice::selection_count(&choices));
+        for choice in &choices {
+            require!(
+                !choice.candidate_indices.is_empty()
+                    && choice.candidate_indices.windows(2).all(|w| w[0] < w[1]),
+                ErrorCode::InvalidContestChoices
+            );
+
+            let contest_info = accounts.next().ok_or(ErrorCode::InvalidContestAccounts)?;
+            require_keys_eq!(
+                contest_info.key(),
+                contest_address(&poll_key, choice.contest_index),
+                ErrorCode::InvalidContestAccounts
+            );
+            require_keys_eq!(
+                *contest_info.owner,
+                crate::ID,
+                anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram
+            );
+            let mut contest = Contest::try_deserialize(&mut &contest_info.try_borrow_data()?[..])?;
+            require!(
+                choice.candidate_indices.len() <= contest.max_selections as usize,
+                ErrorCode::TooManySelections
+            );
+
+            for &candidate_index in &choice.candidate_indices {
+                let candidate_info = accounts.next().ok_or(ErrorCode::InvalidContestAccounts)?;
+                require_keys_eq!(
+                    candidate_info.key(),
+                    contest_candidate_address(&contest_info.key(), candidate_index),
+                    ErrorCode::InvalidContestAccounts
+                );
+                require_keys_eq!(
+                    *candidate_info.owner,
+                    crate::ID,
+                    anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram
+                );
+                let mut candidate =
+                    CandidateAccount::try_deserialize(&mut &candidate_info.try_borrow_data()?[..])?;
+                candidate.votes = candidate
+                    .votes
+                    .checked_add(weight)
+                    .ok_or(ErrorCode::Overflow)?;
+                candidate.try_serialize(&mut &mut candidate_info.try_borrow_mut_data()?[..])?;
+                selections.push(candidate_info.key());
+            }
+
+            contest.total_votes = (choice.candidate_indices.len() as u64)
+                .checked_mul(weight)
+                .and_then(|votes| contest.total_votes.checked_add(votes))
+                .ok_or(ErrorCode::Overflow)?;
+            contest.try_serialize(&mut &mut contest_info.try_borrow_mut_data()?[..])?;
+        }
+
+        poll_account.total_votes = (selections.len() as u64)
+            .checked_mul(weight)
+            .and_then(|votes| poll_account.total_votes.checked_add(votes))
+            .ok_or(ErrorCode::Overflow)?;
+
+        // 回执的 selections 记录所有分项中所选的候选人账户
+        let receipt = &mut ctx.accounts.voter_receipt;
+        receipt.voter = ctx.accounts.signer.key();
+        receipt.poll = poll_key;
+        receipt.candidate = selections[0];
+        receipt.voted_at = clock.unix_timestamp;
+        receipt.slot = clock.slot;
+        receipt.weight = weight;
+        receipt.selections = selections.clone();
+
+        emit_cpi!(BallotCast {
+            poll: poll_key,
+            voter: ctx.accounts.signer.key(),
+            contests: choices.iter().map(|choice| choice.contest_index).collect(),
+            candidates: selections,
+            weight,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // 加权投票：投票者的全部代币余额作为票数，并托管到投票活动的金库中直到投票结束
+    pub fn vote_weighted(ctx: Context<VoteWeighted>, candidate_index: u32) -> Result<()> {
+        let clock = Clock::get()?;
+
+        require_current_layout(&ctx.accounts.poll_account.to_account_info())?;
+
+        let poll_account = &mut ctx.accounts.poll_account;
+        let candidate_account = &mut ctx.accounts.candidate_account;
+
+        let weight_mint = poll_account
+            .options
+            .weight_mint
+            .ok_or(ErrorCode::PollNotWeighted)?;
+        require_keys_eq!(
+            ctx.accounts.weight_mint.key(),
+            weight_mint,
+            ErrorCode::InvalidTokenAccount
+        );
+
+        require_accepting_votes(poll_account)?;
+
+        if clock.unix_timestamp < poll_account.start_time as i64 {
+            return err!(ErrorCode::PollNotStarted);
+        }
+
+        if clock.unix_timestamp > poll_account.end_time as i64 {
+            return err!(ErrorCode::PollEnded);
+        }
+
+        // 候选人地址必须与索引对应
+        require_keys_eq!(
+            candidate_account.key(),
+            candidate_address(
+                &poll_account.key(),
+                poll_account.legacy_candidate_count,
+                candidate_index
+            ),
+            ErrorCode::InvalidCandidateForPoll
+        );
+
+        // 锁定全部余额，防止转移代币后换个钱包重复投票
+        let balance = ctx.accounts.voter_token_account.amount;
+        require!(balance > 0, ErrorCode::InsufficientTokenBalance);
+        let vault_amount = ctx.accounts.vault.amount;
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.voter_token_account.to_account_info(),
+                    mint: ctx.accounts.weight_mint.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                    authority: ctx.accounts.signer.to_account_info(),
+                },
+            ),
+            balance,
+            ctx.accounts.weight_mint.decimals,
+        )?;
+
+        // Token-2022 的转账手续费从到账金额中扣除，按金库实际收到的数量计票，取回时也按此数量退还
+        ctx.accounts.vault.reload()?;
+        let weight = ctx
+            .accounts
+            .vault
+            .amount
+            .checked_sub(vault_amount)
+            .ok_or(ErrorCode::Overflow)?;
+        require!(weight > 0, ErrorCode::InsufficientTokenBalance);
+        poll_account.escrowed_amount = poll_account
+            .escrowed_amount
+            .checked_add(weight)
+            .ok_or(ErrorCode::Overflow)?;
+
+        candidate_account.votes = candidate_account
+            .votes
+            .checked_add(weight)
+            .ok_or(ErrorCode::Overflow)?;
+        poll_account.total_votes = poll_account
+            .total_votes
+            .checked_add(weight)
+            .ok_or(ErrorCode::Overflow)?;
+
+        // 回执记录托管的代币数量，投票结束后凭回执取回
+        let receipt = &mut ctx.accounts.voter_receipt;
+        receipt.voter = ctx.accounts.signer.key();
+        receipt.poll = poll_account.key();
+        receipt.candidate = candidate_account.key();
+        receipt.voted_at = clock.unix_timestamp;
+        receipt.slot = clock.slot;
+        receipt.weight = weight;
+        receipt.selections = vec![candidate_account.key()];
+
+        emit_cpi!(VoteCast {
+            poll: poll_account.key(),
+            candidate: candidate_account.key(),
+            candidate_index,
+            voter: ctx.accounts.signer.key(),
+            votes: candidate_account.votes,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // NFT 集合投票：每个属于已验证集合的 NFT 投一票，回执按 NFT 记录，NFT 转手后也不能重复投票
+    pub fn vote_with_nft(ctx: Context<VoteWithNft>, candidate_index: u32) -> Result<()> {
+        let clock = Clock::get()?;
+
+        require_current_layout(&ctx.accounts.poll_account.to_account_info())?;
+
+        let poll_account = &mut ctx.accounts.poll_account;
+        let candidate_account = &mut ctx.accounts.candidate_account;
+
+        let collection_mint = poll_account
+            .options
+            .collection_mint
+            .ok_or(ErrorCode::PollNotCollectionGated)?;
+
+        require_accepting_votes(poll_account)?;
+
+        if clock.unix_timestamp < poll_account.start_time as i64 {
+            return err!(ErrorCode::PollNotStarted);
+        }
+
+        if clock.unix_timestamp > poll_account.end_time as i64 {
+            return err!(ErrorCode::PollEnded);
+        }
+
+        // 候选人地址必须与索引对应
+        require_keys_eq!(
+            candidate_account.key(),
+            candidate_address(
+                &poll_account.key(),
+                poll_account.legacy_candidate_count,
+                candidate_index
+            ),
+            ErrorCode::InvalidCandidateForPoll
+        );
+
+        // 元数据中的集合必须已验证且等于投票活动指定的集合
+        let nft_mint = &ctx.accounts.nft_mint;
+        require!(
+            nft_mint.decimals == 0 && nft_mint.supply == 1,
+            ErrorCode::InvalidNft
+        );
+        let collection = metadata_collection(&ctx.accounts.nft_metadata.try_borrow_data()?)?;
+        require!(
+            collection == Some((true, collection_mint)),
+            ErrorCode::NftNotInCollection
+        );
+
+        candidate_account.votes = candidate_account
+            .votes
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+        poll_account.total_votes = poll_account
+            .total_votes
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+
+        let receipt = &mut ctx.accounts.nft_receipt;
+        receipt.poll = poll_account.key();
+        receipt.nft_mint = nft_mint.key();
+        receipt.voter = ctx.accounts.signer.key();
+        receipt.candidate = candidate_account.key();
+        receipt.voted_at = clock.unix_timestamp;
+        receipt.slot = clock.slot;
+
+        emit_cpi!(VoteCast {
+            poll: poll_account.key(),
+            candidate: candidate_account.key(),
+            candidate_index,
+            voter: ctx.accounts.signer.key(),
+            votes: candidate_account.votes,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // 排序投票：按偏好顺序传入候选人索引，选票保存在投票者的 ballot PDA 中，投票结束后由 tally_irv 开票
+    pub fn vote_ranked(ctx: Context<VoteRanked>, rankings: Vec<u32>) -> Result<()> {
+        let clock = Clock::get()?;
+
+        require_current_layout(&ctx.accounts.poll_account.to_account_info())?;
+
+        let poll_account = &mut ctx.accounts.poll_account;
+
+        require_accepting_votes(poll_account)?;
+
+        if clock.unix_timestamp < poll_account.start_time as i64 {
+            return err!(ErrorCode::PollNotStarted);
+        }
+
+        if clock.unix_timestamp > poll_account.end_time as i64 {
+            return err!(ErrorCode::PollEnded);
+        }
+
+        let BallotType::Ranked { max_rankings } = poll_account.options.ballot_type else {
+            return err!(ErrorCode::PollNotRanked);
+        };
+
+        check_gate_balance(
+            &poll_account.options,
+            ctx.accounts.voter_token_account.as_ref(),
+            &ctx.accounts.signer.key(),
+        )?;
+
+        // 至少排一名候选人且不能超过 max_rankings，索引必须有效且不能重复
+        require!(!rankings.is_empty(), ErrorCode::InvalidRankings);
+        require!(
+            rankings.len() <= max_rankings as usize,
+            ErrorCode::TooManySelections
+        );
+        for (position, candidate_index) in rankings.iter().enumerate() {
+            require!(
+                *candidate_index < poll_account.candidate_count,
+                ErrorCode::InvalidRankings
+            );
+            require!(
+                !rankings[..position].contains(candidate_index),
+                ErrorCode::DuplicateSelection
+            );
+        }
+
+        // 排序投票的 total_votes 是选票数量，tally_irv 据此判断一轮是否统计完毕
+        poll_account.total_votes = poll_account
+            .total_votes
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+
+        let ballot = &mut ctx.accounts.ranked_ballot;
+        ballot.poll = poll_account.key();
+        ballot.voter = ctx.accounts.signer.key();
+        ballot.rankings = rankings.clone();
+        ballot.counted_rounds = 0;
+        ballot.cast_at = clock.unix_timestamp;
+        ballot.slot = clock.slot;
+
+        emit_cpi!(RankedVoteCast {
+            poll: poll_account.key(),
+            voter: ctx.accounts.signer.key(),
+            rankings,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // 二次方投票：每个投票者有 credits_per_voter 点额度，在同一候选人上累计投 n 票花费 n² 点。
+    // 可以多次调用把票分给不同候选人，回执记录每个候选人的票数和已花费的额度
+    pub fn vote_quadratic(
+        ctx: Context<VoteQuadratic>,
+        candidate_index: u32,
+        votes: u64,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+
+        require_current_layout(&ctx.accounts.poll_account.to_account_info())?;
+
+        let poll_account = &mut ctx.accounts.poll_account;
+        let candidate_account = &mut ctx.accounts.candidate_account;
+
+        require_accepting_votes(poll_account)?;
+
+        if clock.unix_timestamp < poll_account.start_time as i64 {
+            return err!(ErrorCode::PollNotStarted);
+        }
+
+        if clock.unix_timestamp > poll_account.end_time as i64 {
+            return err!(ErrorCode::PollEnded);
+        }
+
+        let BallotType::Quadratic { credits_per_voter } = poll_account.options.ballot_type else {
+            return err!(ErrorCode::PollNotQuadratic);
+        };
+
+        check_gate_balance(
+            &poll_account.options,
+            ctx.accounts.voter_token_account.as_ref(),
+            &ctx.accounts.signer.key(),
+        )?;
+
+        // 投票通行证在第一次投票后标记为已使用
+        if poll_account.options.requires_pass {
+            let voter_pass = ctx
+                .accounts
+                .voter_pass
+                .as_mut()
+                .ok_or(ErrorCode::VoterPassRequired)?;
+            voter_pass.used = true;
+        }
+
+        require!(votes > 0, ErrorCode::InvalidVoteCount);
+
+        // 候选人地址必须与索引对应
+        require_keys_eq!(
+            candidate_account.key(),
+            candidate_address(
+                &poll_account.key(),
+                poll_account.legacy_candidate_count,
+                candidate_index
+            ),
+            ErrorCode::InvalidCandidateForPoll
+        );
+
+        let receipt = &mut ctx.accounts.voter_receipt;
+        receipt.voter = ctx.accounts.signer.key();
+        receipt.poll = poll_account.key();
+
+        // 在同一候选人上追加投票只需支付差额：(n + votes)² - n²
+        let position = receipt
+            .selections
+            .iter()
+            .position(|candidate| *candidate == candidate_account.key());
+        let previous_votes = position.map_or(0, |position| receipt.allocations[position]);
+        let new_votes = previous_votes
+            .checked_add(votes)
+            .ok_or(ErrorCode::Overflow)?;
+        let cost = new_votes
+            .checked_mul(new_votes)
+            .ok_or(ErrorCode::InsufficientCredits)?
+            - previous_votes * previous_votes;
+        let credits_spent = receipt
+            .credits_spent
+            .checked_add(cost)
+            .ok_or(ErrorCode::Overflow)?;
+        require!(
+            credits_spent <= credits_per_voter,
+            ErrorCode::InsufficientCredits
+        );
+
+        match position {
+            Some(position) => receipt.allocations[position] = new_votes,
+            None => {
+                receipt.selections.push(candidate_account.key());
+                receipt.allocations.push(new_votes);
+            }
+        }
+        receipt.credits_spent = credits_spent;
+        receipt.candidate = candidate_account.key();
+        receipt.voted_at = clock.unix_timestamp;
+        receipt.slot = clock.slot;
+        receipt.weight = receipt
+            .weight
+            .checked_add(votes)
+            .ok_or(ErrorCode::Overflow)?;
+
+        // 候选人和投票活动记录的是有效票数
+        candidate_account.votes = candidate_account
+            .votes
+            .checked_add(votes)
+            .ok_or(ErrorCode::Overflow)?;
+        poll_account.total_votes = poll_account
+            .total_votes
+            .checked_add(votes)
+            .ok_or(ErrorCode::Overflow)?;
+
+        // 回执随投过票的候选人数量扩容
+        resize_account(
+            &receipt.to_account_info(),
+            &ctx.accounts.signer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            VoterReceipt::quadratic_space(receipt.selections.len()),
+        )?;
+
+        emit_cpi!(VoteCast {
+            poll: poll_account.key(),
+            candidate: candidate_account.key(),
+            candidate_index,
+            voter: ctx.accounts.signer.key(),
+            votes: candidate_account.votes,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // 秘密投票：投票期间只在回执中提交 sha256(candidate_index || salt)，候选人票数保持不变
+    pub fn commit_vote(
+        ctx: Context<CommitVote>,
+        commitment: [u8; 32],
+        allowlist_proof: Option<AllowlistProof>,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+
+        require_current_layout(&ctx.accounts.poll_account.to_account_info())?;
+
+        let poll_account = &mut ctx.accounts.poll_account;
+
+        require_accepting_votes(poll_account)?;
+
+        if clock.unix_timestamp < poll_account.start_time as i64 {
+            return err!(ErrorCode::PollNotStarted);
+        }
+
+        if clock.unix_timestamp > poll_account.end_time as i64 {
+            return err!(ErrorCode::PollEnded);
+        }
+
+        require!(
+            poll_account.options.reveal_duration.is_some(),
+            ErrorCode::PollNotCommitReveal
+        );
+
+        check_gate_balance(
+            &poll_account.options,
+            ctx.accounts.voter_token_account.as_ref(),
+            &ctx.accounts.signer.key(),
+        )?;
+
+        // 票数在提交承诺时确定，揭示时计入候选人
+        let weight = voter_weight(
+            &poll_account.options,
+            &ctx.accounts.signer.key(),
+            allowlist_proof,
+            ctx.accounts.voter_pass.as_mut(),
+        )?;
+
+        poll_account.commit_count = poll_account
+            .commit_count
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+
+        let receipt = &mut ctx.accounts.voter_receipt;
+        receipt.voter = ctx.accounts.signer.key();
+        receipt.poll = poll_account.key();
+        receipt.voted_at = clock.unix_timestamp;
+        receipt.slot = clock.slot;
+        receipt.weight = weight;
+        receipt.commitment = Some(commitment);
+        receipt.revealed = false;
+
+        emit_cpi!(VoteCommitted {
+            poll: poll_account.key(),
+            voter: ctx.accounts.signer.key(),
+            commitment,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // 揭示期（end_time 之后的 reveal_duration 秒内）提交承诺的原文，校验通过后才计入候选人票数
+    pub fn reveal_vote(
+        ctx: Context<RevealVote>,
+        candidate_index: u32,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+
+        require_current_layout(&ctx.accounts.poll_account.to_account_info())?;
+
+        let poll_account = &mut ctx.accounts.poll_account;
+        let candidate_account = &mut ctx.accounts.candidate_account;
+        let receipt = &mut ctx.accounts.voter_receipt;
+
+        let reveal_duration = poll_account
+            .options
+            .reveal_duration
+            .ok_or(ErrorCode::PollNotCommitReveal)?;
+        if clock.unix_timestamp <= poll_account.end_time as i64 {
+            return err!(ErrorCode::RevealWindowNotOpen);
+        }
+        if clock.unix_timestamp > poll_account.end_time.saturating_add(reveal_duration) as i64 {
+            return err!(ErrorCode::RevealWindowClosed);
+        }
+
+        require_accepting_votes(poll_account)?;
+
+        let commitment = receipt.commitment.ok_or(ErrorCode::PollNotCommitReveal)?;
+        require!(!receipt.revealed, ErrorCode::VoteAlreadyRevealed);
+        require!(
+            vote_commitment(candidate_index, &salt) == commitment,
+            ErrorCode::CommitmentMismatch
+        );
+
+        // 候选人地址必须与索引对应
+        require_keys_eq!(
+            candidate_account.key(),
+            candidate_address(
+                &poll_account.key(),
+                poll_account.legacy_candidate_count,
+                candidate_index
+            ),
+            ErrorCode::InvalidCandidateForPoll
+        );
+
+        candidate_account.votes = candidate_account
+            .votes
+            .checked_add(receipt.weight)
+            .ok_or(ErrorCode::Overflow)?;
+        poll_account.total_votes = poll_account
+            .total_votes
+            .checked_add(receipt.weight)
+            .ok_or(ErrorCode::Overflow)?;
+        poll_account.reveal_count = poll_account
+            .reveal_count
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+
+        receipt.candidate = candidate_account.key();
+        receipt.selections = vec![candidate_account.key()];
+        receipt.revealed = true;
+
+        emit_cpi!(VoteCast {
+            poll: poll_account.key(),
+            candidate: candidate_account.key(),
+            candidate_index,
+            voter: ctx.accounts.signer.key(),
+            votes: candidate_account.votes,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // 投票结束（或投票活动被取消）后取回加权投票托管的代币，并关闭回执退还租金
+    pub fn withdraw_tokens(ctx: Context<WithdrawTokens>) -> Result<()> {
+        let clock = Clock::get()?;
+        let poll_key = ctx.accounts.poll_account.key();
+
+        if !poll_closable(&ctx.accounts.poll_account, &clock) {
+            return err!(ErrorCode::PollNotEnded);
+        }
+
+        let weight = ctx.accounts.voter_receipt.weight;
+        let signer_seeds: &[&[&[u8]]] = &[&[b"vault", poll_key.as_ref(), &[ctx.bumps.vault]]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault.to_account_info(),
+                    mint: ctx.accounts.weight_mint.to_account_info(),
+                    to: ctx.accounts.voter_token_account.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            weight,
+            ctx.accounts.weight_mint.decimals,
+        )?;
+
+        let poll_account = &mut ctx.accounts.poll_account;
+        poll_account.escrowed_amount = poll_account
+            .escrowed_amount
+            .checked_sub(weight)
+            .ok_or(ErrorCode::Overflow)?;
+
+        emit_cpi!(TokensWithdrawn {
+            poll: poll_key,
+            voter: ctx.accounts.signer.key(),
+            amount: weight,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // 投票期间改投其他候选人：票数从原候选人转到新候选人，总票数不变
+    pub fn change_vote(ctx: Context<ChangeVote>, candidate_index: u32) -> Result<()> {
+        let clock = Clock::get()?;
+
+        require_current_layout(&ctx.accounts.poll_account.to_account_info())?;
+
+        let poll_account = &ctx.accounts.poll_account;
+
+        require!(
+            poll_account.options.allow_vote_change,
+            ErrorCode::VoteChangeDisabled
+        );
+        require_accepting_votes(poll_account)?;
+
+        if clock.unix_timestamp < poll_account.start_time as i64 {
+            return err!(ErrorCode::PollNotStarted);
+        }
+
+        if clock.unix_timestamp > poll_account.end_time as i64 {
+            return err!(ErrorCode::PollEnded);
+        }
+
+        // 原候选人必须是回执中记录的候选人
+        let receipt = &mut ctx.accounts.voter_receipt;
+        require_keys_eq!(
+            ctx.accounts.previous_candidate.key(),
+            receipt.candidate,
+            ErrorCode::InvalidCandidateForPoll
+        );
+
+        // 新候选人地址必须与索引对应
+        require_keys_eq!(
+            ctx.accounts.candidate_account.key(),
+            candidate_address(
+                &poll_account.key(),
+                poll_account.legacy_candidate_count,
+                candidate_index
+            ),
+            ErrorCode::InvalidCandidateForPoll
+        );
+        require_keys_neq!(
+            ctx.accounts.candidate_account.key(),
+            receipt.candidate,
+            ErrorCode::SameCandidate
+        );
+
+        // 加权投票时转移的是回执中记录的全部权重
+        let previous_candidate = &mut ctx.accounts.previous_candidate;
+        previous_candidate.votes = previous_candidate
+            .votes
+            .checked_sub(receipt.weight)
+            .ok_or(ErrorCode::Overflow)?;
+        let candidate_account = &mut ctx.accounts.candidate_account;
+        candidate_account.votes = candidate_account
+            .votes
+            .checked_add(receipt.weight)
+            .ok_or(ErrorCode::Overflow)?;
+
+        receipt.candidate = candidate_account.key();
+        receipt.selections = vec![candidate_account.key()];
+        receipt.voted_at = clock.unix_timestamp;
+        receipt.slot = clock.slot;
+
+        emit_cpi!(VoteChanged {
+            poll: poll_account.key(),
+            previous_candidate: previous_candidate.key(),
+            candidate: candidate_account.key(),
+            candidate_index,
+            voter: ctx.accounts.signer.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // 投票期间撤回投票：从候选人票数和总票数中扣除回执的权重，关闭回执并退还租金，之后可以重新投票
+    pub fn retract_vote(ctx: Context<RetractVote>) -> Result<()> {
+        let clock = Clock::get()?;
+
+        require_current_layout(&ctx.accounts.poll_account.to_account_info())?;
+
+        let poll_account = &mut ctx.accounts.poll_account;
+
+        require!(
+            poll_account.options.allow_vote_retraction,
+            ErrorCode::VoteRetractionDisabled
+        );
+        require_accepting_votes(poll_account)?;
+
+        if clock.unix_timestamp < poll_account.start_time as i64 {
+            return err!(ErrorCode::PollNotStarted);
+        }
+
+        if clock.unix_timestamp > poll_account.end_time as i64 {
+            return err!(ErrorCode::PollEnded);
+        }
+
+        let weight = ctx.accounts.voter_receipt.weight;
+        let candidate_account = &mut ctx.accounts.candidate_account;
+        candidate_account.votes = candidate_account
+            .votes
+            .checked_sub(weight)
+            .ok_or(ErrorCode::Overflow)?;
+        poll_account.total_votes = poll_account
+            .total_votes
+            .checked_sub(weight)
+            .ok_or(ErrorCode::Overflow)?;
+
+        emit_cpi!(VoteRetracted {
+            poll: poll_account.key(),
+            candidate: candidate_account.key(),
+            voter: ctx.accounts.signer.key(),
+            votes: candidate_account.votes,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // 分批关闭已结束投票活动的候选人账户（包括分项及其候选人）并回收租金，全部关闭后才能执行 close_poll
+    pub fn close_candidates<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseCandidates<'info>>,
+    ) -> Result<()> {
+        require_role(
+            &ctx.accounts.poll_account,
+            &ctx.accounts.signer.key(),
+            AdminRole::Owner,
+        )?;
+
+        require_current_layout(&ctx.accounts.poll_account.to_account_info())?;
+
+        let clock = Clock::get()?;
+        let poll_key = ctx.accounts.poll_account.key();
+        let poll_account = &mut ctx.accounts.poll_account;
+
+        if !poll_closable(poll_account, &clock) {
+            return err!(ErrorCode::PollNotEnded);
+        }
+        // 揭示期内仍会更新候选人票数，不能提前关闭候选人账户
+        if poll_account.status != PollStatus::Cancelled {
+            require_reveal_ended(poll_account, &clock)?;
+        }
+
+        let closed = close_candidate_accounts(
+            &poll_key,
+            ctx.remaining_accounts,
+            &ctx.accounts.recipient.to_account_info(),
+        )?;
+        poll_account.closed_candidate_count = poll_account
+            .closed_candidate_count
+            .checked_add(closed)
+            .ok_or(ErrorCode::Overflow)?;
+
+        Ok(())
+    }
+
+    // 关闭已结束或已取消的投票活动，回收投票账户及结算结果账户的租金
+    pub fn close_poll<'info>(ctx: Context<'_, '_, '_, 'info, ClosePoll<'info>>) -> Result<()> {
+        require_role(
+            &ctx.accounts.poll_account,
+            &ctx.accounts.signer.key(),
+            AdminRole::Owner,
+        )?;
+
+        require_current_layout(&ctx.accounts.poll_account.to_account_info())?;
+
+        let clock = Clock::get()?;
+        let poll_account = &ctx.accounts.poll_account;
+
+        // 已取消的投票活动不必等到结束或揭示期结束
+        if !poll_closable(poll_account, &clock) {
+            return err!(ErrorCode::PollNotEnded);
+        }
+        if poll_account.status != PollStatus::Cancelled {
+            require_reveal_ended(poll_account, &clock)?;
+        }
+
+        // 执行过 finalize_poll 就会创建结果账户，必须一并关闭；排序投票由 tally_irv 结算，没有该账户
+        let ranked = poll_account.options.ballot_type.is_ranked();
+        let finalized = poll_account.status == PollStatus::Finalized;
+        if (finalized && !ranked) || poll_account.tallied_count > 0 {
+            require!(
+                ctx.accounts.poll_result.is_some(),
+                ErrorCode::PollResultRequired
+            );
+        }
+
+        // 剩余的候选人账户可以在同一笔交易中一并关闭；候选人必须全部关闭，避免遗留无法回收的账户
+        let closed = close_candidate_accounts(
+            &poll_account.key(),
+            ctx.remaining_accounts,
+            &ctx.accounts.recipient.to_account_info(),
+        )?;
+        require!(
+            poll_account.closed_candidate_count as u64 + closed as u64
+                == poll_account.candidate_count as u64
+                    + poll_account.contest_count as u64
+                    + poll_account.contest_candidate_count as u64,
+            ErrorCode::CandidatesNotClosed
+        );
+
+        // 加权投票活动有人投票后会创建金库，所有代币被取回后一并关闭
+        if poll_account.options.weight_mint.is_some() && poll_account.total_votes > 0 {
+            let (Some(vault), Some(token_program), Some(weight_mint)) = (
+                &ctx.accounts.vault,
+                &ctx.accounts.token_program,
+                &ctx.accounts.weight_mint,
+            ) else {
+                return err!(ErrorCode::TokensNotWithdrawn);
+            };
+            require!(
+                poll_account.escrowed_amount == 0,
+                ErrorCode::TokensNotWithdrawn
+            );
+
+            let poll_key = poll_account.key();
+            let signer_seeds: &[&[&[u8]]] = &[&[
+                b"vault",
+                poll_key.as_ref(),
+                &[ctx.bumps.vault.ok_or(ErrorCode::TokensNotWithdrawn)?],
+            ]];
+
+            // 他人直接转入金库的零散代币转给 recipient 的代币账户，否则金库无法关闭
+            if vault.amount > 0 {
+                let recipient_token_account = ctx
+                    .accounts
+                    .recipient_token_account
+                    .as_ref()
+                    .ok_or(ErrorCode::InvalidTokenAccount)?;
+                token_interface::transfer_checked(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        TransferChecked {
+                            from: vault.to_account_info(),
+                            mint: weight_mint.to_account_info(),
+                            to: recipient_token_account.to_account_info(),
+                            authority: vault.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    vault.amount,
+                    weight_mint.decimals,
+                )?;
+            }
+
+            // 投票时扣留在金库中的 Token-2022 转账手续费需要先收集到 mint，否则金库无法关闭
+            if withheld_transfer_fees(&vault.to_account_info())? > 0 {
+                token_interface::harvest_withheld_tokens_to_mint(
+                    CpiContext::new(
+                        token_program.to_account_info(),
+                        HarvestWithheldTokensToMint {
+                            token_program_id: token_program.to_account_info(),
+                            mint: weight_mint.to_account_info(),
+                        },
+                    ),
+                    vec![vault.to_account_info()],
+                )?;
+            }
+
+            token_interface::close_account(CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                CloseAccount {
+                    account: vault.to_account_info(),
+                    destination: ctx.accounts.recipient.to_account_info(),
+                    authority: vault.to_account_info(),
+                },
+                signer_seeds,
+            ))?;
+        }
+
+        // 排序投票活动执行过 tally_irv 后会创建开票结果账户，必须一并关闭
+        if ranked {
+            let irv_result = ctx
+                .accounts
+                .irv_result
+                .as_ref()
+                .ok_or(ErrorCode::IrvResultRequired)?;
+            if irv_result.owner == ctx.program_id {
+                close_account(
+                    &irv_result.to_account_info(),
+                    &ctx.accounts.recipient.to_account_info(),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // 投票结束（或投票活动已被取消、关闭）后，投票者关闭自己的投票回执取回租金
+    pub fn close_receipt(ctx: Context<CloseReceipt>) -> Result<()> {
+        let poll_info = ctx.accounts.poll_account.to_account_info();
+
+        // 投票活动仍然存在时必须已经结束或被取消；若已被 close_poll 关闭，则不可能再投票，可直接回收
+        if poll_info.owner == ctx.program_id {
+            let clock = Clock::get()?;
+            let closable = if poll_info.data_len() == LEGACY_POLL_ACCOUNT_SPACE {
+                clock.unix_timestamp > load_legacy_poll(&poll_info)?.end_time as i64
+            } else {
+                let poll = PollAccount::try_deserialize(&mut &poll_info.try_borrow_data()?[..])?;
+                // 加权投票的回执由 withdraw_tokens 在取回代币时关闭
+                require!(
+                    poll.options.weight_mint.is_none(),
+                    ErrorCode::TokensNotWithdrawn
+                );
+                poll_closable(&poll, &clock)
+            };
+
+            if !closable {
+                return err!(ErrorCode::PollNotEnded);
+            }
+        }
+
+        // 回执可能是旧版布局（没有 candidate 等字段），因此不反序列化，直接按地址关闭
+        close_account(
+            &ctx.accounts.voter_receipt.to_account_info(),
+            &ctx.accounts.signer.to_account_info(),
+        )
+    }
+
+    // 排序投票开票完成（或投票活动已被取消、关闭）后，投票者关闭自己的选票取回租金
+    pub fn close_ballot(ctx: Context<CloseBallot>) -> Result<()> {
+        let poll_info = ctx.accounts.poll_account.to_account_info();
+
+        // 每轮开票都要读取所有选票，投票活动仍然存在时必须已经开票完成或被取消
+        if poll_info.owner == ctx.program_id {
+            let poll = PollAccount::try_deserialize(&mut &poll_info.try_borrow_data()?[..])?;
+            require!(
+                matches!(poll.status, PollStatus::Finalized | PollStatus::Cancelled),
+                ErrorCode::IrvTallyRequired
+            );
+        }
+
+        Ok(())
+    }
+
+    // 投票活动结束、被取消或已被关闭后，投票时持有 NFT 的钱包关闭 NFT 回执取回租金
+    pub fn close_nft_receipt(ctx: Context<CloseNftReceipt>) -> Result<()> {
+        let poll_info = ctx.accounts.poll_account.to_account_info();
+
+        // 与 close_receipt 相同：投票活动仍然存在时必须已经结束或被取消
+        if poll_info.owner == ctx.program_id {
+            let poll = PollAccount::try_deserialize(&mut &poll_info.try_borrow_data()?[..])?;
+            require!(
+                poll_closable(&poll, &Clock::get()?),
+                ErrorCode::PollNotEnded
+            );
+        }
+
+        Ok(())
+    }
+
+    // 投票结束后任何人都可以结算：统计所有候选人票数，按 tie_break 规则在链上记录获胜者。
+    // 候选人较多时可分批调用，全部统计完成后才会标记为已结算
+    pub fn finalize_poll<'info>(
+        ctx: Context<'_, '_, '_, 'info, FinalizePoll<'info>>,
+    ) -> Result<()> {
+        require_current_layout(&ctx.accounts.poll_account.to_account_info())?;
+
+        let clock = Clock::get()?;
+        let poll_key = ctx.accounts.poll_account.key();
+        let poll_account = &mut ctx.accounts.poll_account;
+        let poll_result = &mut ctx.accounts.poll_result;
+
+        // 分项投票的票数记录在各分项及其候选人账户中，无论何时都不能按普通候选人结算
+        require!(
+            poll_account.contest_count == 0,
+            ErrorCode::ContestTallyUnsupported
+        );
+
+        if clock.unix_timestamp <= poll_account.end_time as i64 {
+            return err!(ErrorCode::PollNotEnded);
+        }
+
+        require!(
+            poll_account.status != PollStatus::Finalized,
+            ErrorCode::PollAlreadyFinalized
+        );
+        require!(
+            poll_account.status != PollStatus::Cancelled,
+            ErrorCode::PollCancelled
+        );
+        require!(
+            !poll_account.options.ballot_type.is_ranked(),
+            ErrorCode::IrvTallyRequired
+        );
+        require_reveal_ended(poll_account, &clock)?;
+
+        // 每批候选人从第 tallied_count 个开始按索引连续传入，保证每个候选人只统计一次
+        require!(
+            ctx.remaining_accounts.len() as u64
+                <= (poll_account.candidate_count - poll_account.tallied_count) as u64,
+            ErrorCode::InvalidCandidateAccounts
+        );
+
+        poll_result.poll = poll_key;
+        for candidate_info in ctx.remaining_accounts {
+            let candidate_index = poll_account.tallied_count;
+            let candidate = load_candidate(
+                candidate_info,
+                &poll_key,
+                poll_account.legacy_candidate_count,
+                candidate_index,
+            )?;
+            poll_result.ranked_tallies.push(CandidateTally {
+                candidate_index,
+                votes: candidate.votes,
+            });
+            poll_account.tallied_count += 1;
+        }
+
+        if poll_account.tallied_count == poll_account.candidate_count {
+            // 按票数降序排列；sort_by_key 是稳定排序，票数相同的候选人保持添加顺序
+            poll_result
+                .ranked_tallies
+                .sort_by_key(|tally| std::cmp::Reverse(tally.votes));
+
+            // 无人投票时不产生获胜者
+            let ranked_tallies = &poll_result.ranked_tallies;
+            let top_votes = ranked_tallies.first().map_or(0, |tally| tally.votes);
+            poll_result.winners = if top_votes == 0 {
+                Vec::new()
+            } else {
+                match poll_account.options.tie_break {
+                    // 所有并列第一的候选人共同获胜
+                    TieBreak::Shared => ranked_tallies
+                        .iter()
+                        .take_while(|tally| tally.votes == top_votes)
+                        .map(|tally| tally.candidate_index)
+                        .collect(),
+                    // 并列时最先添加（索引最小）的候选人获胜
+                    TieBreak::LowestIndex => vec![ranked_tallies[0].candidate_index],
+                }
+            };
+            poll_account.status = PollStatus::Finalized;
+        }
+
+        // 结果账户随统计进度扩容
+        resize_account(
+            &poll_result.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            PollResult::space(poll_result.ranked_tallies.len(), poll_result.winners.len()),
+        )?;
+
+        if poll_account.status == PollStatus::Finalized {
+            emit_cpi!(PollFinalized {
+                poll: poll_key,
+                winners: poll_result.winners.clone(),
+                total_votes: poll_account.total_votes,
+                unrevealed_commits: poll_account.commit_count - poll_account.reveal_count,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+
+        Ok(())
+    }
+
+    // 投票结束后任何人都可以对排序投票执行即时决选（IRV）开票。每一轮都要把所有选票通过
+    // remaining_accounts 分批传入，一轮统计完毕后淘汰得票最少的候选人，直到有人获得过半有效票
+    pub fn tally_irv<'info>(ctx: Context<'_, '_, '_, 'info, TallyIrv<'info>>) -> Result<()> {
+        require_current_layout(&ctx.accounts.poll_account.to_account_info())?;
+
+        let clock = Clock::get()?;
+        let poll_key = ctx.accounts.poll_account.key();
+        let poll_account = &mut ctx.accounts.poll_account;
+        let irv_result = &mut ctx.accounts.irv_result;
+
+        if clock.unix_timestamp <= poll_account.end_time as i64 {
+            return err!(ErrorCode::PollNotEnded);
+        }
+
+        require!(
+            poll_account.options.ballot_type.is_ranked(),
+            ErrorCode::PollNotRanked
+        );
+        require!(
+            poll_account.status != PollStatus::Finalized,
+            ErrorCode::PollAlreadyFinalized
+        );
+        require!(
+            poll_account.status != PollStatus::Cancelled,
+            ErrorCode::PollCancelled
+        );
+
+        // 首次开票时初始化结果账户
+        if irv_result.poll == Pubkey::default() {
+            irv_result.poll = poll_key;
+            irv_result.tallies = vec![0; poll_account.candidate_count as usize];
+        }
+
+        // 每张选票计入排名中第一个未被淘汰的候选人，全部被淘汰则计为耗尽票；
+        // 选票记录已统计的轮数，保证每轮只统计一次
+        for ballot_info in ctx.remaining_accounts {
+            require_keys_eq!(
+                *ballot_info.owner,
+                crate::ID,
+                anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram
+            );
+            let mut ballot =
+                RankedBallot::try_deserialize(&mut &ballot_info.try_borrow_data()?[..])?;
+            require_keys_eq!(ballot.poll, poll_key, ErrorCode::InvalidBallot);
+            require!(
+                ballot.counted_rounds == irv_result.round,
+                ErrorCode::BallotAlreadyCounted
+            );
+            let eliminated = &irv_result.eliminated;
+            match ballot
+                .rankings
+                .iter()
+                .find(|candidate_index| !eliminated.contains(candidate_index))
+            {
+                // 计票表的长度在首次开票时确定，越界的排名视为无效选票
+                Some(&candidate_index) => {
+                    *irv_result
+                        .tallies
+                        .get_mut(candidate_index as usize)
+                        .ok_or(ErrorCode::InvalidBallot)? += 1
+                }
+                None => irv_result.exhausted_ballots += 1,
+            }
+            irv_result.counted_ballots += 1;
+            ballot.counted_rounds += 1;
+            ballot.try_serialize(&mut &mut ballot_info.try_borrow_mut_data()?[..])?;
+        }
+
+        // 本轮还有选票未统计，等待后续调用
+        if irv_result.counted_ballots < poll_account.total_votes {
+            return Ok(());
+        }
+
+        // 得票过半（或只剩一名候选人）即当选，否则淘汰得票最少的候选人；
+        // 得票相同时保留最先添加（索引最小）的候选人
+        let standing: Vec<u32> = (0..irv_result.tallies.len() as u32)
+            .filter(|candidate_index| !irv_result.eliminated.contains(candidate_index))
+            .collect();
+        let tallies = &irv_result.tallies;
+        let rank = |candidate_index: &u32| {
+            (
+                tallies[*candidate_index as usize],
+                std::cmp::Reverse(*candidate_index),
+            )
+        };
+        let active_ballots = irv_result.counted_ballots - irv_result.exhausted_ballots;
+        let mut winner = None;
+        let mut eliminated = None;
+        // 所有选票都已耗尽（或无人投票）时不产生获胜者
+        if active_ballots > 0 {
+            let leader = standing.iter().copied().max_by_key(rank);
+            match leader {
+                Some(leader)
+                    if tallies[leader as usize] > active_ballots / 2 || standing.len() == 1 =>
+                {
+                    winner = Some(leader)
+                }
+                _ => eliminated = standing.iter().copied().min_by_key(rank),
+            }
+        }
+
+        emit_cpi!(IrvRoundCompleted {
+            poll: poll_key,
+            round: irv_result.round,
+            tallies: irv_result.tallies.clone(),
+            exhausted_ballots: irv_result.exhausted_ballots,
+            eliminated,
+            winner,
+            timestamp: clock.unix_timestamp,
+        });
+
+        match eliminated {
+            // 进入下一轮，所有选票需要重新统计
+            Some(candidate_index) => {
+                irv_result.eliminated.push(candidate_index);
+                irv_result.round += 1;
+                irv_result.counted_ballots = 0;
+                irv_result.exhausted_ballots = 0;
+                irv_result.tallies.fill(0);
+            }
+            None => {
+                irv_result.winner = winner;
+                irv_result.complete = true;
+                poll_account.status = PollStatus::Finalized;
+            }
+        }
+
+        Ok(())
+    }
+
+    // 将旧版布局的投票账户迁移到当前布局，并根据候选人账户重新统计 total_votes
+    pub fn migrate_poll<'info>(ctx: Context<'_, '_, '_, 'info, MigratePoll<'info>>) -> Result<()> {
+        let poll_info = ctx.accounts.poll_account.to_account_info();
+
+        require!(
+            poll_info.data_len() == LEGACY_POLL_ACCOUNT_SPACE,
+            ErrorCode::PollAlreadyMigrated
+        );
+
+        let legacy = load_legacy_poll(&poll_info)?;
+
+        // 候选人账户必须全部传入且顺序与 candidates 一致，保证重新统计的票数完整
+        require!(
+            ctx.remaining_accounts.len() == legacy.candidates.len(),
+            ErrorCode::InvalidCandidateAccounts
+        );
+
+        let mut total_votes: u64 = 0;
+        for (candidate_info, expected_key) in
+            ctx.remaining_accounts.iter().zip(legacy.candidates.iter())
+        {
+            require_keys_eq!(
+                candidate_info.key(),
+                *expected_key,
+                ErrorCode::InvalidCandidateAccounts
+            );
+            require_keys_eq!(
+                *candidate_info.owner,
+                crate::ID,
+                anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram
+            );
+            let candidate =
+                CandidateAccount::try_deserialize(&mut &candidate_info.try_borrow_data()?[..])?;
+            total_votes = total_votes
+                .checked_add(candidate.votes)
+                .ok_or(ErrorCode::Overflow)?;
+        }
+
+        resize_account(
+            &poll_info,
+            &ctx.accounts.signer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            8 + PollAccount::INIT_SPACE,
+        )?;
+
+        let poll_account = PollAccount {
+            authority: legacy.authority,
+            name: legacy.name,
+            description: legacy.description,
+            start_time: legacy.start_time,
+            end_time: legacy.end_time,
+            candidate_count: legacy.candidate_count as u32,
+            // 已有候选人的 PDA 使用 u8 索引，之后新增的候选人使用 u32 索引
+            legacy_candidate_count: legacy.candidate_count,
+            total_votes,
+            // 旧投票活动创建时没有指定平票规则，默认并列者共同获胜，也不开启其他可选功能
+            options: PollOptions::default(),
+            status: PollStatus::Active,
+            tallied_count: 0,
+            closed_candidate_count: 0,
+            escrowed_amount: 0,
+            commit_count: 0,
+            reveal_count: 0,
+            contest_count: 0,
+            contest_candidate_count: 0,
+            pending_authority: None,
+            admins: Vec::new(),
+        };
+        poll_account.try_serialize(&mut &mut poll_info.try_borrow_mut_data()?[..])?;
+
+        Ok(())
+    }
+}
+
+// Metaplex Token Metadata 程序
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey =
+    pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+// 元数据账户第一个字节的类型标记：MetadataV1
+const METADATA_V1_KEY: u8 = 4;
+
+// 每个投票活动最多的管理员数量，不含 authority
+pub const MAX_POLL_ADMINS: usize = 5;
+
+// 旧版 PollAccount 布局，仅用于 migrate_poll 等解析旧账户
+#[derive(AnchorDeserialize, InitSpace)]
+struct LegacyPollAccount {
+    authority: Pubkey,
+    #[max_len(32)]
+    name: String,
+    #[max_len(280)]
+    description: String,
+    start_time: u64,
+    end_time: u64,
+    candidate_count: u8,
+    #[max_len(15)]
+    candidates: Vec<Pubkey>,
+}
+
+// 旧版投票账户都按固定大小创建，据此区分新旧布局
+const LEGACY_POLL_ACCOUNT_SPACE: usize = 8 + LegacyPollAccount::INIT_SPACE;
+// 当前布局的大小不能与旧版相同，否则无法区分
+const _: () = assert!(8 + PollAccount::INIT_SPACE != LEGACY_POLL_ACCOUNT_SPACE);
+
+// 解析旧版布局的投票账户
+fn load_legacy_poll(poll_info: &AccountInfo) -> Result<LegacyPollAccount> {
+    let data = poll_info.try_borrow_data()?;
+    require!(
+        data.len() >= 8 && data[..8] == *PollAccount::DISCRIMINATOR,
+        anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+    );
+    LegacyPollAccount::deserialize(&mut &data[8..])
+        .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into())
+}
+
+// 旧版布局的投票账户需先执行 migrate_poll
+fn require_current_layout(poll_info: &AccountInfo) -> Result<()> {
+    require!(
+        poll_info.data_len() == 8 + PollAccount::INIT_SPACE,
+        ErrorCode::PollMigrationRequired
+    );
+    Ok(())
+}
+
+// 候选人 PDA：迁移前已有的候选人使用 u8 索引，其余使用 u32 索引
+fn candidate_address(poll: &Pubkey, legacy_candidate_count: u8, candidate_index: u32) -> Pubkey {
+    if candidate_index < legacy_candidate_count as u32 {
+        Pubkey::find_program_address(
+            &[b"candidate", poll.as_ref(), &[candidate_index as u8]],
+            &crate::ID,
+        )
+        .0
+    } else {
+        Pubkey::find_program_address(
+            &[b"candidate", poll.as_ref(), &candidate_index.to_le_bytes()],
+            &crate::ID,
+        )
+        .0
+    }
+}
+
+// 分项 PDA：`[b"contest", poll, contest_index]`
+fn contest_address(poll: &Pubkey, contest_index: u32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"contest", poll.as_ref(), &contest_index.to_le_bytes()],
+        &crate::ID,
+    )
+    .0
+}
+
+// 分项候选人 PDA：`[b"candidate", contest, candidate_index]`
+fn contest_candidate_address(contest: &Pubkey, candidate_index: u32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"candidate",
+            contest.as_ref(),
+            &candidate_index.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+    .0
+}
+
+// 校验 remaining_accounts 中的候选人账户与索引对应，并读取其数据
+fn load_candidate(
+    candidate_info: &AccountInfo,
+    poll: &Pubkey,
+    legacy_candidate_count: u8,
+    candidate_index: u32,
+) -> Result<CandidateAccount> {
+    require_keys_eq!(
+        candidate_info.key(),
+        candidate_address(poll, legacy_candidate_count, candidate_index),
+        ErrorCode::InvalidCandidateAccounts
+    );
+    require_keys_eq!(
+        *candidate_info.owner,
+        crate::ID,
+        anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram
+    );
+    CandidateAccount::try_deserialize(&mut &candidate_info.try_borrow_data()?[..])
+}
+
+// 关闭属于该投票活动的候选人账户或分项账户，返回关闭的数量；已关闭的账户不再归程序所有，不会被重复计数
+fn close_candidate_accounts<'info>(
+    poll: &Pubkey,
+    candidates: &[AccountInfo<'info>],
+    recipient: &AccountInfo<'info>,
+) -> Result<u32> {
+    for candidate_info in candidates {
+        require_keys_eq!(
+            *candidate_info.owner,
+            crate::ID,
+            anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram
+        );
+        // 分项账户与候选人账户一样记录所属的投票活动
+        let candidate_poll = {
+            let data = candidate_info.try_borrow_data()?;
+            if data.starts_with(Contest::DISCRIMINATOR) {
+                Contest::try_deserialize(&mut &data[..])?.poll
+            } else {
+                CandidateAccount::try_deserialize(&mut &data[..])?.poll
+            }
+        };
+        require_keys_eq!(candidate_poll, *poll, ErrorCode::InvalidCandidateAccounts);
+        close_account(candidate_info, recipient)?;
+    }
+    Ok(candidates.len() as u32)
+}
+
+// 补足租金后调整账户大小
+fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_space: usize,
+) -> Result<()> {
+    let rent_shortfall = Rent::get()?
+        .minimum_balance(new_space)
+        .saturating_sub(account.lamports());
+    if rent_shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent_shortfall,
+        )?;
+    }
+    account.resize(new_space)?;
+    Ok(())
+}
+
+// 创建由本程序拥有的 PDA 账户；与 Anchor 的 init 相同，地址上已有 lamports 时
+// create_account 会失败，改为只补足租金差额，再分配空间并指定所有者
+fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.lamports();
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                signer_seeds,
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    let rent_shortfall = rent.saturating_sub(current_lamports);
+    if rent_shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent_shortfall,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate {
+                account_to_allocate: account.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Assign {
+                account_to_assign: account.clone(),
+            },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )
+}
+
+// 从 Metaplex 元数据账户（MetadataV1）中依次跳过前面的字段，读取 collection：(verified, key)
+fn metadata_collection(mut data: &[u8]) -> Result<Option<(bool, Pubkey)>> {
+    let parse = |data: &mut &[u8]| -> std::io::Result<Option<(bool, Pubkey)>> {
+        // key、update_authority、mint
+        let (key, _, _) = <(u8, Pubkey, Pubkey)>::deserialize(data)?;
+        if key != METADATA_V1_KEY {
+            return Err(std::io::ErrorKind::InvalidData.into());
+        }
+        // name、symbol、uri、seller_fee_basis_points
+        <(String, String, String, u16)>::deserialize(data)?;
+        // creators：(address, verified, share)
+        Option::<Vec<(Pubkey, bool, u8)>>::deserialize(data)?;
+        // primary_sale_happened、is_mutable、edition_nonce、token_standard
+        <(bool, bool, Option<u8>, Option<u8>)>::deserialize(data)?;
+        Option::<(bool, Pubkey)>::deserialize(data)
+    };
+    parse(&mut data).map_err(|_| ErrorCode::InvalidNft.into())
+}
+
+// 白名单叶子：sha256(0x00 || voter || weight 小端序)
+fn allowlist_leaf(voter: &Pubkey, weight: u64) -> [u8; 32] {
+    hashv(&[&[0], voter.as_ref(), &weight.to_le_bytes()]).to_bytes()
+}
+
+// 自下而上计算 merkle 根，父节点为 sha256(0x01 || 较小的子节点 || 较大的子节点)
+fn verify_allowlist_proof(root: [u8; 32], leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        let (left, right) = if node <= *sibling {
+            (node, *sibling)
+        } else {
+            (*sibling, node)
+        };
+        hashv(&[&[1], &left, &right]).to_bytes()
+    });
+    computed == root
+}
+
+// 代币门槛：投票者需持有足够数量的指定代币，兼容 Token-2022
+fn check_gate_balance(
+    options: &PollOptions,
+    voter_token_account: Option<&InterfaceAccount<TokenAccount>>,
+    voter: &Pubkey,
+) -> Result<()> {
+    if let Some(gate_mint) = options.gate_mint {
+        let token_account = voter_token_account.ok_or(ErrorCode::InsufficientTokenBalance)?;
+        require_keys_eq!(
+            token_account.mint,
+            gate_mint,
+            ErrorCode::InvalidTokenAccount
+        );
+        require_keys_eq!(token_account.owner, *voter, ErrorCode::InvalidTokenAccount);
+        require!(
+            token_account.amount >= options.min_balance,
+            ErrorCode::InsufficientTokenBalance
+        );
+    }
+    Ok(())
+}
+
+// 投票者的票数：白名单叶子中的票数，或投票通行证的票数（通行证随即标记为已使用），否则为 1
+fn voter_weight(
+    options: &PollOptions,
+    voter: &Pubkey,
+    allowlist_proof: Option<AllowlistProof>,
+    voter_pass: Option<&mut Account<VoterPass>>,
+) -> Result<u64> {
+    match options.allowlist_root {
+        // 白名单：叶子为 (投票者, 票数)，票数计入候选人和总票数
+        Some(root) => {
+            let allowlist_proof = allowlist_proof.ok_or(ErrorCode::NotOnAllowlist)?;
+            let leaf = allowlist_leaf(voter, allowlist_proof.weight);
+            require!(
+                allowlist_proof.weight > 0
+                    && verify_allowlist_proof(root, leaf, &allowlist_proof.proof),
+                ErrorCode::NotOnAllowlist
+            );
+            Ok(allowlist_proof.weight)
+        }
+        // 投票通行证：使用后不能再被撤销
+        None if options.requires_pass => {
+            let voter_pass = voter_pass.ok_or(ErrorCode::VoterPassRequired)?;
+            voter_pass.used = true;
+            Ok(voter_pass.weight)
+        }
+        None => Ok(1),
+    }
+}
+
+// 秘密投票的承诺：sha256(candidate_index 小端序 || salt)
+fn vote_commitment(candidate_index: u32, salt: &[u8; 32]) -> [u8; 32] {
+    hashv(&[&candidate_index.to_le_bytes(), salt]).to_bytes()
+}
+
+// 秘密投票在揭示期结束前不能结算或关闭
+fn require_reveal_ended(poll_account: &PollAccount, clock: &Clock) -> Result<()> {
+    if let Some(reveal_duration) = poll_account.options.reveal_duration {
+        require!(
+            clock.unix_timestamp > poll_account.end_time.saturating_add(reveal_duration) as i64,
+            ErrorCode::RevealWindowOpen
+        );
+    }
+    Ok(())
+}
+
+// 校验签名者拥有所需的管理角色：authority 和 Owner 角色拥有全部权限；
+// 不是管理员时返回 Unauthorized，是管理员但缺少该角色时返回 MissingAdminRole
+fn require_role(poll_account: &PollAccount, signer: &Pubkey, role: AdminRole) -> Result<()> {
+    if poll_account.authority == *signer {
+        return Ok(());
+    }
+    let entry = poll_account
+        .admins
+        .iter()
+        .find(|entry| entry.admin == *signer)
+        .ok_or(ErrorCode::Unauthorized)?;
+    require!(
+        entry.role == AdminRole::Owner || entry.role == role,
+        ErrorCode::MissingAdminRole
+    );
+    Ok(())
+}
+
+// 只有进行中的投票活动接受投票，暂停、取消和已结算分别返回不同的错误
+fn require_accepting_votes(poll_account: &PollAccount) -> Result<()> {
+    match poll_account.status {
+        PollStatus::Active => Ok(()),
+        PollStatus::Paused => err!(ErrorCode::PollPaused),
+        PollStatus::Cancelled => err!(ErrorCode::PollCancelled),
+        PollStatus::Finalized => err!(ErrorCode::PollFinalized),
+    }
+}
+
+// Token-2022 代币账户中扣留的转账手续费，其他代币账户为 0
+fn withheld_transfer_fees(token_account: &AccountInfo) -> Result<u64> {
+    if token_account.owner != &spl_token_2022::ID {
+        return Ok(0);
+    }
+    let data = token_account.try_borrow_data()?;
+    let account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+    Ok(account
+        .get_extension::<TransferFeeAmount>()
+        .map_or(0, |fee| u64::from(fee.withheld_amount)))
+}
+
+// 暂停和取消只能在投票结束、开始结算之前进行，否则会卡住揭示和已开始的结算
+fn require_before_tally(poll_account: &PollAccount, clock: &Clock) -> Result<()> {
+    require!(
+        clock.unix_timestamp <= poll_account.end_time as i64,
+        ErrorCode::PollEnded
+    );
+    require!(poll_account.tallied_count == 0, ErrorCode::TallyInProgress);
+    Ok(())
+}
+
+// 投票活动已结束或已被取消，可以回收相关账户
+fn poll_closable(poll_account: &PollAccount, clock: &Clock) -> bool {
+    poll_account.status == PollStatus::Cancelled
+        || clock.unix_timestamp > poll_account.end_time as i64
+}
+
+// 投票通行证的票数，未指定时为 1
+fn pass_weight(weight: Option<u64>) -> Result<u64> {
+    let weight = weight.unwrap_or(1);
+    require!(weight > 0, ErrorCode::InvalidPassWeight);
+    Ok(weight)
+}
+
+// 名称（投票活动和候选人）不能为空，且不能超过 #[max_len(32)]
+fn validate_name(name: &str) -> Result<()> {
+    require!(!name.trim().is_empty(), ErrorCode::EmptyName);
+    require!(name.len() <= 32, ErrorCode::NameTooLong);
+    Ok(())
+}
+
+// 手动关闭程序拥有的账户（用于 remaining_accounts 中无法使用 close 约束的账户）
+fn close_account<'info>(
+    account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+) -> Result<()> {
+    let lamports = account.lamports();
+    **destination.try_borrow_mut_lamports()? = destination
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(ErrorCode::Overflow)?;
+    **account.try_borrow_mut_lamports()? = 0;
+
+    account.assign(&system_program::ID);
+    account.resize(0)?;
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct InitializePoll<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + PollAccount::INIT_SPACE,
+        seeds = [b"poll", signer.key().as_ref(), poll_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+    // 每个 authority 一个计数器，首次创建投票活动时自动初始化
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + PollCounter::INIT_SPACE,
+        seeds = [b"poll_counter", signer.key().as_ref()],
+        bump
+    )]
+    pub poll_counter: Account<'info, PollCounter>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddCandidate<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + CandidateAccount::INIT_SPACE,
+        // seeds 现在使用更可靠的计数器
+        seeds = [b"candidate", poll_account.key().as_ref(), poll_account.candidate_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub candidate_account: Account<'info, CandidateAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + Contest::INIT_SPACE,
+        seeds = [b"contest", poll_account.key().as_ref(), poll_account.contest_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub contest: Account<'info, Contest>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddContestCandidate<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(
+        mut,
+        constraint = contest.poll == poll_account.key() @ ErrorCode::InvalidContestAccounts
+    )]
+    pub contest: Account<'info, Contest>,
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + CandidateAccount::INIT_SPACE,
+        seeds = [b"candidate", contest.key().as_ref(), contest.candidate_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub candidate_account: Account<'info, CandidateAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdatePoll<'info> {
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPollStatus<'info> {
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetAdmin<'info> {
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Vote<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(
+        mut,
+        constraint = candidate_account.poll == poll_account.key() @ ErrorCode::InvalidCandidateForPoll
+    )]
+    pub candidate_account: Account<'info, CandidateAccount>,
+    #[account(
+        init,
+        payer = signer,
+        space = VoterReceipt::space(poll_account.options.ballot_type.max_selections()),
+        seeds = [b"receipt", poll_account.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub voter_receipt: Account<'info, VoterReceipt>,
+    // 设置了 gate_mint 的投票活动需要传入投票者持有该代币的账户
+    pub voter_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    // 设置了 requires_pass 的投票活动需要传入投票者的通行证
+    #[account(
+        mut,
+        seeds = [b"pass", poll_account.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub voter_pass: Option<Account<'info, VoterPass>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(voter: Pubkey)]
+pub struct IssueVoterPass<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + VoterPass::INIT_SPACE,
+        seeds = [b"pass", poll_account.key().as_ref(), voter.as_ref()],
+        bump
+    )]
+    pub voter_pass: Account<'info, VoterPass>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct IssueVoterPasses<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub poll_account: Account<'info, PollAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevokeVoterPass<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(
+        mut,
+        close = signer,
+        seeds = [b"pass", poll_account.key().as_ref(), voter_pass.voter.as_ref()],
+        bump
+    )]
+    pub voter_pass: Account<'info, VoterPass>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(choices: Vec<ContestChoice>)]
+pub struct CastBallot<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(
+        init,
+        payer = signer,
+        space = VoterReceipt::space(ContestChoice::selection_count(&choices)),
+        seeds = [b"receipt", poll_account.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub voter_receipt: Account<'info, VoterReceipt>,
+    // 设置了 gate_mint 的投票活动需要传入投票者持有该代币的账户
+    pub voter_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    // 设置了 requires_pass 的投票活动需要传入投票者的通行证
+    #[account(
+        mut,
+        seeds = [b"pass", poll_account.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub voter_pass: Option<Account<'info, VoterPass>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VoteWeighted<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(
+        mut,
+        constraint = candidate_account.poll == poll_account.key() @ ErrorCode::InvalidCandidateForPoll
+    )]
+    pub candidate_account: Account<'info, CandidateAccount>,
+    #[account(
+        init,
+        payer = signer,
+        space = VoterReceipt::space(1),
+        seeds = [b"receipt", poll_account.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub voter_receipt: Account<'info, VoterReceipt>,
+    pub weight_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        constraint = voter_token_account.mint == weight_mint.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = voter_token_account.owner == signer.key() @ ErrorCode::InvalidTokenAccount
+    )]
+    pub voter_token_account: InterfaceAccount<'info, TokenAccount>,
+    // 每个加权投票活动一个金库，由自身 PDA 签名转出
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [b"vault", poll_account.key().as_ref()],
+        bump,
+        token::mint = weight_mint,
+        token::authority = vault,
+        token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VoteWithNft<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(
+        mut,
+        constraint = candidate_account.poll == poll_account.key() @ ErrorCode::InvalidCandidateForPoll
+    )]
+    pub candidate_account: Account<'info, CandidateAccount>,
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+    // 投票者必须持有该 NFT
+    #[account(
+        constraint = nft_token_account.mint == nft_mint.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = nft_token_account.owner == signer.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = nft_token_account.amount == 1 @ ErrorCode::InvalidTokenAccount
+    )]
+    pub nft_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: 由 seeds 和 owner 约束校验为该 NFT 的元数据账户，在指令中解析
+    #[account(
+        owner = TOKEN_METADATA_PROGRAM_ID,
+        seeds = [b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), nft_mint.key().as_ref()],
+        seeds::program = TOKEN_METADATA_PROGRAM_ID,
+        bump
+    )]
+    pub nft_metadata: UncheckedAccount<'info>,
+    // 每个 NFT 在每个投票活动中只有一张回执
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + NftReceipt::INIT_SPACE,
+        seeds = [b"nft_receipt", poll_account.key().as_ref(), nft_mint.key().as_ref()],
+        bump
+    )]
+    pub nft_receipt: Account<'info, NftReceipt>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(rankings: Vec<u32>)]
+pub struct VoteRanked<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(
+        init,
+        payer = signer,
+        space = RankedBallot::space(rankings.len()),
+        seeds = [b"ballot", poll_account.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub ranked_ballot: Account<'info, RankedBallot>,
+    // 设置了 gate_mint 的投票活动需要传入投票者持有该代币的账户
+    pub voter_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VoteQuadratic<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(
+        mut,
+        constraint = candidate_account.poll == poll_account.key() @ ErrorCode::InvalidCandidateForPoll
+    )]
+    pub candidate_account: Account<'info, CandidateAccount>,
+    // 回执是投票者的额度账本，多次投票共用
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = VoterReceipt::quadratic_space(0),
+        seeds = [b"receipt", poll_account.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub voter_receipt: Account<'info, VoterReceipt>,
+    // 设置了 gate_mint 的投票活动需要传入投票者持有该代币的账户
+    pub voter_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    // 设置了 requires_pass 的投票活动需要传入投票者的通行证
+    #[account(
+        mut,
+        seeds = [b"pass", poll_account.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub voter_pass: Option<Account<'info, VoterPass>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CommitVote<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(
+        init,
+        payer = signer,
+        space = VoterReceipt::space(1),
+        seeds = [b"receipt", poll_account.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub voter_receipt: Account<'info, VoterReceipt>,
+    // 设置了 gate_mint 的投票活动需要传入投票者持有该代币的账户
+    pub voter_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    // 设置了 requires_pass 的投票活动需要传入投票者的通行证
+    #[account(
+        mut,
+        seeds = [b"pass", poll_account.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub voter_pass: Option<Account<'info, VoterPass>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevealVote<'info> {
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(
+        mut,
+        constraint = candidate_account.poll == poll_account.key() @ ErrorCode::InvalidCandidateForPoll
+    )]
+    pub candidate_account: Account<'info, CandidateAccount>,
+    #[account(
+        mut,
+        seeds = [b"receipt", poll_account.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub voter_receipt: Account<'info, VoterReceipt>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawTokens<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(
+        mut,
+        close = signer,
+        seeds = [b"receipt", poll_account.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub voter_receipt: Account<'info, VoterReceipt>,
+    #[account(
+        constraint = poll_account.options.weight_mint == Some(weight_mint.key()) @ ErrorCode::PollNotWeighted
+    )]
+    pub weight_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        constraint = voter_token_account.mint == weight_mint.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = voter_token_account.owner == signer.key() @ ErrorCode::InvalidTokenAccount
+    )]
+    pub voter_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"vault", poll_account.key().as_ref()],
+        bump
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ChangeVote<'info> {
+    pub signer: Signer<'info>,
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(
+        mut,
+        constraint = previous_candidate.poll == poll_account.key() @ ErrorCode::InvalidCandidateForPoll
+    )]
+    pub previous_candidate: Account<'info, CandidateAccount>,
+    #[account(
+        mut,
+        constraint = candidate_account.poll == poll_account.key() @ ErrorCode::InvalidCandidateForPoll
+    )]
+    pub candidate_account: Account<'info, CandidateAccount>,
+    // 回执由 signer 派生，保证只能修改自己的投票
+    #[account(
+        mut,
+        seeds = [b"receipt", poll_account.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub voter_receipt: Account<'info, VoterReceipt>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RetractVote<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+    // 必须是回执中记录的候选人
+    #[account(
+        mut,
+        address = voter_receipt.candidate @ ErrorCode::InvalidCandidateForPoll
+    )]
+    pub candidate_account: Account<'info, CandidateAccount>,
+    // 回执由 signer 派生，撤回后关闭并把租金退还给投票者
+    #[account(
+        mut,
+        close = signer,
+        seeds = [b"receipt", poll_account.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub voter_receipt: Account<'info, VoterReceipt>,
+}
+
+#[derive(Accounts)]
+pub struct CloseCandidates<'info> {
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+    /// CHECK: 只用于接收回收的租金，可以是任意账户
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClosePoll<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(mut, close = recipient)]
+    pub poll_account: Account<'info, PollAccount>,
+    // 开始结算后必须一并关闭结果账户
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [b"result", poll_account.key().as_ref()],
+        bump
+    )]
+    pub poll_result: Option<Account<'info, PollResult>>,
+    /// CHECK: 只用于接收回收的租金，可以是任意账户
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+    // 加权投票活动的金库，代币全部取回后一并关闭
+    #[account(
+        mut,
+        seeds = [b"vault", poll_account.key().as_ref()],
+        bump
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    // 加权投票的代币 mint，用于转出金库中的零散代币和收集扣留的转账手续费
+    #[account(
+        mut,
+        constraint = poll_account.options.weight_mint == Some(weight_mint.key()) @ ErrorCode::PollNotWeighted
+    )]
+    pub weight_mint: Option<InterfaceAccount<'info, Mint>>,
+    // 接收金库中零散代币的代币账户，金库中只有托管的代币时可以不传
+    #[account(
+        mut,
+        constraint = Some(recipient_token_account.mint) == poll_account.options.weight_mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: 排序投票的开票结果账户，未执行过 tally_irv 时不存在，在指令中根据所有者判断
+    #[account(
+        mut,
+        seeds = [b"irv", poll_account.key().as_ref()],
+        bump
+    )]
+    pub irv_result: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseReceipt<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    /// CHECK: 投票活动可能已被 close_poll 关闭，在指令中根据所有者判断
+    pub poll_account: UncheckedAccount<'info>,
+    /// CHECK: 由 seeds 和 owner 约束校验；兼容旧版布局的回执
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [b"receipt", poll_account.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub voter_receipt: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseNftReceipt<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    /// CHECK: 投票活动可能已被 close_poll 关闭，在指令中根据所有者判断
+    pub poll_account: UncheckedAccount<'info>,
+    // 租金退还给回执中记录的投票者，NFT 转手后也不会退给新的持有人
+    #[account(
+        mut,
+        close = signer,
+        constraint = nft_receipt.voter == signer.key() @ ErrorCode::Unauthorized,
+        seeds = [b"nft_receipt", poll_account.key().as_ref(), nft_receipt.nft_mint.as_ref()],
+        bump
+    )]
+    pub nft_receipt: Account<'info, NftReceipt>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FinalizePoll<'info> {
+    // 结算无需权限，候选人账户通过 remaining_accounts 传入；payer 支付结果账户的租金
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PollResult::space(0, 0),
+        seeds = [b"result", poll_account.key().as_ref()],
+        bump
+    )]
+    pub poll_result: Account<'info, PollResult>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseBallot<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    /// CHECK: 投票活动可能已被 close_poll 关闭，在指令中根据所有者判断
+    pub poll_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = signer,
+        seeds = [b"ballot", poll_account.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub ranked_ballot: Account<'info, RankedBallot>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TallyIrv<'info> {
+    // 开票无需权限，选票通过 remaining_accounts 传入；payer 支付开票结果账户的租金
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = IrvResult::space(poll_account.candidate_count as usize),
+        seeds = [b"irv", poll_account.key().as_ref()],
+        bump
+    )]
+    pub irv_result: Account<'info, IrvResult>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigratePoll<'info> {
+    // 任何人都可以发起迁移并支付扩容租金，票数由链上候选人账户重新统计
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    /// CHECK: 旧版布局无法按当前 PollAccount 反序列化，在指令中手动校验
+    #[account(mut, owner = crate::ID)]
+    pub poll_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PollAccount {
+    pub authority: Pubkey,
+    #[max_len(32)]
+    pub name: String,
+    #[max_len(280)]
+    pub description: String,
     pub start_time: u64,
     pub end_time: u64,
-    // 增加专门的计数器
-    pub candidate_count: u8,
-    #[max_len(15, 32)]
-    pub candidates: Vec<Pubkey>,
+    // 候选人数量，也是下一个候选人 PDA 的索引
+    pub candidate_count: u32,
+    // 迁移自旧版布局时已有的候选人数量，这些候选人的 PDA 使用 u8 索引
+    pub legacy_candidate_count: u8,
+    // 所有候选人票数之和
+    pub total_votes: u64,
+    // 创建时指定的平票规则和可选功能
+    pub options: PollOptions,
+    // 进行中、已结算、已暂停或已取消
+    pub status: PollStatus,
+    // 已被 finalize_poll 统计的候选人数量
+    pub tallied_count: u32,
+    // 已被关闭的候选人账户数量
+    pub closed_candidate_count: u32,
+    // 加权投票托管在金库中、尚未取回的代币数量；金库中超出的部分是他人转入的零散代币
+    pub escrowed_amount: u64,
+    // 秘密投票已提交的承诺数量和已揭示的数量，两者之差为未揭示的承诺
+    pub commit_count: u64,
+    pub reveal_count: u64,
+    // 分项数量（也是下一个分项 PDA 的索引），以及所有分项的候选人数量之和
+    pub contest_count: u32,
+    pub contest_candidate_count: u32,
+    // propose_authority 提名、尚未接受的新 authority
+    pub pending_authority: Option<Pubkey>,
+    // 除 authority 之外的管理员及其角色
+    #[max_len(MAX_POLL_ADMINS)]
+    pub admins: Vec<PollAdmin>,
+}
+
+// 结算结果，大小随候选人数量增长，seeds = [b"result", poll]
+#[account]
+pub struct PollResult {
+    pub poll: Pubkey,
+    // 按票数降序排列的候选人票数，结算完成前只包含已统计的部分
+    pub ranked_tallies: Vec<CandidateTally>,
+    // 获胜者的候选人索引，TieBreak::Shared 时可能有多个
+    pub winners: Vec<u32>,
+}
+
+impl PollResult {
+    pub const fn space(tally_count: usize, winner_count: usize) -> usize {
+        8 + 32 + 4 + tally_count * CandidateTally::INIT_SPACE + 4 + winner_count * 4
+    }
+}
+
+// authority 已创建的投票活动数量，也是下一个投票活动的 poll_id
+#[account]
+#[derive(InitSpace)]
+pub struct PollCounter {
+    pub authority: Pubkey,
+    pub poll_count: u64,
 }
 
 #[account]
@@ -162,11 +3295,454 @@ pub struct CandidateAccount {
     pub votes: u64,
 }
 
+// 投票活动下的分项：`[b"contest", poll, contest_index]`，候选人为 `[b"candidate", contest, candidate_index]`
+#[account]
+#[derive(InitSpace)]
+pub struct Contest {
+    pub poll: Pubkey,
+    pub index: u32,
+    #[max_len(32)]
+    pub name: String,
+    // 每张选票在该分项中最多可选的候选人数量
+    pub max_selections: u8,
+    pub candidate_count: u32,
+    // 该分项所有候选人票数之和
+    pub total_votes: u64,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct VoterReceipt {
     pub voter: Pubkey,
     pub poll: Pubkey,
+    // 所投的候选人账户
+    pub candidate: Pubkey,
+    pub voted_at: i64,
+    pub slot: u64,
+    // 计入的票数：普通投票为 1，加权投票为托管的代币数量
+    pub weight: u64,
+    // 所选的全部候选人账户，单选时只有 candidate 一个
+    #[max_len(0)]
+    pub selections: Vec<Pubkey>,
+    // 二次方投票在 selections 中每个候选人上累计的票数，以及已花费的额度
+    #[max_len(0)]
+    pub allocations: Vec<u64>,
+    pub credits_spent: u64,
+    // 秘密投票提交的承诺，揭示后 candidate 才会被填写
+    pub commitment: Option<[u8; 32]>,
+    pub revealed: bool,
+}
+
+impl VoterReceipt {
+    // 回执大小随最多可选的候选人数量增长
+    pub const fn space(max_selections: usize) -> usize {
+        8 + VoterReceipt::INIT_SPACE + 32 * max_selections
+    }
+
+    // 二次方投票的回执为每个候选人额外记录票数
+    pub const fn quadratic_space(selection_count: usize) -> usize {
+        8 + VoterReceipt::INIT_SPACE + (32 + 8) * selection_count
+    }
+}
+
+// authority 签发的投票通行证：`[b"pass", poll, voter]`
+#[account]
+#[derive(InitSpace)]
+pub struct VoterPass {
+    pub poll: Pubkey,
+    pub voter: Pubkey,
+    pub weight: u64,
+    // 投票后不能再撤销
+    pub used: bool,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct NftReceipt {
+    pub poll: Pubkey,
+    pub nft_mint: Pubkey,
+    // 投票时持有该 NFT 的钱包
+    pub voter: Pubkey,
+    pub candidate: Pubkey,
+    pub voted_at: i64,
+    pub slot: u64,
+}
+
+// 排序投票的选票：`[b"ballot", poll, voter]`
+#[account]
+#[derive(InitSpace)]
+pub struct RankedBallot {
+    pub poll: Pubkey,
+    pub voter: Pubkey,
+    // 按偏好从高到低排列的候选人索引
+    #[max_len(0)]
+    pub rankings: Vec<u32>,
+    // 已被 tally_irv 统计过的轮数
+    pub counted_rounds: u32,
+    pub cast_at: i64,
+    pub slot: u64,
+}
+
+impl RankedBallot {
+    pub const fn space(ranking_count: usize) -> usize {
+        8 + RankedBallot::INIT_SPACE + 4 * ranking_count
+    }
+}
+
+// 排序投票的开票结果，seeds = [b"irv", poll]
+#[account]
+#[derive(InitSpace)]
+pub struct IrvResult {
+    pub poll: Pubkey,
+    // 当前轮次，从 0 开始
+    pub round: u32,
+    // 本轮已统计的选票数量
+    pub counted_ballots: u64,
+    // 本轮排名中的候选人已全部被淘汰的选票数量
+    pub exhausted_ballots: u64,
+    // 本轮各候选人（按索引）的票数，开票完成后保留最后一轮的票数
+    #[max_len(0)]
+    pub tallies: Vec<u64>,
+    // 按轮次顺序被淘汰的候选人索引
+    #[max_len(0)]
+    pub eliminated: Vec<u32>,
+    // 获胜者的候选人索引，所有选票耗尽时为空
+    pub winner: Option<u32>,
+    pub complete: bool,
+}
+
+impl IrvResult {
+    // 每个候选人占用一个票数，最多被淘汰一次
+    pub const fn space(candidate_count: usize) -> usize {
+        8 + IrvResult::INIT_SPACE + (8 + 4) * candidate_count
+    }
+}
+
+// 投票活动的状态。Active 和 Finalized 的编码与旧版的 finalized: bool 相同，已有账户无需迁移
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum PollStatus {
+    Active,
+    Finalized,
+    Paused,
+    Cancelled,
+}
+
+// 管理员角色：Owner 拥有全部权限并管理管理员列表，CandidateManager 添加候选人和分项，
+// ScheduleManager 修改投票活动的名称、描述和起止时间
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum AdminRole {
+    Owner,
+    CandidateManager,
+    ScheduleManager,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct PollAdmin {
+    pub admin: Pubkey,
+    pub role: AdminRole,
+}
+
+// 结算时的平票处理规则
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, InitSpace)]
+pub enum TieBreak {
+    // 所有并列第一的候选人共同获胜
+    #[default]
+    Shared,
+    // 并列时最先添加的候选人获胜
+    LowestIndex,
+}
+
+// 选票类型：单选，最多批准 max_selections 个候选人的赞成投票，最多排序 max_rankings 个候选人的排序投票，
+// 或每个投票者有 credits_per_voter 点额度的二次方投票
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, InitSpace)]
+pub enum BallotType {
+    #[default]
+    Single,
+    Approval {
+        max_selections: u8,
+    },
+    Ranked {
+        max_rankings: u8,
+    },
+    Quadratic {
+        credits_per_voter: u64,
+    },
+}
+
+impl BallotType {
+    // vote 每张选票最多涉及的候选人数量；排序投票和二次方投票不使用 vote
+    pub fn max_selections(&self) -> usize {
+        match self {
+            BallotType::Approval { max_selections } => *max_selections as usize,
+            BallotType::Single | BallotType::Ranked { .. } | BallotType::Quadratic { .. } => 1,
+        }
+    }
+
+    pub fn is_ranked(&self) -> bool {
+        matches!(self, BallotType::Ranked { .. })
+    }
+}
+
+// 投票活动创建时的选项，默认并列者共同获胜且可选功能全部关闭
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct PollOptions {
+    pub tie_break: TieBreak,
+    // 是否允许投票者在投票期间改票
+    pub allow_vote_change: bool,
+    // 是否允许投票者在投票期间撤回投票
+    pub allow_vote_retraction: bool,
+    // 设置后只有持有至少 min_balance 个该代币的用户才能投票
+    pub gate_mint: Option<Pubkey>,
+    pub min_balance: u64,
+    // 设置后为加权投票：票数等于投票者托管的该代币数量
+    pub weight_mint: Option<Pubkey>,
+    // 设置后每个属于该已验证集合的 NFT 投一票，需使用 vote_with_nft
+    pub collection_mint: Option<Pubkey>,
+    // 设置后只有白名单中的投票者才能投票，值为 (投票者, 票数) 叶子构成的 merkle 根
+    pub allowlist_root: Option<[u8; 32]>,
+    // 设置后只有持有 authority 签发的投票通行证的投票者才能投票
+    pub requires_pass: bool,
+    // 选票类型，默认单选
+    pub ballot_type: BallotType,
+    // 设置后为秘密投票：投票期间只提交承诺，end_time 之后的 reveal_duration 秒内揭示并计票
+    pub reveal_duration: Option<u64>,
+}
+
+// 批量签发时的一张投票通行证
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VoterPassEntry {
+    pub voter: Pubkey,
+    pub weight: Option<u64>,
+}
+
+// 选票中一个分项的选择，candidate_indices 为分项内的候选人索引
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ContestChoice {
+    pub contest_index: u32,
+    pub candidate_indices: Vec<u32>,
+}
+
+impl ContestChoice {
+    // 所有分项中所选的候选人总数
+    pub fn selection_count(choices: &[ContestChoice]) -> usize {
+        choices
+            .iter()
+            .map(|choice| choice.candidate_indices.len())
+            .sum()
+    }
+
+    // cast_ballot 需要的 remaining_accounts 数量：每个分项账户加上所选的候选人账户
+    pub fn account_count(choices: &[ContestChoice]) -> usize {
+        choices.len() + ContestChoice::selection_count(choices)
+    }
+}
+
+// 投票者在白名单中的票数及 merkle 证明（从叶子到根的兄弟节点）
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AllowlistProof {
+    pub weight: u64,
+    pub proof: Vec<[u8; 32]>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct CandidateTally {
+    pub candidate_index: u32,
+    pub votes: u64,
+}
+
+#[event]
+pub struct PollCreated {
+    pub poll: Pubkey,
+    pub authority: Pubkey,
+    pub name: String,
+    pub description: String,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CandidateAdded {
+    pub poll: Pubkey,
+    pub candidate: Pubkey,
+    pub candidate_index: u32,
+    pub name: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContestAdded {
+    pub poll: Pubkey,
+    pub contest: Pubkey,
+    pub contest_index: u32,
+    pub name: String,
+    pub max_selections: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContestCandidateAdded {
+    pub poll: Pubkey,
+    pub contest: Pubkey,
+    pub candidate: Pubkey,
+    pub candidate_index: u32,
+    pub name: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityProposed {
+    pub poll: Pubkey,
+    pub authority: Pubkey,
+    // 为空表示取消了之前的提名
+    pub pending_authority: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub poll: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminUpdated {
+    pub poll: Pubkey,
+    pub admin: Pubkey,
+    // 为空表示该管理员已被移除
+    pub role: Option<AdminRole>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PollStatusChanged {
+    pub poll: Pubkey,
+    pub status: PollStatus,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PollUpdated {
+    pub poll: Pubkey,
+    pub name: String,
+    pub description: String,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteCast {
+    pub poll: Pubkey,
+    pub candidate: Pubkey,
+    pub candidate_index: u32,
+    pub voter: Pubkey,
+    // 本次投票后候选人的最新票数
+    pub votes: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ApprovalVoteCast {
+    pub poll: Pubkey,
+    pub voter: Pubkey,
+    pub candidates: Vec<Pubkey>,
+    pub weight: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BallotCast {
+    pub poll: Pubkey,
+    pub voter: Pubkey,
+    // 选票涉及的分项索引，以及所有分项中所选的候选人账户
+    pub contests: Vec<u32>,
+    pub candidates: Vec<Pubkey>,
+    pub weight: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RankedVoteCast {
+    pub poll: Pubkey,
+    pub voter: Pubkey,
+    pub rankings: Vec<u32>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteCommitted {
+    pub poll: Pubkey,
+    pub voter: Pubkey,
+    pub commitment: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteChanged {
+    pub poll: Pubkey,
+    pub previous_candidate: Pubkey,
+    pub candidate: Pubkey,
+    pub candidate_index: u32,
+    pub voter: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteRetracted {
+    pub poll: Pubkey,
+    pub candidate: Pubkey,
+    pub voter: Pubkey,
+    // 撤回后候选人的最新票数
+    pub votes: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoterPassIssued {
+    pub poll: Pubkey,
+    pub voter: Pubkey,
+    pub weight: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoterPassRevoked {
+    pub poll: Pubkey,
+    pub voter: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokensWithdrawn {
+    pub poll: Pubkey,
+    pub voter: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PollFinalized {
+    pub poll: Pubkey,
+    pub winners: Vec<u32>,
+    pub total_votes: u64,
+    // 秘密投票中直到揭示期结束仍未揭示的承诺数量
+    pub unrevealed_commits: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IrvRoundCompleted {
+    pub poll: Pubkey,
+    pub round: u32,
+    pub tallies: Vec<u64>,
+    pub exhausted_ballots: u64,
+    // 本轮被淘汰的候选人，开票完成的最后一轮为空
+    pub eliminated: Option<u32>,
+    pub winner: Option<u32>,
+    pub timestamp: i64,
 }
 
 #[error_code]
@@ -181,4 +3757,136 @@ pub enum ErrorCode {
     MaxCandidatesReached,
     #[msg("This candidate is not valid for this poll.")]
     InvalidCandidateForPoll,
+    #[msg("Poll has not ended yet")]
+    PollNotEnded,
+    #[msg("Candidate accounts do not match the poll's candidates.")]
+    InvalidCandidateAccounts,
+    #[msg("Arithmetic overflow.")]
+    Overflow,
+    #[msg("Poll account uses a legacy layout, run migrate_poll first.")]
+    PollMigrationRequired,
+    #[msg("Poll account is already migrated.")]
+    PollAlreadyMigrated,
+    #[msg("Poll is already finalized.")]
+    PollAlreadyFinalized,
+    #[msg("Poll is finalized and no longer accepts votes.")]
+    PollFinalized,
+    #[msg("Poll has already started, only the end time can be extended.")]
+    PollAlreadyStarted,
+    #[msg("New end time must be later than the current end time.")]
+    EndTimeNotExtended,
+    #[msg("Start time must be before end time, and end time must be in the future.")]
+    InvalidSchedule,
+    #[msg("Name exceeds 32 bytes.")]
+    NameTooLong,
+    #[msg("Description exceeds 280 bytes.")]
+    DescriptionTooLong,
+    #[msg("Name must not be empty.")]
+    EmptyName,
+    #[msg("Poll id must equal the authority's current poll count.")]
+    InvalidPollId,
+    #[msg("Close all candidate accounts before closing the poll.")]
+    CandidatesNotClosed,
+    #[msg("Poll result account must be closed together with the poll.")]
+    PollResultRequired,
+    #[msg("Changing votes is not allowed for this poll.")]
+    VoteChangeDisabled,
+    #[msg("The new candidate is the same as the current one.")]
+    SameCandidate,
+    #[msg("Retracting votes is not allowed for this poll.")]
+    VoteRetractionDisabled,
+    #[msg("Voter does not hold enough of the poll's gate token.")]
+    InsufficientTokenBalance,
+    #[msg("Token account is not the voter's account for the required mint.")]
+    InvalidTokenAccount,
+    #[msg("Weighted polls only accept vote_weighted.")]
+    WeightedVoteRequired,
+    #[msg("Poll is not a weighted poll.")]
+    PollNotWeighted,
+    #[msg("Poll options are incompatible with each other.")]
+    InvalidPollOptions,
+    #[msg("Escrowed tokens must be withdrawn first.")]
+    TokensNotWithdrawn,
+    #[msg("Collection polls only accept vote_with_nft.")]
+    NftVoteRequired,
+    #[msg("Poll is not gated by an NFT collection.")]
+    PollNotCollectionGated,
+    #[msg("Account is not a valid NFT or NFT metadata.")]
+    InvalidNft,
+    #[msg("NFT is not a verified member of the poll's collection.")]
+    NftNotInCollection,
+    #[msg("Voter is not on the poll's allowlist.")]
+    NotOnAllowlist,
+    #[msg("Poll requires a voter pass.")]
+    VoterPassRequired,
+    #[msg("Voter pass has already been used and cannot be revoked before the poll ends.")]
+    VoterPassUsed,
+    #[msg("Voter pass weight must be greater than zero.")]
+    InvalidPassWeight,
+    #[msg("Voter pass accounts do not match the passes being issued.")]
+    InvalidVoterPassAccounts,
+    #[msg("Too many candidates selected for this ballot.")]
+    TooManySelections,
+    #[msg("The same candidate was selected more than once.")]
+    DuplicateSelection,
+    #[msg("Ranked polls only accept vote_ranked.")]
+    RankedVoteRequired,
+    #[msg("Poll does not use ranked ballots.")]
+    PollNotRanked,
+    #[msg("Rankings must list at least one of the poll's candidates.")]
+    InvalidRankings,
+    #[msg("Ranked polls must be tallied with tally_irv first.")]
+    IrvTallyRequired,
+    #[msg("Ballot has already been counted in this round.")]
+    BallotAlreadyCounted,
+    #[msg("Ballot does not belong to this poll.")]
+    InvalidBallot,
+    #[msg("IRV result account must be closed together with the poll.")]
+    IrvResultRequired,
+    #[msg("Quadratic polls only accept vote_quadratic.")]
+    QuadraticVoteRequired,
+    #[msg("Poll is not a quadratic poll.")]
+    PollNotQuadratic,
+    #[msg("Vote count must be greater than zero.")]
+    InvalidVoteCount,
+    #[msg("Not enough voting credits left.")]
+    InsufficientCredits,
+    #[msg("Commit-reveal polls only accept commit_vote.")]
+    CommitRevealRequired,
+    #[msg("Poll does not use commit-reveal voting.")]
+    PollNotCommitReveal,
+    #[msg("Reveal window opens after the poll ends.")]
+    RevealWindowNotOpen,
+    #[msg("Reveal window has closed.")]
+    RevealWindowClosed,
+    #[msg("Reveal window is still open.")]
+    RevealWindowOpen,
+    #[msg("Vote has already been revealed.")]
+    VoteAlreadyRevealed,
+    #[msg("Candidate index and salt do not match the commitment.")]
+    CommitmentMismatch,
+    #[msg("Polls with contests only accept cast_ballot.")]
+    ContestBallotRequired,
+    #[msg("Poll has no contests.")]
+    PollHasNoContests,
+    #[msg("Contest choices must be sorted, unique and non-empty.")]
+    InvalidContestChoices,
+    #[msg("Contest or candidate accounts do not match the ballot.")]
+    InvalidContestAccounts,
+    #[msg("Polls with contests are counted per contest and cannot be finalized.")]
+    ContestTallyUnsupported,
+    #[msg("Signer is not the pending authority.")]
+    NotPendingAuthority,
+    #[msg("Signer does not have the admin role required for this instruction.")]
+    MissingAdminRole,
+    #[msg("Poll already has the maximum number of admins.")]
+    TooManyAdmins,
+    #[msg("Poll is paused and does not accept votes.")]
+    PollPaused,
+    #[msg("Poll has been cancelled.")]
+    PollCancelled,
+    #[msg("Poll is not paused.")]
+    PollNotPaused,
+    #[msg("Poll tally has already started.")]
+    TallyInProgress,
 }
@@ -8,7 +8,9 @@ use solana_sdk::{
 use std::convert::TryFrom;
 use std::{env, fs, str::FromStr};
 
-use voting_client::{accounts::PollAccount, instructions::AddCandidateBuilder};
+use voting_client::{
+    accounts::PollAccount, instructions::AddCandidateBuilder, pdas::find_poll_candidate_pda,
+};
 
 /// 从文件加载钱包 Keypair
 fn load_wallet(path: &str) -> Result<Keypair> {
@@ -54,16 +56,18 @@ async fn main() -> Result<()> {
     println!("✅ Current candidate count is: {}", current_candidate_count);
 
     // 3. 计算新候选人账户的 PDA
-    let (candidate_pda, _) = Pubkey::find_program_address(
-        &[
-            b"candidate",
-            &poll_account_pubkey.to_bytes(),
-            &[current_candidate_count], // count 是 u8
-        ],
-        &voting_client::programs::VOTING_ID,
+    // 新候选人的索引就是当前的 candidate_count（u32，小端序）
+    let (candidate_pda, _) = find_poll_candidate_pda(
+        &poll_account_pubkey,
+        poll_account_data.legacy_candidate_count,
+        current_candidate_count,
     );
     println!("🌱 New Candidate PDA: {}", candidate_pda);
 
+    // emit_cpi! 事件需要程序的 event authority PDA
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &voting_client::programs::VOTING_ID);
+
     // 4. 使用 Builder 构造指令
     let candidate_name = format!("Candidate #{}", current_candidate_count + 1);
     println!("➕ Adding candidate with name: \"{}\"", candidate_name);
@@ -71,6 +75,8 @@ async fn main() -> Result<()> {
         .signer(signer.pubkey())
         .poll_account(poll_account_pubkey)
         .candidate_account(candidate_pda)
+        .event_authority(event_authority)
+        .program(voting_client::programs::VOTING_ID)
         .candidate_name(candidate_name)
         .instruction();
 
@@ -0,0 +1,104 @@
+use anyhow::Result;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use std::convert::TryFrom;
+use std::{env, fs, str::FromStr};
+
+use voting_client::{
+    accounts::PollAccount,
+    instructions::{AddContestBuilder, AddContestCandidateBuilder},
+    pdas::{find_contest_candidate_pda, find_contest_pda},
+    programs::VOTING_ID,
+};
+
+/// 从文件加载钱包 Keypair
+fn load_wallet(path: &str) -> Result<Keypair> {
+    let content = fs::read_to_string(path)?;
+    let bytes: Vec<u8> = serde_json::from_str(&content)?;
+    Ok(Keypair::try_from(bytes.as_slice())?)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    println!("--- 🚀 Starting [Add Contest] Rust Client ---");
+
+    dotenvy::from_path("../.env").ok();
+
+    let rpc_url =
+        env::var("RPC_URL").unwrap_or_else(|_| "https://api.devnet.solana.com".to_string());
+
+    let wallet_path = env::var("WALLET_PATH").unwrap_or_else(|_| {
+        let home = env::var("HOME").expect("HOME environment variable is not set");
+        format!("{}/.config/solana/id.json", home)
+    });
+
+    // !! 重要：请将这里的地址替换为尚未开始的 Poll Account 地址 !!
+    let poll_account_pubkey = Pubkey::from_str("8ucTtmiD8Jw4gCARkfWkVP1fQmfkTnXixEn5enfqCJUy")?;
+    // 分项名称、最多可选的候选人数量及其候选人
+    let contest_name = "Chair".to_string();
+    let max_selections: u8 = 1;
+    let candidate_names = ["Alice", "Bob"];
+
+    let client = RpcClient::new(rpc_url);
+    let signer = load_wallet(&wallet_path)?;
+
+    println!("🔑 Signer (Authority): {}", signer.pubkey());
+    println!("📝 Using Poll Account: {}", poll_account_pubkey);
+
+    // 1. 新分项的索引就是当前的 contest_count
+    let poll_account_info = client.get_account(&poll_account_pubkey)?;
+    let poll_account_data = PollAccount::from_bytes(&poll_account_info.data)?;
+    let contest_index = poll_account_data.contest_count;
+    let (contest_pda, _) = find_contest_pda(&poll_account_pubkey, contest_index);
+    println!("🗳️  New Contest #{}: {}", contest_index, contest_pda);
+
+    // emit_cpi! 事件需要程序的 event authority PDA
+    let (event_authority, _) = Pubkey::find_program_address(&[b"__event_authority"], &VOTING_ID);
+
+    // 2. 在同一笔交易中创建分项并依次添加候选人，候选人索引从 0 开始
+    let mut instructions = vec![AddContestBuilder::new()
+        .signer(signer.pubkey())
+        .poll_account(poll_account_pubkey)
+        .contest(contest_pda)
+        .event_authority(event_authority)
+        .program(VOTING_ID)
+        .name(contest_name)
+        .max_selections(max_selections)
+        .instruction()];
+    for (candidate_index, candidate_name) in candidate_names.iter().enumerate() {
+        let (candidate_pda, _) = find_contest_candidate_pda(&contest_pda, candidate_index as u32);
+        println!("➕ Candidate #{}: {}", candidate_index, candidate_pda);
+        instructions.push(
+            AddContestCandidateBuilder::new()
+                .signer(signer.pubkey())
+                .poll_account(poll_account_pubkey)
+                .contest(contest_pda)
+                .candidate_account(candidate_pda)
+                .event_authority(event_authority)
+                .program(VOTING_ID)
+                .candidate_name(candidate_name.to_string())
+                .instruction(),
+        );
+    }
+
+    // 3. 发送交易
+    let recent_blockhash = client.get_latest_blockhash()?;
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signer.pubkey()),
+        &[&signer],
+        recent_blockhash,
+    );
+
+    println!("\n⏳ Sending transaction...");
+    let signature = client.send_and_confirm_transaction(&transaction)?;
+
+    println!("\n✅ Success! Contest has been added.");
+    println!("   - Transaction Signature: {}", signature);
+
+    Ok(())
+}
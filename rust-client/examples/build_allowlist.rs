@@ -0,0 +1,53 @@
+use anyhow::{Context, Result};
+use serde_json::{json, Map};
+use std::{env, fs};
+
+use voting_client::allowlist::{parse_allowlist_csv, AllowlistTree};
+
+/// 32 字节哈希转为十六进制字符串
+fn to_hex(bytes: &[u8; 32]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn main() -> Result<()> {
+    println!("--- 🚀 Starting [Build Allowlist] Tool ---");
+
+    // 用法：cargo run --example build_allowlist -- <allowlist.csv> [allowlist.json]
+    // CSV 每行为 `voter[,weight]`，省略 weight 时票数为 1
+    let mut args = env::args().skip(1);
+    let csv_path = args.next().unwrap_or_else(|| "allowlist.csv".to_string());
+    let output_path = args.next().unwrap_or_else(|| "allowlist.json".to_string());
+
+    // 1. 读取 CSV 并构建 merkle 树
+    let csv = fs::read_to_string(&csv_path).with_context(|| format!("reading {}", csv_path))?;
+    let tree = AllowlistTree::new(parse_allowlist_csv(&csv)?)?;
+    println!("🗂️  Voters: {}", tree.entries().len());
+    println!("🌳 Merkle Root: {}", to_hex(&tree.root()));
+
+    // 2. 为每个投票者生成证明
+    let mut proofs = Map::new();
+    for entry in tree.entries() {
+        let proof = tree
+            .proof(&entry.voter)
+            .expect("every entry is in the tree");
+        proofs.insert(
+            entry.voter.to_string(),
+            json!({
+                "weight": proof.weight,
+                "proof": proof.proof.iter().map(to_hex).collect::<Vec<_>>(),
+            }),
+        );
+    }
+
+    // 3. 输出 JSON：root 用于 initialize_poll 的 allowlist_root，proofs 分发给各投票者
+    let output = json!({
+        "root": to_hex(&tree.root()),
+        "proofs": proofs,
+    });
+    fs::write(&output_path, serde_json::to_string_pretty(&output)?)?;
+
+    println!("\n✅ Success! Proofs written to {}", output_path);
+    println!("   - Set PollOptions.allowlist_root to the root above when creating the poll.");
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use anyhow::Result;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use std::convert::TryFrom;
+use std::{env, fs, str::FromStr};
+
+use voting_client::{
+    accounts::{Contest, PollAccount},
+    allowlist::{parse_allowlist_csv, AllowlistTree},
+    instructions::CastBallotBuilder,
+    pdas::{find_contest_pda, find_voter_pass_pda},
+    programs::VOTING_ID,
+    types::ContestChoice,
+};
+
+/// 从文件加载钱包 Keypair
+fn load_wallet(path: &str) -> Result<Keypair> {
+    let content = fs::read_to_string(path)?;
+    let bytes: Vec<u8> = serde_json::from_str(&content)?;
+    Ok(Keypair::try_from(bytes.as_slice())?)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    println!("--- 🚀 Starting [Cast Ballot] Rust Client ---");
+
+    dotenvy::from_path("../.env").ok();
+
+    let rpc_url =
+        env::var("RPC_URL").unwrap_or_else(|_| "https://api.devnet.solana.com".to_string());
+
+    let wallet_path = env::var("WALLET_PATH").unwrap_or_else(|_| {
+        let home = env::var("HOME").expect("HOME environment variable is not set");
+        format!("{}/.config/solana/id.json", home)
+    });
+
+    // !! 重要：请将这里的地址替换为包含分项的 Poll Account 地址 !!
+    let poll_account_pubkey = Pubkey::from_str("8ucTtmiD8Jw4gCARkfWkVP1fQmfkTnXixEn5enfqCJUy")?;
+    // 每个分项所选的候选人索引，分项和候选人索引都须升序；未列出的分项视为弃权
+    let choices = vec![
+        ContestChoice {
+            contest_index: 0,
+            candidate_indices: vec![0],
+        },
+        ContestChoice {
+            contest_index: 1,
+            candidate_indices: vec![1],
+        },
+    ];
+
+    let client = RpcClient::new(rpc_url);
+    let voter = load_wallet(&wallet_path)?;
+
+    println!("🔑 Voter: {}", voter.pubkey());
+    println!("📝 Casting ballot in Poll: {}", poll_account_pubkey);
+
+    let poll_account_info = client.get_account(&poll_account_pubkey)?;
+    let poll_account_data = PollAccount::from_bytes(&poll_account_info.data)?;
+
+    let (voter_receipt_pda, _) = Pubkey::find_program_address(
+        &[
+            b"receipt",
+            &poll_account_pubkey.to_bytes(),
+            &voter.pubkey().to_bytes(),
+        ],
+        &VOTING_ID,
+    );
+
+    // emit_cpi! 事件需要程序的 event authority PDA
+    let (event_authority, _) = Pubkey::find_program_address(&[b"__event_authority"], &VOTING_ID);
+
+    // 1. 一张选票覆盖所有分项，共用同一份回执
+    let mut builder = CastBallotBuilder::new();
+    builder
+        .signer(voter.pubkey())
+        .poll_account(poll_account_pubkey)
+        .voter_receipt(voter_receipt_pda)
+        .event_authority(event_authority)
+        .program(VOTING_ID)
+        .contests(&poll_account_pubkey, choices.clone());
+
+    // 设置了白名单的投票活动：用创建时的 CSV 重建 merkle 树并附加证明
+    if let Some(allowlist_root) = poll_account_data.options.allowlist_root {
+        let csv_path = env::var("ALLOWLIST_CSV").unwrap_or_else(|_| "allowlist.csv".to_string());
+        let tree = AllowlistTree::new(parse_allowlist_csv(&fs::read_to_string(csv_path)?)?)?;
+        anyhow::ensure!(
+            tree.root() == allowlist_root,
+            "Allowlist CSV does not match the poll's allowlist root"
+        );
+        builder.allowlist(&tree, &voter.pubkey())?;
+    }
+    // 设置了 requires_pass 的投票活动需要传入 authority 签发的通行证
+    if poll_account_data.options.requires_pass {
+        let (voter_pass, _) = find_voter_pass_pda(&poll_account_pubkey, &voter.pubkey());
+        builder.voter_pass(Some(voter_pass));
+    }
+    let instruction = builder.instruction();
+
+    // 2. 发送交易
+    let recent_blockhash = client.get_latest_blockhash()?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&voter.pubkey()),
+        &[&voter],
+        recent_blockhash,
+    );
+
+    println!("\n⏳ Sending transaction...");
+    let signature = client.send_and_confirm_transaction(&transaction)?;
+
+    println!("\n✅ Success! Your ballot has been cast.");
+    println!("   - Transaction Signature: {}", signature);
+
+    // 3. 读取各分项的最新票数
+    for choice in &choices {
+        let (contest_pda, _) = find_contest_pda(&poll_account_pubkey, choice.contest_index);
+        let contest = Contest::from_bytes(&client.get_account(&contest_pda)?.data)?;
+        println!(
+            "   - Contest \"{}\": {} vote(s) in total",
+            contest.name, contest.total_votes
+        );
+    }
+
+    Ok(())
+}
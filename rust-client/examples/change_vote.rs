@@ -0,0 +1,113 @@
+use anyhow::Result;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use std::convert::TryFrom;
+use std::{env, fs, str::FromStr};
+
+// 导入生成的代码
+use voting_client::{
+    accounts::{PollAccount, VoterReceipt},
+    instructions::ChangeVoteBuilder,
+    pdas::find_poll_candidate_pda,
+};
+
+/// 从文件加载钱包 Keypair
+fn load_wallet(path: &str) -> Result<Keypair> {
+    let content = fs::read_to_string(path)?;
+    let bytes: Vec<u8> = serde_json::from_str(&content)?;
+    Ok(Keypair::try_from(bytes.as_slice())?)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    println!("--- 🚀 Starting [Change Vote] Rust Client ---");
+
+    dotenvy::from_path("../.env").ok();
+
+    let rpc_url =
+        env::var("RPC_URL").unwrap_or_else(|_| "https://api.devnet.solana.com".to_string());
+
+    let wallet_path = env::var("WALLET_PATH").unwrap_or_else(|_| {
+        let home = env::var("HOME").expect("HOME environment variable is not set");
+        format!("{}/.config/solana/id.json", home)
+    });
+
+    // !! 重要：请将这里的地址替换为您已投过票、且允许改票的 Poll Account 地址 !!
+    let poll_account_pubkey = Pubkey::from_str("8ucTtmiD8Jw4gCARkfWkVP1fQmfkTnXixEn5enfqCJUy")?;
+    // 改投的候选人索引（从 0 开始）
+    let candidate_index: u32 = 1;
+
+    let client = RpcClient::new(rpc_url);
+    let voter = load_wallet(&wallet_path)?;
+
+    println!("🔑 Voter: {}", voter.pubkey());
+    println!("📝 Voting in Poll: {}", poll_account_pubkey);
+
+    // 1. 读取投票账户，根据索引推导候选人账户地址
+    let poll_account_info = client.get_account(&poll_account_pubkey)?;
+    let poll_account_data = PollAccount::from_bytes(&poll_account_info.data)?;
+    let (candidate_account_pubkey, _) = find_poll_candidate_pda(
+        &poll_account_pubkey,
+        poll_account_data.legacy_candidate_count,
+        candidate_index,
+    );
+    println!(
+        "👍 Changing vote to Candidate #{}: {}",
+        candidate_index, candidate_account_pubkey
+    );
+
+    // 2. 计算投票回执账户的 PDA，并读取之前所投的候选人
+    let (voter_receipt_pda, _) = Pubkey::find_program_address(
+        &[
+            b"receipt",
+            &poll_account_pubkey.to_bytes(),
+            &voter.pubkey().to_bytes(),
+        ],
+        &voting_client::programs::VOTING_ID,
+    );
+    println!("🧾 Voter Receipt PDA: {}", voter_receipt_pda);
+    let receipt_info = client.get_account(&voter_receipt_pda)?;
+    let previous_candidate = VoterReceipt::from_bytes(&receipt_info.data)?.candidate;
+    println!("↩️  Previous Candidate: {}", previous_candidate);
+
+    // emit_cpi! 事件需要程序的 event authority PDA
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &voting_client::programs::VOTING_ID);
+
+    // 3. 使用 Builder 构造指令
+    let instruction = ChangeVoteBuilder::new()
+        .signer(voter.pubkey())
+        .poll_account(poll_account_pubkey)
+        .previous_candidate(previous_candidate)
+        .candidate_account(candidate_account_pubkey)
+        .voter_receipt(voter_receipt_pda)
+        .event_authority(event_authority)
+        .program(voting_client::programs::VOTING_ID)
+        .candidate_index(candidate_index)
+        .instruction();
+
+    // 4. 发送交易
+    let recent_blockhash = client.get_latest_blockhash()?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&voter.pubkey()),
+        &[&voter], // 只有投票者需要签名
+        recent_blockhash,
+    );
+
+    println!("\n⏳ Sending transaction...");
+    let signature = client.send_and_confirm_transaction(&transaction)?;
+
+    println!("\n✅ Success! Your vote has been changed.");
+    println!("   - Transaction Signature: {}", signature);
+    println!(
+        "   - Review on Explorer: https://explorer.solana.com/tx/{}?cluster=devnet",
+        signature
+    );
+
+    Ok(())
+}
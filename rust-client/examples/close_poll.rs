@@ -0,0 +1,155 @@
+use anyhow::Result;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use std::convert::TryFrom;
+use std::{env, fs, str::FromStr};
+
+use voting_client::{
+    accounts::PollAccount,
+    instructions::{CloseCandidatesBuilder, ClosePollBuilder},
+    pdas::{find_irv_result_pda, find_poll_candidate_pdas, find_poll_result_pda, find_vault_pda},
+    types::BallotType,
+};
+
+// 每笔交易关闭的候选人账户数量，避免超出交易大小限制
+const CLOSE_BATCH_SIZE: usize = 20;
+
+/// 从文件加载钱包 Keypair
+fn load_wallet(path: &str) -> Result<Keypair> {
+    let content = fs::read_to_string(path)?;
+    let bytes: Vec<u8> = serde_json::from_str(&content)?;
+    Ok(Keypair::try_from(bytes.as_slice())?)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    println!("--- 🚀 Starting [Close Poll] Rust Client ---");
+
+    dotenvy::from_path("../.env").ok();
+
+    let rpc_url =
+        env::var("RPC_URL").unwrap_or_else(|_| "https://api.devnet.solana.com".to_string());
+
+    let wallet_path = env::var("WALLET_PATH").unwrap_or_else(|_| {
+        let home = env::var("HOME").expect("HOME environment variable is not set");
+        format!("{}/.config/solana/id.json", home)
+    });
+
+    // !! 重要：请将这里的地址替换为您要关闭的（已结束的）Poll Account 地址 !!
+    let poll_account_pubkey = Pubkey::from_str("8ucTtmiD8Jw4gCARkfWkVP1fQmfkTnXixEn5enfqCJUy")?;
+
+    let client = RpcClient::new(rpc_url);
+    let signer = load_wallet(&wallet_path)?;
+
+    println!("🔑 Signer (Authority): {}", signer.pubkey());
+    println!("📝 Closing Poll Account: {}", poll_account_pubkey);
+
+    // 1. 读取投票账户，找出仍未关闭的候选人账户
+    let poll_account_info = client.get_account(&poll_account_pubkey)?;
+    let poll_account_data = PollAccount::from_bytes(&poll_account_info.data)?;
+    let candidate_pdas = find_poll_candidate_pdas(
+        &poll_account_pubkey,
+        poll_account_data.legacy_candidate_count,
+        poll_account_data.candidate_count,
+    );
+    let mut open_candidates = Vec::new();
+    for chunk in candidate_pdas.chunks(100) {
+        let accounts = client.get_multiple_accounts(chunk)?;
+        for (candidate, account) in chunk.iter().zip(accounts) {
+            if account.is_some() {
+                open_candidates.push(*candidate);
+            }
+        }
+    }
+    println!("🗂️  Candidate accounts to close: {}", open_candidates.len());
+
+    // 2. 候选人账户分批通过 close_candidates 关闭，租金退回到 authority 自己的钱包
+    for batch in open_candidates.chunks(CLOSE_BATCH_SIZE) {
+        let candidate_metas: Vec<AccountMeta> = batch
+            .iter()
+            .map(|candidate| AccountMeta::new(*candidate, false))
+            .collect();
+        let instruction = CloseCandidatesBuilder::new()
+            .signer(signer.pubkey())
+            .poll_account(poll_account_pubkey)
+            .recipient(signer.pubkey())
+            .add_remaining_accounts(&candidate_metas)
+            .instruction();
+
+        let recent_blockhash = client.get_latest_blockhash()?;
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[&signer],
+            recent_blockhash,
+        );
+        let signature = client.send_and_confirm_transaction(&transaction)?;
+        println!("   - Closed {} candidate(s): {}", batch.len(), signature);
+    }
+
+    // 执行过 finalize_poll 的投票活动需要一并关闭结果账户
+    let (poll_result_pda, _) = find_poll_result_pda(&poll_account_pubkey);
+    let poll_result = client
+        .get_account_with_commitment(&poll_result_pda, client.commitment())?
+        .value
+        .map(|_| poll_result_pda);
+
+    // 加权投票活动的金库需要一并关闭（所有投票者都已通过 withdraw_tokens 取回代币）
+    let (vault_pda, _) = find_vault_pda(&poll_account_pubkey);
+    let vault_account = client
+        .get_account_with_commitment(&vault_pda, client.commitment())?
+        .value;
+    let token_program = vault_account.as_ref().map(|account| account.owner);
+    let vault = vault_account.map(|_| vault_pda);
+    // 转出金库中零散代币、收集 Token-2022 转账手续费时需要代币 mint；
+    // 金库中有他人转入的零散代币时，通过 RECIPIENT_TOKEN_ACCOUNT 指定接收的代币账户
+    let weight_mint = vault.and(poll_account_data.options.weight_mint);
+    let recipient_token_account = env::var("RECIPIENT_TOKEN_ACCOUNT")
+        .ok()
+        .map(|address| Pubkey::from_str(&address))
+        .transpose()?;
+
+    // 排序投票活动需要传入开票结果账户，执行过 tally_irv 时会被一并关闭
+    let irv_result = match poll_account_data.options.ballot_type {
+        BallotType::Ranked { .. } => Some(find_irv_result_pda(&poll_account_pubkey).0),
+        _ => None,
+    };
+
+    let instruction = ClosePollBuilder::new()
+        .signer(signer.pubkey())
+        .poll_account(poll_account_pubkey)
+        .poll_result(poll_result)
+        .vault(vault)
+        .token_program(token_program)
+        .weight_mint(weight_mint)
+        .recipient_token_account(recipient_token_account)
+        .irv_result(irv_result)
+        .recipient(signer.pubkey())
+        .instruction();
+
+    // 3. 发送交易
+    let recent_blockhash = client.get_latest_blockhash()?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&signer.pubkey()),
+        &[&signer],
+        recent_blockhash,
+    );
+
+    println!("\n⏳ Sending transaction...");
+    let signature = client.send_and_confirm_transaction(&transaction)?;
+
+    println!("\n✅ Success! The poll has been closed and its rent reclaimed.");
+    println!("   - Transaction Signature: {}", signature);
+    println!(
+        "   - Review on Explorer: https://explorer.solana.com/tx/{}?cluster=devnet",
+        signature
+    );
+
+    Ok(())
+}
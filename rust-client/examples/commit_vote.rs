@@ -0,0 +1,124 @@
+use anyhow::Result;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use std::convert::TryFrom;
+use std::{env, fs, str::FromStr};
+
+use voting_client::{
+    accounts::PollAccount,
+    allowlist::{parse_allowlist_csv, AllowlistTree},
+    ballot::vote_commitment,
+    instructions::CommitVoteBuilder,
+    pdas::find_voter_pass_pda,
+    programs::VOTING_ID,
+};
+
+/// 从文件加载钱包 Keypair
+fn load_wallet(path: &str) -> Result<Keypair> {
+    let content = fs::read_to_string(path)?;
+    let bytes: Vec<u8> = serde_json::from_str(&content)?;
+    Ok(Keypair::try_from(bytes.as_slice())?)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    println!("--- 🚀 Starting [Commit Vote] Rust Client ---");
+
+    dotenvy::from_path("../.env").ok();
+
+    let rpc_url =
+        env::var("RPC_URL").unwrap_or_else(|_| "https://api.devnet.solana.com".to_string());
+
+    let wallet_path = env::var("WALLET_PATH").unwrap_or_else(|_| {
+        let home = env::var("HOME").expect("HOME environment variable is not set");
+        format!("{}/.config/solana/id.json", home)
+    });
+
+    // !! 重要：请将这里的地址替换为设置了 reveal_duration 的 Poll Account 地址 !!
+    let poll_account_pubkey = Pubkey::from_str("8ucTtmiD8Jw4gCARkfWkVP1fQmfkTnXixEn5enfqCJUy")?;
+    // 要投票的候选人索引（从 0 开始），揭示前不会出现在链上
+    let candidate_index: u32 = 0;
+
+    let client = RpcClient::new(rpc_url);
+    let voter = load_wallet(&wallet_path)?;
+
+    println!("🔑 Voter: {}", voter.pubkey());
+    println!("📝 Committing vote in Poll: {}", poll_account_pubkey);
+
+    // 1. 读取投票账户，确认是秘密投票
+    let poll_account_info = client.get_account(&poll_account_pubkey)?;
+    let poll_account_data = PollAccount::from_bytes(&poll_account_info.data)?;
+    let Some(reveal_duration) = poll_account_data.options.reveal_duration else {
+        anyhow::bail!("Poll does not use commit-reveal voting");
+    };
+
+    // 2. 随机生成 salt 并计算承诺，salt 需要保存到揭示期使用
+    let salt: [u8; 32] = Keypair::new().to_bytes()[..32].try_into()?;
+    let commitment = vote_commitment(candidate_index, &salt);
+
+    let (voter_receipt_pda, _) = Pubkey::find_program_address(
+        &[
+            b"receipt",
+            &poll_account_pubkey.to_bytes(),
+            &voter.pubkey().to_bytes(),
+        ],
+        &VOTING_ID,
+    );
+
+    // emit_cpi! 事件需要程序的 event authority PDA
+    let (event_authority, _) = Pubkey::find_program_address(&[b"__event_authority"], &VOTING_ID);
+
+    let mut builder = CommitVoteBuilder::new();
+    builder
+        .signer(voter.pubkey())
+        .poll_account(poll_account_pubkey)
+        .voter_receipt(voter_receipt_pda)
+        .event_authority(event_authority)
+        .program(VOTING_ID)
+        .commitment(commitment);
+
+    // 设置了白名单的投票活动：用创建时的 CSV 重建 merkle 树并附加证明
+    if let Some(allowlist_root) = poll_account_data.options.allowlist_root {
+        let csv_path = env::var("ALLOWLIST_CSV").unwrap_or_else(|_| "allowlist.csv".to_string());
+        let tree = AllowlistTree::new(parse_allowlist_csv(&fs::read_to_string(csv_path)?)?)?;
+        anyhow::ensure!(
+            tree.root() == allowlist_root,
+            "Allowlist CSV does not match the poll's allowlist root"
+        );
+        builder.allowlist(&tree, &voter.pubkey())?;
+    }
+    // 设置了 requires_pass 的投票活动需要传入 authority 签发的通行证
+    if poll_account_data.options.requires_pass {
+        let (voter_pass, _) = find_voter_pass_pda(&poll_account_pubkey, &voter.pubkey());
+        builder.voter_pass(Some(voter_pass));
+    }
+    let instruction = builder.instruction();
+
+    // 3. 发送交易
+    let recent_blockhash = client.get_latest_blockhash()?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&voter.pubkey()),
+        &[&voter],
+        recent_blockhash,
+    );
+
+    println!("\n⏳ Sending transaction...");
+    let signature = client.send_and_confirm_transaction(&transaction)?;
+
+    println!("\n✅ Success! Your vote has been committed.");
+    println!("   - Transaction Signature: {}", signature);
+    println!(
+        "🔒 Reveal between {} and {} with:",
+        poll_account_data.end_time,
+        poll_account_data.end_time + reveal_duration
+    );
+    println!("   CANDIDATE_INDEX={}", candidate_index);
+    println!("   SALT={}", bs58::encode(salt).into_string());
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use anyhow::Result;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use std::convert::TryFrom;
+use std::{env, fs, str::FromStr};
+
+use voting_client::{
+    accounts::{PollAccount, PollResult},
+    instructions::FinalizePollBuilder,
+    pdas::{find_poll_candidate_pda, find_poll_result_pda},
+    programs::VOTING_ID,
+    types::PollStatus,
+};
+
+// 每笔交易统计的候选人数量，避免超出交易大小限制
+const FINALIZE_BATCH_SIZE: u32 = 20;
+
+/// 从文件加载钱包 Keypair
+fn load_wallet(path: &str) -> Result<Keypair> {
+    let content = fs::read_to_string(path)?;
+    let bytes: Vec<u8> = serde_json::from_str(&content)?;
+    Ok(Keypair::try_from(bytes.as_slice())?)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    println!("--- 🚀 Starting [Finalize Poll] Rust Client ---");
+
+    dotenvy::from_path("../.env").ok();
+
+    let rpc_url =
+        env::var("RPC_URL").unwrap_or_else(|_| "https://api.devnet.solana.com".to_string());
+
+    let wallet_path = env::var("WALLET_PATH").unwrap_or_else(|_| {
+        let home = env::var("HOME").expect("HOME environment variable is not set");
+        format!("{}/.config/solana/id.json", home)
+    });
+
+    // !! 重要：请将这里的地址替换为您要结算的（已结束的）Poll Account 地址 !!
+    let poll_account_pubkey = Pubkey::from_str("8ucTtmiD8Jw4gCARkfWkVP1fQmfkTnXixEn5enfqCJUy")?;
+
+    let client = RpcClient::new(rpc_url);
+    // 结算无需权限，签名者只负责支付交易费
+    let signer = load_wallet(&wallet_path)?;
+
+    println!("🔑 Signer (Fee Payer): {}", signer.pubkey());
+    println!("📝 Finalizing Poll Account: {}", poll_account_pubkey);
+
+    // emit_cpi! 事件需要程序的 event authority PDA
+    let (event_authority, _) = Pubkey::find_program_address(&[b"__event_authority"], &VOTING_ID);
+    let (poll_result_pda, _) = find_poll_result_pda(&poll_account_pubkey);
+
+    // 1. 候选人账户按索引分批作为 remaining accounts 传入，每批从 tallied_count 开始
+    loop {
+        let poll_account_info = client.get_account(&poll_account_pubkey)?;
+        let poll_account_data = PollAccount::from_bytes(&poll_account_info.data)?;
+        if poll_account_data.status == PollStatus::Finalized {
+            break;
+        }
+
+        let batch_end = poll_account_data
+            .candidate_count
+            .min(poll_account_data.tallied_count + FINALIZE_BATCH_SIZE);
+        let candidate_metas: Vec<AccountMeta> = (poll_account_data.tallied_count..batch_end)
+            .map(|candidate_index| {
+                let (candidate, _) = find_poll_candidate_pda(
+                    &poll_account_pubkey,
+                    poll_account_data.legacy_candidate_count,
+                    candidate_index,
+                );
+                AccountMeta::new_readonly(candidate, false)
+            })
+            .collect();
+        println!(
+            "🗂️  Tallying candidates {}..{} of {}",
+            poll_account_data.tallied_count, batch_end, poll_account_data.candidate_count
+        );
+
+        let instruction = FinalizePollBuilder::new()
+            .payer(signer.pubkey())
+            .poll_account(poll_account_pubkey)
+            .poll_result(poll_result_pda)
+            .event_authority(event_authority)
+            .program(VOTING_ID)
+            .add_remaining_accounts(&candidate_metas)
+            .instruction();
+
+        // 2. 发送交易
+        let recent_blockhash = client.get_latest_blockhash()?;
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[&signer],
+            recent_blockhash,
+        );
+
+        println!("\n⏳ Sending transaction...");
+        let signature = client.send_and_confirm_transaction(&transaction)?;
+        println!("   - Transaction Signature: {}", signature);
+    }
+
+    println!("\n✅ Success! The poll has been finalized.");
+
+    // 3. 读取链上记录的结算结果
+    let poll_account_info = client.get_account(&poll_account_pubkey)?;
+    let poll_account_data = PollAccount::from_bytes(&poll_account_info.data)?;
+    let poll_result_info = client.get_account(&poll_result_pda)?;
+    let poll_result_data = PollResult::from_bytes(&poll_result_info.data)?;
+    println!("\n🏆 Winners ({:?}):", poll_account_data.options.tie_break);
+    for winner in &poll_result_data.winners {
+        println!("   - Candidate #{}", winner);
+    }
+    // 秘密投票中未在揭示期内揭示的承诺不计票
+    if poll_account_data.options.reveal_duration.is_some() {
+        println!(
+            "🙈 Unrevealed commits: {} of {}",
+            poll_account_data.commit_count - poll_account_data.reveal_count,
+            poll_account_data.commit_count
+        );
+    }
+    println!("📊 Ranked tallies:");
+    for (rank, tally) in poll_result_data.ranked_tallies.iter().enumerate() {
+        println!(
+            "   {}. Candidate #{} - {} votes",
+            rank + 1,
+            tally.candidate_index,
+            tally.votes
+        );
+    }
+
+    Ok(())
+}
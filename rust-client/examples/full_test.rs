@@ -11,9 +11,11 @@ use std::{env, fs};
 
 // 导入所有需要的生成代码
 use voting_client::{
-    accounts::{CandidateAccount, PollAccount},
+    accounts::{CandidateAccount, PollAccount, PollCounter},
     instructions::{AddCandidateBuilder, InitializePollBuilder, VoteBuilder},
+    pdas::{find_candidate_pda, find_poll_counter_pda, find_poll_pda},
     programs::VOTING_ID,
+    types::{BallotType, PollOptions, TieBreak},
 };
 
 /// 从文件加载钱包 Keypair
@@ -39,22 +41,53 @@ async fn main() -> Result<()> {
     let signer = load_wallet(&wallet_path)?;
     println!("🔑 Signer Wallet: {}", signer.pubkey());
 
+    // emit_cpi! 事件需要程序的 event authority PDA
+    let (event_authority, _) = Pubkey::find_program_address(&[b"__event_authority"], &VOTING_ID);
+
     // --- 步骤 1: 初始化投票 ---
-    let poll_account = Keypair::new();
+    // 投票账户是 PDA，poll_id 为 authority 当前的计数（计数器不存在时为 0）
+    let (poll_counter, _) = find_poll_counter_pda(&signer.pubkey());
+    let poll_id = match client
+        .get_account_with_commitment(&poll_counter, client.commitment())?
+        .value
+    {
+        Some(account) => PollCounter::from_bytes(&account.data)?.poll_count,
+        None => 0,
+    };
+    let (poll_account, _) = find_poll_pda(&signer.pubkey(), poll_id);
+
     let init_instruction = InitializePollBuilder::new()
         .signer(signer.pubkey())
-        .poll_account(poll_account.pubkey())
+        .poll_account(poll_account)
+        .poll_counter(poll_counter)
+        .event_authority(event_authority)
+        .program(VOTING_ID)
+        .poll_id(poll_id)
         .name("Full Integration Test Poll".to_string())
         .description("Automated test poll.".to_string())
         .start_time((Utc::now().timestamp() - 60) as u64)
         .end_time((Utc::now().timestamp() + 3600) as u64)
+        .options(PollOptions {
+            // 平票时最先添加的候选人获胜
+            tie_break: TieBreak::LowestIndex,
+            allow_vote_change: false,
+            allow_vote_retraction: false,
+            gate_mint: None,
+            min_balance: 0,
+            weight_mint: None,
+            collection_mint: None,
+            allowlist_root: None,
+            requires_pass: false,
+            ballot_type: BallotType::Single,
+            reveal_duration: None,
+        })
         .instruction();
 
     let recent_blockhash = client.get_latest_blockhash()?;
     let init_tx = Transaction::new_signed_with_payer(
         &[init_instruction],
         Some(&signer.pubkey()),
-        &[&signer, &poll_account],
+        &[&signer],
         recent_blockhash,
     );
     let init_sig = client.send_and_confirm_transaction(&init_tx)?;
@@ -62,24 +95,20 @@ async fn main() -> Result<()> {
         "\n[✅ Step 1 SUCCESS] Poll initialized. Signature: {}",
         init_sig
     );
-    println!("   Poll Account: {}", poll_account.pubkey());
+    println!("   Poll Account: {}", poll_account);
 
     // --- 步骤 2: 添加候选人 ---
-    let poll_account_info = client.get_account(&poll_account.pubkey())?;
+    let poll_account_info = client.get_account(&poll_account)?;
     let poll_account_data = PollAccount::from_bytes(&poll_account_info.data)?;
-    let (candidate_pda, _) = Pubkey::find_program_address(
-        &[
-            b"candidate",
-            &poll_account.pubkey().to_bytes(),
-            &[poll_account_data.candidate_count],
-        ],
-        &VOTING_ID,
-    );
+    let candidate_index = poll_account_data.candidate_count;
+    let (candidate_pda, _) = find_candidate_pda(&poll_account, candidate_index);
 
     let add_cand_instruction = AddCandidateBuilder::new()
         .signer(signer.pubkey())
-        .poll_account(poll_account.pubkey())
+        .poll_account(poll_account)
         .candidate_account(candidate_pda)
+        .event_authority(event_authority)
+        .program(VOTING_ID)
         .candidate_name("Candidate A".to_string())
         .instruction();
 
@@ -101,16 +130,19 @@ async fn main() -> Result<()> {
     let (receipt_pda, _) = Pubkey::find_program_address(
         &[
             b"receipt",
-            &poll_account.pubkey().to_bytes(),
+            &poll_account.to_bytes(),
             &signer.pubkey().to_bytes(),
         ],
         &VOTING_ID,
     );
     let vote_instruction = VoteBuilder::new()
         .signer(signer.pubkey())
-        .poll_account(poll_account.pubkey())
+        .poll_account(poll_account)
         .candidate_account(candidate_pda)
         .voter_receipt(receipt_pda)
+        .event_authority(event_authority)
+        .program(VOTING_ID)
+        .candidate_index(candidate_index)
         .instruction();
 
     let recent_blockhash = client.get_latest_blockhash()?;
@@ -2,6 +2,7 @@ use anyhow::Result;
 use chrono::Utc;
 use solana_client::rpc_client::RpcClient;
 use solana_sdk::{
+    pubkey::Pubkey,
     signature::{Keypair, Signer},
     transaction::Transaction,
 };
@@ -9,7 +10,13 @@ use std::convert::TryFrom;
 use std::{env, fs};
 
 // --- 现在可以直接像使用外部库一样导入 ---
-use voting_client::instructions::InitializePollBuilder;
+use voting_client::{
+    accounts::PollCounter,
+    instructions::InitializePollBuilder,
+    pdas::{find_poll_counter_pda, find_poll_pda},
+    programs::VOTING_ID,
+    types::{BallotType, PollOptions, TieBreak},
+};
 
 /// 从文件加载钱包 Keypair
 fn load_wallet(path: &str) -> Result<Keypair> {
@@ -35,26 +42,61 @@ async fn main() -> Result<()> {
 
     let client = RpcClient::new(rpc_url);
     let signer = load_wallet(&wallet_path)?;
-    let poll_account = Keypair::new();
+
+    // 投票账户是 [b"poll", authority, poll_id] 的 PDA，无需再生成并签名一个新的 Keypair；
+    // poll_id 为 authority 当前的计数（计数器不存在时为 0）
+    let (poll_counter, _) = find_poll_counter_pda(&signer.pubkey());
+    let poll_id = match client
+        .get_account_with_commitment(&poll_counter, client.commitment())?
+        .value
+    {
+        Some(account) => PollCounter::from_bytes(&account.data)?.poll_count,
+        None => 0,
+    };
+    let (poll_account, _) = find_poll_pda(&signer.pubkey(), poll_id);
 
     println!("🔑 Signer (Authority): {}", signer.pubkey());
-    println!("📝 New Poll Account Address: {}", poll_account.pubkey());
+    println!(
+        "📝 New Poll Account Address: {} (poll id {})",
+        poll_account, poll_id
+    );
+
+    // emit_cpi! 事件需要程序的 event authority PDA
+    let (event_authority, _) = Pubkey::find_program_address(&[b"__event_authority"], &VOTING_ID);
 
     // InitializePollBuilder 会自动处理 program_id 和 system_program
     let instruction = InitializePollBuilder::new()
         .signer(signer.pubkey())
-        .poll_account(poll_account.pubkey())
+        .poll_account(poll_account)
+        .poll_counter(poll_counter)
+        .event_authority(event_authority)
+        .program(VOTING_ID)
+        .poll_id(poll_id)
         .name("Poll from Rust Client (Final)".to_string())
         .description("This should finally work!".to_string())
         .start_time((Utc::now().timestamp() - 60) as u64)
         .end_time((Utc::now().timestamp() + 3600) as u64)
+        .options(PollOptions {
+            // 平票时最先添加的候选人获胜
+            tie_break: TieBreak::LowestIndex,
+            allow_vote_change: false,
+            allow_vote_retraction: false,
+            gate_mint: None,
+            min_balance: 0,
+            weight_mint: None,
+            collection_mint: None,
+            allowlist_root: None,
+            requires_pass: false,
+            ballot_type: BallotType::Single,
+            reveal_duration: None,
+        })
         .instruction();
 
     let recent_blockhash = client.get_latest_blockhash()?;
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
         Some(&signer.pubkey()),
-        &[&signer, &poll_account],
+        &[&signer],
         recent_blockhash,
     );
 
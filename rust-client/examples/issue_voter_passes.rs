@@ -0,0 +1,99 @@
+use anyhow::Result;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use std::convert::TryFrom;
+use std::{env, fs, str::FromStr};
+
+use voting_client::{
+    allowlist::parse_allowlist_csv, instructions::IssueVoterPassesBuilder,
+    pdas::find_voter_pass_pda, types::VoterPassEntry,
+};
+
+// 每笔交易签发的通行证数量，避免超出交易大小限制
+const ISSUE_BATCH_SIZE: usize = 10;
+
+/// 从文件加载钱包 Keypair
+fn load_wallet(path: &str) -> Result<Keypair> {
+    let content = fs::read_to_string(path)?;
+    let bytes: Vec<u8> = serde_json::from_str(&content)?;
+    Ok(Keypair::try_from(bytes.as_slice())?)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    println!("--- 🚀 Starting [Issue Voter Passes] Rust Client ---");
+
+    dotenvy::from_path("../.env").ok();
+
+    let rpc_url =
+        env::var("RPC_URL").unwrap_or_else(|_| "https://api.devnet.solana.com".to_string());
+
+    let wallet_path = env::var("WALLET_PATH").unwrap_or_else(|_| {
+        let home = env::var("HOME").expect("HOME environment variable is not set");
+        format!("{}/.config/solana/id.json", home)
+    });
+
+    // !! 重要：请将这里的地址替换为设置了 requires_pass 的 Poll Account 地址 !!
+    let poll_account_pubkey = Pubkey::from_str("8ucTtmiD8Jw4gCARkfWkVP1fQmfkTnXixEn5enfqCJUy")?;
+    // 投票者名单，与白名单 CSV 格式相同：每行 `voter[,weight]`
+    let csv_path = env::var("VOTER_ROLL_CSV").unwrap_or_else(|_| "voters.csv".to_string());
+
+    let client = RpcClient::new(rpc_url);
+    let signer = load_wallet(&wallet_path)?;
+
+    println!("🔑 Signer (Authority): {}", signer.pubkey());
+    println!("📝 Issuing passes for Poll: {}", poll_account_pubkey);
+
+    // 1. 读取投票者名单
+    let entries = parse_allowlist_csv(&fs::read_to_string(&csv_path)?)?;
+    println!("🗂️  Voters in {}: {}", csv_path, entries.len());
+
+    // emit_cpi! 事件需要程序的 event authority PDA
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &voting_client::programs::VOTING_ID);
+
+    // 2. 分批签发，remaining_accounts 依次传入对应的通行证 PDA
+    for batch in entries.chunks(ISSUE_BATCH_SIZE) {
+        let passes: Vec<VoterPassEntry> = batch
+            .iter()
+            .map(|entry| VoterPassEntry {
+                voter: entry.voter,
+                weight: Some(entry.weight),
+            })
+            .collect();
+        let pass_metas: Vec<AccountMeta> = batch
+            .iter()
+            .map(|entry| {
+                let (voter_pass, _) = find_voter_pass_pda(&poll_account_pubkey, &entry.voter);
+                AccountMeta::new(voter_pass, false)
+            })
+            .collect();
+        let instruction = IssueVoterPassesBuilder::new()
+            .signer(signer.pubkey())
+            .poll_account(poll_account_pubkey)
+            .event_authority(event_authority)
+            .program(voting_client::programs::VOTING_ID)
+            .passes(passes)
+            .add_remaining_accounts(&pass_metas)
+            .instruction();
+
+        let recent_blockhash = client.get_latest_blockhash()?;
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[&signer],
+            recent_blockhash,
+        );
+        let signature = client.send_and_confirm_transaction(&transaction)?;
+        println!("   - Issued {} pass(es): {}", batch.len(), signature);
+    }
+
+    println!("\n✅ Success! All voter passes have been issued.");
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use anyhow::Result;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::signature::{Keypair, Signer};
+use std::convert::TryFrom;
+use std::{env, fs};
+
+use voting_client::{
+    accounts::{PollAccount, PollCounter},
+    pdas::{find_authority_poll_pdas, find_poll_counter_pda},
+};
+
+/// 从文件加载钱包 Keypair
+fn load_wallet(path: &str) -> Result<Keypair> {
+    let content = fs::read_to_string(path)?;
+    let bytes: Vec<u8> = serde_json::from_str(&content)?;
+    Ok(Keypair::try_from(bytes.as_slice())?)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    println!("--- 🚀 Starting [List Polls] Rust Client ---");
+
+    dotenvy::from_path("../.env").ok();
+
+    let rpc_url =
+        env::var("RPC_URL").unwrap_or_else(|_| "https://api.devnet.solana.com".to_string());
+
+    let wallet_path = env::var("WALLET_PATH").unwrap_or_else(|_| {
+        let home = env::var("HOME").expect("HOME environment variable is not set");
+        format!("{}/.config/solana/id.json", home)
+    });
+
+    let client = RpcClient::new(rpc_url);
+    let authority = load_wallet(&wallet_path)?.pubkey();
+
+    println!("🔑 Authority: {}", authority);
+
+    // 1. 读取计数器，得到该 authority 创建过的投票活动数量
+    let (poll_counter, _) = find_poll_counter_pda(&authority);
+    let poll_count = match client
+        .get_account_with_commitment(&poll_counter, client.commitment())?
+        .value
+    {
+        Some(account) => PollCounter::from_bytes(&account.data)?.poll_count,
+        None => 0,
+    };
+    println!("🗂️  Polls created: {}", poll_count);
+
+    // 2. 按 poll_id 推导出所有投票账户地址并批量读取（已被 close_poll 关闭的会返回 None）
+    let poll_pdas = find_authority_poll_pdas(&authority, poll_count);
+    for chunk in poll_pdas.chunks(100) {
+        let accounts = client.get_multiple_accounts(chunk)?;
+        for (poll_pda, account) in chunk.iter().zip(accounts) {
+            match account {
+                Some(account) => {
+                    let poll = PollAccount::from_bytes(&account.data)?;
+                    println!(
+                        "   - {} \"{}\" ({} candidates, {} votes, {:?})",
+                        poll_pda, poll.name, poll.candidate_count, poll.total_votes, poll.status
+                    );
+                }
+                None => println!("   - {} (closed)", poll_pda),
+            }
+        }
+    }
+
+    Ok(())
+}
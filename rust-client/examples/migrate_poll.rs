@@ -0,0 +1,93 @@
+use anyhow::Result;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use std::convert::TryFrom;
+use std::{env, fs, str::FromStr};
+
+use voting_client::{instructions::MigratePollBuilder, programs::VOTING_ID};
+
+/// 从文件加载钱包 Keypair
+fn load_wallet(path: &str) -> Result<Keypair> {
+    let content = fs::read_to_string(path)?;
+    let bytes: Vec<u8> = serde_json::from_str(&content)?;
+    Ok(Keypair::try_from(bytes.as_slice())?)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    println!("--- 🚀 Starting [Migrate Poll] Rust Client ---");
+
+    dotenvy::from_path("../.env").ok();
+
+    let rpc_url =
+        env::var("RPC_URL").unwrap_or_else(|_| "https://api.devnet.solana.com".to_string());
+
+    let wallet_path = env::var("WALLET_PATH").unwrap_or_else(|_| {
+        let home = env::var("HOME").expect("HOME environment variable is not set");
+        format!("{}/.config/solana/id.json", home)
+    });
+
+    // !! 重要：请将这里的地址替换为需要迁移的旧版 Poll Account 地址 !!
+    let poll_account_pubkey = Pubkey::from_str("2R3tUpUfQhTjMVowcd8wKhGKzJbQ1HpKc9HPeC5xXLyq")?;
+
+    let client = RpcClient::new(rpc_url);
+    let signer = load_wallet(&wallet_path)?;
+
+    println!("🔑 Signer (Payer): {}", signer.pubkey());
+    println!("📝 Migrating Poll Account: {}", poll_account_pubkey);
+
+    // 1. 旧版账户无法用当前布局解析，直接按索引推导候选人 PDA，保留链上存在的账户
+    let candidate_pdas: Vec<Pubkey> = (0..15u8)
+        .map(|index| {
+            Pubkey::find_program_address(
+                &[b"candidate", poll_account_pubkey.as_ref(), &[index]],
+                &VOTING_ID,
+            )
+            .0
+        })
+        .collect();
+    let candidate_metas: Vec<AccountMeta> = client
+        .get_multiple_accounts(&candidate_pdas)?
+        .iter()
+        .zip(candidate_pdas.iter())
+        .take_while(|(account, _)| account.is_some())
+        .map(|(_, candidate)| AccountMeta::new_readonly(*candidate, false))
+        .collect();
+    println!(
+        "🗂️  Candidate accounts to recount: {}",
+        candidate_metas.len()
+    );
+
+    // 2. 候选人账户作为 remaining accounts 传入，用于重新统计 total_votes
+    let instruction = MigratePollBuilder::new()
+        .signer(signer.pubkey())
+        .poll_account(poll_account_pubkey)
+        .add_remaining_accounts(&candidate_metas)
+        .instruction();
+
+    // 3. 发送交易
+    let recent_blockhash = client.get_latest_blockhash()?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&signer.pubkey()),
+        &[&signer],
+        recent_blockhash,
+    );
+
+    println!("\n⏳ Sending transaction...");
+    let signature = client.send_and_confirm_transaction(&transaction)?;
+
+    println!("\n✅ Success! The poll has been migrated to the current layout.");
+    println!("   - Transaction Signature: {}", signature);
+    println!(
+        "   - Review on Explorer: https://explorer.solana.com/tx/{}?cluster=devnet",
+        signature
+    );
+
+    Ok(())
+}
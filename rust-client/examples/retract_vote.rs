@@ -0,0 +1,92 @@
+use anyhow::Result;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use std::convert::TryFrom;
+use std::{env, fs, str::FromStr};
+
+// 导入生成的代码
+use voting_client::{accounts::VoterReceipt, instructions::RetractVoteBuilder};
+
+/// 从文件加载钱包 Keypair
+fn load_wallet(path: &str) -> Result<Keypair> {
+    let content = fs::read_to_string(path)?;
+    let bytes: Vec<u8> = serde_json::from_str(&content)?;
+    Ok(Keypair::try_from(bytes.as_slice())?)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    println!("--- 🚀 Starting [Retract Vote] Rust Client ---");
+
+    dotenvy::from_path("../.env").ok();
+
+    let rpc_url =
+        env::var("RPC_URL").unwrap_or_else(|_| "https://api.devnet.solana.com".to_string());
+
+    let wallet_path = env::var("WALLET_PATH").unwrap_or_else(|_| {
+        let home = env::var("HOME").expect("HOME environment variable is not set");
+        format!("{}/.config/solana/id.json", home)
+    });
+
+    // !! 重要：请将这里的地址替换为您已投过票、且允许撤回投票的 Poll Account 地址 !!
+    let poll_account_pubkey = Pubkey::from_str("8ucTtmiD8Jw4gCARkfWkVP1fQmfkTnXixEn5enfqCJUy")?;
+
+    let client = RpcClient::new(rpc_url);
+    let voter = load_wallet(&wallet_path)?;
+
+    println!("🔑 Voter: {}", voter.pubkey());
+    println!("📝 Voting in Poll: {}", poll_account_pubkey);
+
+    // 1. 计算投票回执账户的 PDA，并读取之前所投的候选人
+    let (voter_receipt_pda, _) = Pubkey::find_program_address(
+        &[
+            b"receipt",
+            &poll_account_pubkey.to_bytes(),
+            &voter.pubkey().to_bytes(),
+        ],
+        &voting_client::programs::VOTING_ID,
+    );
+    println!("🧾 Voter Receipt PDA: {}", voter_receipt_pda);
+    let receipt_info = client.get_account(&voter_receipt_pda)?;
+    let candidate = VoterReceipt::from_bytes(&receipt_info.data)?.candidate;
+    println!("↩️  Retracting vote for Candidate: {}", candidate);
+
+    // emit_cpi! 事件需要程序的 event authority PDA
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &voting_client::programs::VOTING_ID);
+
+    // 2. 使用 Builder 构造指令
+    let instruction = RetractVoteBuilder::new()
+        .signer(voter.pubkey())
+        .poll_account(poll_account_pubkey)
+        .candidate_account(candidate)
+        .voter_receipt(voter_receipt_pda)
+        .event_authority(event_authority)
+        .program(voting_client::programs::VOTING_ID)
+        .instruction();
+
+    // 3. 发送交易
+    let recent_blockhash = client.get_latest_blockhash()?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&voter.pubkey()),
+        &[&voter], // 只有投票者需要签名
+        recent_blockhash,
+    );
+
+    println!("\n⏳ Sending transaction...");
+    let signature = client.send_and_confirm_transaction(&transaction)?;
+
+    println!("\n✅ Success! Your vote has been retracted and the receipt rent refunded.");
+    println!("   - Transaction Signature: {}", signature);
+    println!(
+        "   - Review on Explorer: https://explorer.solana.com/tx/{}?cluster=devnet",
+        signature
+    );
+
+    Ok(())
+}
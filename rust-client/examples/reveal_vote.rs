@@ -0,0 +1,114 @@
+use anyhow::Result;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use std::convert::TryFrom;
+use std::{env, fs, str::FromStr};
+
+use voting_client::{
+    accounts::{PollAccount, VoterReceipt},
+    instructions::RevealVoteBuilder,
+    pdas::find_poll_candidate_pda,
+    programs::VOTING_ID,
+};
+
+/// 从文件加载钱包 Keypair
+fn load_wallet(path: &str) -> Result<Keypair> {
+    let content = fs::read_to_string(path)?;
+    let bytes: Vec<u8> = serde_json::from_str(&content)?;
+    Ok(Keypair::try_from(bytes.as_slice())?)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    println!("--- 🚀 Starting [Reveal Vote] Rust Client ---");
+
+    dotenvy::from_path("../.env").ok();
+
+    let rpc_url =
+        env::var("RPC_URL").unwrap_or_else(|_| "https://api.devnet.solana.com".to_string());
+
+    let wallet_path = env::var("WALLET_PATH").unwrap_or_else(|_| {
+        let home = env::var("HOME").expect("HOME environment variable is not set");
+        format!("{}/.config/solana/id.json", home)
+    });
+
+    // !! 重要：请将这里的地址替换为已提交承诺的 Poll Account 地址 !!
+    let poll_account_pubkey = Pubkey::from_str("8ucTtmiD8Jw4gCARkfWkVP1fQmfkTnXixEn5enfqCJUy")?;
+    // commit_vote 输出的候选人索引和 salt
+    let candidate_index: u32 = env::var("CANDIDATE_INDEX")?.parse()?;
+    let salt: [u8; 32] = bs58::decode(env::var("SALT")?)
+        .into_vec()?
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("SALT must be 32 bytes"))?;
+
+    let client = RpcClient::new(rpc_url);
+    let voter = load_wallet(&wallet_path)?;
+
+    println!("🔑 Voter: {}", voter.pubkey());
+    println!("📝 Revealing vote in Poll: {}", poll_account_pubkey);
+
+    // 1. 读取投票账户，根据索引推导候选人账户地址
+    let poll_account_info = client.get_account(&poll_account_pubkey)?;
+    let poll_account_data = PollAccount::from_bytes(&poll_account_info.data)?;
+    let (candidate_account_pubkey, _) = find_poll_candidate_pda(
+        &poll_account_pubkey,
+        poll_account_data.legacy_candidate_count,
+        candidate_index,
+    );
+    println!(
+        "👀 Revealing vote for Candidate #{}: {}",
+        candidate_index, candidate_account_pubkey
+    );
+
+    let (voter_receipt_pda, _) = Pubkey::find_program_address(
+        &[
+            b"receipt",
+            &poll_account_pubkey.to_bytes(),
+            &voter.pubkey().to_bytes(),
+        ],
+        &VOTING_ID,
+    );
+
+    // emit_cpi! 事件需要程序的 event authority PDA
+    let (event_authority, _) = Pubkey::find_program_address(&[b"__event_authority"], &VOTING_ID);
+
+    let instruction = RevealVoteBuilder::new()
+        .signer(voter.pubkey())
+        .poll_account(poll_account_pubkey)
+        .candidate_account(candidate_account_pubkey)
+        .voter_receipt(voter_receipt_pda)
+        .event_authority(event_authority)
+        .program(VOTING_ID)
+        .candidate_index(candidate_index)
+        .salt(salt)
+        .instruction();
+
+    // 2. 发送交易
+    let recent_blockhash = client.get_latest_blockhash()?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&voter.pubkey()),
+        &[&voter],
+        recent_blockhash,
+    );
+
+    println!("\n⏳ Sending transaction...");
+    let signature = client.send_and_confirm_transaction(&transaction)?;
+
+    println!("\n✅ Success! Your vote has been revealed and counted.");
+    println!("   - Transaction Signature: {}", signature);
+
+    // 3. 回执中已记录揭示后的候选人
+    let receipt_info = client.get_account(&voter_receipt_pda)?;
+    let receipt = VoterReceipt::from_bytes(&receipt_info.data)?;
+    println!(
+        "🧾 Receipt: candidate {}, {} vote(s), revealed: {}",
+        receipt.candidate, receipt.weight, receipt.revealed
+    );
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use anyhow::Result;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use std::convert::TryFrom;
+use std::{env, fs, str::FromStr};
+
+use voting_client::{
+    accounts::PollAccount, instructions::SetAdminBuilder, programs::VOTING_ID, types::AdminRole,
+};
+
+/// 从文件加载钱包 Keypair
+fn load_wallet(path: &str) -> Result<Keypair> {
+    let content = fs::read_to_string(path)?;
+    let bytes: Vec<u8> = serde_json::from_str(&content)?;
+    Ok(Keypair::try_from(bytes.as_slice())?)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    println!("--- 🚀 Starting [Set Admin] Rust Client ---");
+
+    dotenvy::from_path("../.env").ok();
+
+    let rpc_url =
+        env::var("RPC_URL").unwrap_or_else(|_| "https://api.devnet.solana.com".to_string());
+
+    let wallet_path = env::var("WALLET_PATH").unwrap_or_else(|_| {
+        let home = env::var("HOME").expect("HOME environment variable is not set");
+        format!("{}/.config/solana/id.json", home)
+    });
+
+    // !! 重要：请将这里的地址替换为您管理的 Poll Account 地址 !!
+    let poll_account_pubkey = Pubkey::from_str("8ucTtmiD8Jw4gCARkfWkVP1fQmfkTnXixEn5enfqCJUy")?;
+    // 要设置的管理员及其角色；role 为 None 时移除该管理员
+    let admin = Pubkey::from_str(&env::var("ADMIN")?)?;
+    let role = Some(AdminRole::CandidateManager);
+
+    let client = RpcClient::new(rpc_url);
+    let signer = load_wallet(&wallet_path)?;
+
+    println!("🔑 Signer (Owner): {}", signer.pubkey());
+    println!("📝 Poll Account: {}", poll_account_pubkey);
+    println!("👤 Admin: {} -> {:?}", admin, role);
+
+    // emit_cpi! 事件需要程序的 event authority PDA
+    let (event_authority, _) = Pubkey::find_program_address(&[b"__event_authority"], &VOTING_ID);
+
+    let mut builder = SetAdminBuilder::new();
+    builder
+        .signer(signer.pubkey())
+        .poll_account(poll_account_pubkey)
+        .event_authority(event_authority)
+        .program(VOTING_ID)
+        .admin(admin);
+    if let Some(role) = role {
+        builder.role(role);
+    }
+    let instruction = builder.instruction();
+
+    let recent_blockhash = client.get_latest_blockhash()?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&signer.pubkey()),
+        &[&signer],
+        recent_blockhash,
+    );
+
+    println!("\n⏳ Sending transaction...");
+    let signature = client.send_and_confirm_transaction(&transaction)?;
+
+    println!("\n✅ Success! The admin list has been updated.");
+    println!("   - Transaction Signature: {}", signature);
+
+    // 读取最新的管理员列表
+    let poll_account_info = client.get_account(&poll_account_pubkey)?;
+    let poll_account_data = PollAccount::from_bytes(&poll_account_info.data)?;
+    println!("👑 Authority: {}", poll_account_data.authority);
+    for entry in &poll_account_data.admins {
+        println!("   - {}: {:?}", entry.admin, entry.role);
+    }
+
+    Ok(())
+}
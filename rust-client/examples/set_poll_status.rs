@@ -0,0 +1,94 @@
+use anyhow::Result;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use std::convert::TryFrom;
+use std::{env, fs, str::FromStr};
+
+use voting_client::{
+    accounts::PollAccount,
+    instructions::{CancelPollBuilder, PausePollBuilder, ResumePollBuilder},
+    programs::VOTING_ID,
+};
+
+/// 从文件加载钱包 Keypair
+fn load_wallet(path: &str) -> Result<Keypair> {
+    let content = fs::read_to_string(path)?;
+    let bytes: Vec<u8> = serde_json::from_str(&content)?;
+    Ok(Keypair::try_from(bytes.as_slice())?)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    println!("--- 🚀 Starting [Set Poll Status] Rust Client ---");
+
+    dotenvy::from_path("../.env").ok();
+
+    let rpc_url =
+        env::var("RPC_URL").unwrap_or_else(|_| "https://api.devnet.solana.com".to_string());
+
+    let wallet_path = env::var("WALLET_PATH").unwrap_or_else(|_| {
+        let home = env::var("HOME").expect("HOME environment variable is not set");
+        format!("{}/.config/solana/id.json", home)
+    });
+
+    // !! 重要：请将这里的地址替换为您管理的 Poll Account 地址 !!
+    let poll_account_pubkey = Pubkey::from_str("8ucTtmiD8Jw4gCARkfWkVP1fQmfkTnXixEn5enfqCJUy")?;
+    // 要执行的操作：pause、resume 或 cancel（取消后不可恢复）
+    let action = env::var("ACTION").unwrap_or_else(|_| "pause".to_string());
+
+    let client = RpcClient::new(rpc_url);
+    let signer = load_wallet(&wallet_path)?;
+
+    println!("🔑 Signer (Owner): {}", signer.pubkey());
+    println!("📝 Poll Account: {}", poll_account_pubkey);
+
+    // emit_cpi! 事件需要程序的 event authority PDA
+    let (event_authority, _) = Pubkey::find_program_address(&[b"__event_authority"], &VOTING_ID);
+
+    let instruction = match action.as_str() {
+        "pause" => PausePollBuilder::new()
+            .signer(signer.pubkey())
+            .poll_account(poll_account_pubkey)
+            .event_authority(event_authority)
+            .program(VOTING_ID)
+            .instruction(),
+        "resume" => ResumePollBuilder::new()
+            .signer(signer.pubkey())
+            .poll_account(poll_account_pubkey)
+            .event_authority(event_authority)
+            .program(VOTING_ID)
+            .instruction(),
+        "cancel" => CancelPollBuilder::new()
+            .signer(signer.pubkey())
+            .poll_account(poll_account_pubkey)
+            .event_authority(event_authority)
+            .program(VOTING_ID)
+            .instruction(),
+        other => anyhow::bail!(
+            "Unknown ACTION \"{}\", expected pause, resume or cancel",
+            other
+        ),
+    };
+
+    let recent_blockhash = client.get_latest_blockhash()?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&signer.pubkey()),
+        &[&signer],
+        recent_blockhash,
+    );
+
+    println!("\n⏳ Sending transaction...");
+    let signature = client.send_and_confirm_transaction(&transaction)?;
+
+    let poll_account_info = client.get_account(&poll_account_pubkey)?;
+    let poll_account_data = PollAccount::from_bytes(&poll_account_info.data)?;
+    println!("\n✅ Success! Poll status: {:?}", poll_account_data.status);
+    println!("   - Transaction Signature: {}", signature);
+
+    Ok(())
+}
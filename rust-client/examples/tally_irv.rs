@@ -0,0 +1,157 @@
+use anyhow::Result;
+use solana_client::{
+    rpc_client::RpcClient,
+    rpc_config::RpcProgramAccountsConfig,
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_sdk::{
+    hash::hash,
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use std::convert::TryFrom;
+use std::{env, fs, str::FromStr};
+
+use voting_client::{
+    accounts::{IrvResult, PollAccount, RankedBallot},
+    instructions::TallyIrvBuilder,
+    pdas::find_irv_result_pda,
+    programs::VOTING_ID,
+    types::PollStatus,
+};
+
+// 每笔交易统计的选票数量，避免超出交易大小限制
+const TALLY_BATCH_SIZE: usize = 20;
+
+/// 从文件加载钱包 Keypair
+fn load_wallet(path: &str) -> Result<Keypair> {
+    let content = fs::read_to_string(path)?;
+    let bytes: Vec<u8> = serde_json::from_str(&content)?;
+    Ok(Keypair::try_from(bytes.as_slice())?)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    println!("--- 🚀 Starting [Tally IRV] Rust Client ---");
+
+    dotenvy::from_path("../.env").ok();
+
+    let rpc_url =
+        env::var("RPC_URL").unwrap_or_else(|_| "https://api.devnet.solana.com".to_string());
+
+    let wallet_path = env::var("WALLET_PATH").unwrap_or_else(|_| {
+        let home = env::var("HOME").expect("HOME environment variable is not set");
+        format!("{}/.config/solana/id.json", home)
+    });
+
+    // !! 重要：请将这里的地址替换为您要开票的（已结束的）排序投票 Poll Account 地址 !!
+    let poll_account_pubkey = Pubkey::from_str("8ucTtmiD8Jw4gCARkfWkVP1fQmfkTnXixEn5enfqCJUy")?;
+
+    let client = RpcClient::new(rpc_url);
+    // 开票无需权限，签名者只负责支付交易费和结果账户的租金
+    let signer = load_wallet(&wallet_path)?;
+
+    println!("🔑 Signer (Fee Payer): {}", signer.pubkey());
+    println!("📝 Tallying Poll Account: {}", poll_account_pubkey);
+
+    // emit_cpi! 事件需要程序的 event authority PDA
+    let (event_authority, _) = Pubkey::find_program_address(&[b"__event_authority"], &VOTING_ID);
+    let (irv_result_pda, _) = find_irv_result_pda(&poll_account_pubkey);
+
+    // 1. 按账户类型的 discriminator 和 poll 字段查询该投票活动的所有选票
+    let discriminator = &hash(b"account:RankedBallot").to_bytes()[..8];
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![
+            RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, discriminator)),
+            RpcFilterType::Memcmp(Memcmp::new_base58_encoded(8, poll_account_pubkey.as_ref())),
+        ]),
+        ..Default::default()
+    };
+    let ballots: Vec<Pubkey> = client
+        .get_program_accounts_with_config(&VOTING_ID, config)?
+        .into_iter()
+        .map(|(ballot, _)| ballot)
+        .collect();
+    println!("🗳️  Ballots: {}", ballots.len());
+
+    // 2. 每一轮都要重新统计所有选票，每次传入一批本轮尚未统计的选票，直到开票完成
+    loop {
+        let poll_account_info = client.get_account(&poll_account_pubkey)?;
+        let poll_account_data = PollAccount::from_bytes(&poll_account_info.data)?;
+        if poll_account_data.status == PollStatus::Finalized {
+            break;
+        }
+
+        let round = match client
+            .get_account_with_commitment(&irv_result_pda, client.commitment())?
+            .value
+        {
+            Some(account) => IrvResult::from_bytes(&account.data)?.round,
+            None => 0,
+        };
+        let mut pending = Vec::new();
+        for chunk in ballots.chunks(100) {
+            let accounts = client.get_multiple_accounts(chunk)?;
+            for (ballot, account) in chunk.iter().zip(accounts) {
+                if let Some(account) = account {
+                    if RankedBallot::from_bytes(&account.data)?.counted_rounds == round {
+                        pending.push(*ballot);
+                    }
+                }
+            }
+        }
+
+        // 没有选票时也需要调用一次，以记录无人当选的结果
+        let ballot_metas: Vec<AccountMeta> = pending
+            .iter()
+            .take(TALLY_BATCH_SIZE)
+            .map(|ballot| AccountMeta::new(*ballot, false))
+            .collect();
+        println!(
+            "🔁 Round {}: counting {} of {} pending ballot(s)",
+            round + 1,
+            ballot_metas.len(),
+            pending.len()
+        );
+
+        let instruction = TallyIrvBuilder::new()
+            .payer(signer.pubkey())
+            .poll_account(poll_account_pubkey)
+            .irv_result(irv_result_pda)
+            .event_authority(event_authority)
+            .program(VOTING_ID)
+            .add_remaining_accounts(&ballot_metas)
+            .instruction();
+
+        let recent_blockhash = client.get_latest_blockhash()?;
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[&signer],
+            recent_blockhash,
+        );
+        let signature = client.send_and_confirm_transaction(&transaction)?;
+        println!("   - Transaction Signature: {}", signature);
+    }
+
+    println!("\n✅ Success! The ranked poll has been tallied.");
+
+    // 3. 读取链上记录的逐轮淘汰顺序和获胜者
+    let irv_result_info = client.get_account(&irv_result_pda)?;
+    let irv_result_data = IrvResult::from_bytes(&irv_result_info.data)?;
+    for (round, eliminated) in irv_result_data.eliminated.iter().enumerate() {
+        println!(
+            "   Round {}: eliminated Candidate #{}",
+            round + 1,
+            eliminated
+        );
+    }
+    match irv_result_data.winner {
+        Some(winner) => println!("\n🏆 Winner: Candidate #{}", winner),
+        None => println!("\n🏆 No winner: every ballot was exhausted"),
+    }
+
+    Ok(())
+}
@@ -3,7 +3,10 @@ use solana_client::rpc_client::RpcClient;
 use solana_sdk::pubkey::Pubkey;
 use std::{env, str::FromStr};
 
-use voting_client::accounts::CandidateAccount;
+use voting_client::{
+    accounts::{CandidateAccount, VoterReceipt},
+    programs::VOTING_ID,
+};
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -17,6 +20,8 @@ async fn main() -> Result<()> {
     // !! 重要：请将这里的地址替换为您投票的候选人地址 !!
     let candidate_account_pubkey =
         Pubkey::from_str("D2dmKitcUCCcDYEsd1vT67rzF11xTV1kDZwJoXhRnet1")?;
+    // !! 重要：请将这里的地址替换为要核验选票的投票者地址 !!
+    let voter_pubkey = Pubkey::from_str("6MZDRo5v8K2NfdohdD76QNpSgk3GH3Aup53BeMaRAEpd")?;
 
     let client = RpcClient::new(rpc_url);
 
@@ -38,5 +43,33 @@ async fn main() -> Result<()> {
         println!("\n🤔 Hmm, the vote count is still 0. Something might be wrong.");
     }
 
+    // 通过投票回执核验该投票者的选票：seeds 与合约一致 [b"receipt", poll_key, voter_key]
+    let (voter_receipt_pda, _) = Pubkey::find_program_address(
+        &[
+            b"receipt",
+            candidate_data.poll.as_ref(),
+            voter_pubkey.as_ref(),
+        ],
+        &VOTING_ID,
+    );
+    println!("\n🧾 Checking voter receipt: {}", voter_receipt_pda);
+
+    let receipt_info = client.get_account(&voter_receipt_pda)?;
+    let receipt_data = VoterReceipt::from_bytes(&receipt_info.data)?;
+    let voted_at = chrono::DateTime::from_timestamp(receipt_data.voted_at, 0)
+        .map(|time| time.to_rfc3339())
+        .unwrap_or_else(|| receipt_data.voted_at.to_string());
+
+    println!("   - Voter: {}", receipt_data.voter);
+    println!("   - Voted For: {}", receipt_data.candidate);
+    println!("   - Voted At: {}", voted_at);
+    println!("   - Slot: {}", receipt_data.slot);
+
+    if receipt_data.candidate == candidate_account_pubkey {
+        println!("\n🎉🎉 The voter's ballot was cast for this candidate.");
+    } else {
+        println!("\n🤔 The voter's ballot was cast for a different candidate.");
+    }
+
     Ok(())
 }
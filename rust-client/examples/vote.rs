@@ -9,7 +9,12 @@ use std::convert::TryFrom;
 use std::{env, fs, str::FromStr};
 
 // 导入生成的代码
-use voting_client::instructions::VoteBuilder;
+use voting_client::{
+    accounts::PollAccount,
+    allowlist::{parse_allowlist_csv, AllowlistTree},
+    instructions::VoteBuilder,
+    pdas::{find_poll_candidate_pda, find_voter_pass_pda},
+};
 
 /// 从文件加载钱包 Keypair
 fn load_wallet(path: &str) -> Result<Keypair> {
@@ -34,17 +39,29 @@ async fn main() -> Result<()> {
 
     // !! 重要：请将这里的地址替换为您之前步骤中创建的账户地址 !!
     let poll_account_pubkey = Pubkey::from_str("8ucTtmiD8Jw4gCARkfWkVP1fQmfkTnXixEn5enfqCJUy")?;
-    let candidate_account_pubkey =
-        Pubkey::from_str("D2dmKitcUCCcDYEsd1vT67rzF11xTV1kDZwJoXhRnet1")?;
+    // 要投票的候选人索引（从 0 开始）
+    let candidate_index: u32 = 0;
 
     let client = RpcClient::new(rpc_url);
     let voter = load_wallet(&wallet_path)?;
 
     println!("🔑 Voter: {}", voter.pubkey());
     println!("📝 Voting in Poll: {}", poll_account_pubkey);
-    println!("👍 Voting for Candidate: {}", candidate_account_pubkey);
 
-    // 1. 计算投票回执账户的 PDA
+    // 1. 读取投票账户，根据索引推导候选人账户地址
+    let poll_account_info = client.get_account(&poll_account_pubkey)?;
+    let poll_account_data = PollAccount::from_bytes(&poll_account_info.data)?;
+    let (candidate_account_pubkey, _) = find_poll_candidate_pda(
+        &poll_account_pubkey,
+        poll_account_data.legacy_candidate_count,
+        candidate_index,
+    );
+    println!(
+        "👍 Voting for Candidate #{}: {}",
+        candidate_index, candidate_account_pubkey
+    );
+
+    // 2. 计算投票回执账户的 PDA
     let (voter_receipt_pda, _) = Pubkey::find_program_address(
         &[
             b"receipt",
@@ -55,15 +72,39 @@ async fn main() -> Result<()> {
     );
     println!("🧾 Voter Receipt PDA: {}", voter_receipt_pda);
 
-    // 2. 使用 Builder 构造指令
-    let instruction = VoteBuilder::new()
+    // emit_cpi! 事件需要程序的 event authority PDA
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &voting_client::programs::VOTING_ID);
+
+    // 3. 使用 Builder 构造指令
+    let mut builder = VoteBuilder::new();
+    builder
         .signer(voter.pubkey())
         .poll_account(poll_account_pubkey)
         .candidate_account(candidate_account_pubkey)
         .voter_receipt(voter_receipt_pda)
-        .instruction();
+        .event_authority(event_authority)
+        .program(voting_client::programs::VOTING_ID)
+        .candidate_index(candidate_index);
+
+    // 设置了白名单的投票活动：用创建时的 CSV 重建 merkle 树并附加证明
+    if let Some(allowlist_root) = poll_account_data.options.allowlist_root {
+        let csv_path = env::var("ALLOWLIST_CSV").unwrap_or_else(|_| "allowlist.csv".to_string());
+        let tree = AllowlistTree::new(parse_allowlist_csv(&fs::read_to_string(csv_path)?)?)?;
+        anyhow::ensure!(
+            tree.root() == allowlist_root,
+            "Allowlist CSV does not match the poll's allowlist root"
+        );
+        builder.allowlist(&tree, &voter.pubkey())?;
+    }
+    // 设置了 requires_pass 的投票活动需要传入 authority 签发的通行证
+    if poll_account_data.options.requires_pass {
+        let (voter_pass, _) = find_voter_pass_pda(&poll_account_pubkey, &voter.pubkey());
+        builder.voter_pass(Some(voter_pass));
+    }
+    let instruction = builder.instruction();
 
-    // 3. 发送交易
+    // 4. 发送交易
     let recent_blockhash = client.get_latest_blockhash()?;
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -0,0 +1,125 @@
+use anyhow::Result;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use std::convert::TryFrom;
+use std::{env, fs, str::FromStr};
+
+use voting_client::{
+    accounts::{PollAccount, VoterReceipt},
+    instructions::VoteQuadraticBuilder,
+    pdas::{find_poll_candidate_pda, find_voter_pass_pda},
+    programs::VOTING_ID,
+    types::BallotType,
+};
+
+/// 从文件加载钱包 Keypair
+fn load_wallet(path: &str) -> Result<Keypair> {
+    let content = fs::read_to_string(path)?;
+    let bytes: Vec<u8> = serde_json::from_str(&content)?;
+    Ok(Keypair::try_from(bytes.as_slice())?)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    println!("--- 🚀 Starting [Vote Quadratic] Rust Client ---");
+
+    dotenvy::from_path("../.env").ok();
+
+    let rpc_url =
+        env::var("RPC_URL").unwrap_or_else(|_| "https://api.devnet.solana.com".to_string());
+
+    let wallet_path = env::var("WALLET_PATH").unwrap_or_else(|_| {
+        let home = env::var("HOME").expect("HOME environment variable is not set");
+        format!("{}/.config/solana/id.json", home)
+    });
+
+    // !! 重要：请将这里的地址替换为使用二次方投票（BallotType::Quadratic）的 Poll Account 地址 !!
+    let poll_account_pubkey = Pubkey::from_str("8ucTtmiD8Jw4gCARkfWkVP1fQmfkTnXixEn5enfqCJUy")?;
+    // 要投票的候选人索引（从 0 开始）及本次追加的票数
+    let candidate_index: u32 = 0;
+    let votes: u64 = 2;
+
+    let client = RpcClient::new(rpc_url);
+    let voter = load_wallet(&wallet_path)?;
+
+    println!("🔑 Voter: {}", voter.pubkey());
+    println!("📝 Voting in Poll: {}", poll_account_pubkey);
+
+    // 1. 读取投票账户，确认是二次方投票
+    let poll_account_info = client.get_account(&poll_account_pubkey)?;
+    let poll_account_data = PollAccount::from_bytes(&poll_account_info.data)?;
+    let BallotType::Quadratic { credits_per_voter } = poll_account_data.options.ballot_type else {
+        anyhow::bail!("Poll is not a quadratic poll");
+    };
+    let (candidate_account_pubkey, _) = find_poll_candidate_pda(
+        &poll_account_pubkey,
+        poll_account_data.legacy_candidate_count,
+        candidate_index,
+    );
+    println!(
+        "👍 Casting {} vote(s) for Candidate #{}: {}",
+        votes, candidate_index, candidate_account_pubkey
+    );
+
+    // 2. 回执是额度账本，多次投票共用同一个 PDA
+    let (voter_receipt_pda, _) = Pubkey::find_program_address(
+        &[
+            b"receipt",
+            &poll_account_pubkey.to_bytes(),
+            &voter.pubkey().to_bytes(),
+        ],
+        &VOTING_ID,
+    );
+
+    // emit_cpi! 事件需要程序的 event authority PDA
+    let (event_authority, _) = Pubkey::find_program_address(&[b"__event_authority"], &VOTING_ID);
+
+    let mut builder = VoteQuadraticBuilder::new();
+    builder
+        .signer(voter.pubkey())
+        .poll_account(poll_account_pubkey)
+        .candidate_account(candidate_account_pubkey)
+        .voter_receipt(voter_receipt_pda)
+        .event_authority(event_authority)
+        .program(VOTING_ID)
+        .candidate_index(candidate_index)
+        .votes(votes);
+    // 设置了 requires_pass 的投票活动需要传入 authority 签发的通行证
+    if poll_account_data.options.requires_pass {
+        let (voter_pass, _) = find_voter_pass_pda(&poll_account_pubkey, &voter.pubkey());
+        builder.voter_pass(Some(voter_pass));
+    }
+    let instruction = builder.instruction();
+
+    // 3. 发送交易
+    let recent_blockhash = client.get_latest_blockhash()?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&voter.pubkey()),
+        &[&voter],
+        recent_blockhash,
+    );
+
+    println!("\n⏳ Sending transaction...");
+    let signature = client.send_and_confirm_transaction(&transaction)?;
+
+    println!("\n✅ Success! Your votes have been cast.");
+    println!("   - Transaction Signature: {}", signature);
+
+    // 4. 读取回执中的额度使用情况
+    let receipt_info = client.get_account(&voter_receipt_pda)?;
+    let receipt = VoterReceipt::from_bytes(&receipt_info.data)?;
+    println!(
+        "💳 Credits used: {} / {}",
+        receipt.credits_spent, credits_per_voter
+    );
+    for (candidate, votes) in receipt.selections.iter().zip(&receipt.allocations) {
+        println!("   - {}: {} vote(s)", candidate, votes);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use anyhow::Result;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use std::convert::TryFrom;
+use std::{env, fs, str::FromStr};
+
+use voting_client::{
+    accounts::PollAccount, instructions::VoteRankedBuilder, pdas::find_ranked_ballot_pda,
+    programs::VOTING_ID, types::BallotType,
+};
+
+/// 从文件加载钱包 Keypair
+fn load_wallet(path: &str) -> Result<Keypair> {
+    let content = fs::read_to_string(path)?;
+    let bytes: Vec<u8> = serde_json::from_str(&content)?;
+    Ok(Keypair::try_from(bytes.as_slice())?)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    println!("--- 🚀 Starting [Vote Ranked] Rust Client ---");
+
+    dotenvy::from_path("../.env").ok();
+
+    let rpc_url =
+        env::var("RPC_URL").unwrap_or_else(|_| "https://api.devnet.solana.com".to_string());
+
+    let wallet_path = env::var("WALLET_PATH").unwrap_or_else(|_| {
+        let home = env::var("HOME").expect("HOME environment variable is not set");
+        format!("{}/.config/solana/id.json", home)
+    });
+
+    // !! 重要：请将这里的地址替换为使用排序投票（BallotType::Ranked）的 Poll Account 地址 !!
+    let poll_account_pubkey = Pubkey::from_str("8ucTtmiD8Jw4gCARkfWkVP1fQmfkTnXixEn5enfqCJUy")?;
+    // 按偏好从高到低排列的候选人索引（从 0 开始）
+    let rankings: Vec<u32> = vec![1, 0, 2];
+
+    let client = RpcClient::new(rpc_url);
+    let voter = load_wallet(&wallet_path)?;
+
+    println!("🔑 Voter: {}", voter.pubkey());
+    println!("📝 Voting in Poll: {}", poll_account_pubkey);
+
+    // 1. 读取投票账户，确认是排序投票且排名数量不超过上限
+    let poll_account_info = client.get_account(&poll_account_pubkey)?;
+    let poll_account_data = PollAccount::from_bytes(&poll_account_info.data)?;
+    let BallotType::Ranked { max_rankings } = poll_account_data.options.ballot_type else {
+        anyhow::bail!("Poll does not use ranked ballots");
+    };
+    anyhow::ensure!(
+        rankings.len() <= max_rankings as usize,
+        "At most {} candidates can be ranked",
+        max_rankings
+    );
+    println!("👍 Rankings: {:?}", rankings);
+
+    // 2. 选票保存在投票者的 ballot PDA 中
+    let (ranked_ballot, _) = find_ranked_ballot_pda(&poll_account_pubkey, &voter.pubkey());
+    println!("🧾 Ranked Ballot PDA: {}", ranked_ballot);
+
+    // emit_cpi! 事件需要程序的 event authority PDA
+    let (event_authority, _) = Pubkey::find_program_address(&[b"__event_authority"], &VOTING_ID);
+
+    let instruction = VoteRankedBuilder::new()
+        .signer(voter.pubkey())
+        .poll_account(poll_account_pubkey)
+        .ranked_ballot(ranked_ballot)
+        .event_authority(event_authority)
+        .program(VOTING_ID)
+        .rankings(rankings)
+        .instruction();
+
+    // 3. 发送交易
+    let recent_blockhash = client.get_latest_blockhash()?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&voter.pubkey()),
+        &[&voter],
+        recent_blockhash,
+    );
+
+    println!("\n⏳ Sending transaction...");
+    let signature = client.send_and_confirm_transaction(&transaction)?;
+
+    println!("\n✅ Success! Your ranked ballot has been cast.");
+    println!("   - Transaction Signature: {}", signature);
+
+    Ok(())
+}
@@ -19,8 +19,10 @@ import {
 } from "../generated/ts/voting/instructions";
 import {
   getPollAccountDecoder,
+  getPollCounterDecoder,
   getCandidateAccountDecoder,
 } from "../generated/ts/voting/accounts";
+import { TieBreak, ballotType } from "../generated/ts/voting/types";
 
 dotenv.config();
 
@@ -53,19 +55,57 @@ async function main() {
   console.log(`🔑 Signer Wallet: ${signer.publicKey.toBase58()}`);
 
   try {
+    // emit_cpi! 事件需要程序的 event authority PDA
+    const [eventAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("__event_authority")],
+      CONFIG.programId,
+    );
+
     // === 步骤 1: 初始化投票 ===
-    const pollAccount = Keypair.generate();
+    // 投票账户是 [b"poll", authority, poll_id] 的 PDA，poll_id 为 authority 当前的计数
+    const [pollCounter] = PublicKey.findProgramAddressSync(
+      [Buffer.from("poll_counter"), signer.publicKey.toBuffer()],
+      CONFIG.programId,
+    );
+    const counterInfo = await connection.getAccountInfo(pollCounter);
+    const pollId = counterInfo
+      ? getPollCounterDecoder().decode(counterInfo.data).pollCount
+      : 0n;
+    const pollIdBytes = Buffer.alloc(8);
+    pollIdBytes.writeBigUInt64LE(pollId);
+    const [pollAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from("poll"), signer.publicKey.toBuffer(), pollIdBytes],
+      CONFIG.programId,
+    );
     const initData = getInitializePollInstructionDataEncoder().encode({
+      pollId,
       name: "Full Test Poll",
       description: "A poll created from the integration test script.",
       startTime: BigInt(Math.floor(Date.now() / 1000) - 60),
       endTime: BigInt(Math.floor(Date.now() / 1000) + 3600),
+      options: {
+        // 平票时最先添加的候选人获胜
+        tieBreak: TieBreak.LowestIndex,
+        allowVoteChange: false,
+        allowVoteRetraction: false,
+        gateMint: null,
+        minBalance: 0n,
+        weightMint: null,
+        collectionMint: null,
+        allowlistRoot: null,
+        requiresPass: false,
+        ballotType: ballotType("Single"),
+        revealDuration: null,
+      },
     });
     const initInstruction = new TransactionInstruction({
       keys: [
         { pubkey: signer.publicKey, isSigner: true, isWritable: true },
-        { pubkey: pollAccount.publicKey, isSigner: true, isWritable: true },
+        { pubkey: pollAccount, isSigner: false, isWritable: true },
+        { pubkey: pollCounter, isSigner: false, isWritable: true },
         { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: eventAuthority, isSigner: false, isWritable: false },
+        { pubkey: CONFIG.programId, isSigner: false, isWritable: false },
       ],
       programId: CONFIG.programId,
       data: Buffer.from(initData),
@@ -73,23 +113,22 @@ async function main() {
     const initSig = await sendAndConfirmTransaction(
       connection,
       new Transaction().add(initInstruction),
-      [signer, pollAccount],
+      [signer],
     );
     console.log(
       `\n[✅ Step 1 SUCCESS] Poll initialized. Signature: ${initSig}`,
     );
-    console.log(`   Poll Account: ${pollAccount.publicKey.toBase58()}`);
+    console.log(`   Poll Account: ${pollAccount.toBase58()}`);
 
     // === 步骤 2: 添加候选人 ===
-    const pollInfo = await connection.getAccountInfo(pollAccount.publicKey);
+    const pollInfo = await connection.getAccountInfo(pollAccount);
     if (!pollInfo) throw new Error("Poll account not found after creation.");
     const decodedPoll = getPollAccountDecoder().decode(pollInfo.data);
+    const candidateIndex = decodedPoll.candidateCount;
+    const candidateIndexBytes = Buffer.alloc(4);
+    candidateIndexBytes.writeUInt32LE(candidateIndex);
     const [candidatePda] = PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("candidate"),
-        pollAccount.publicKey.toBuffer(),
-        Buffer.from([decodedPoll.candidateCount]),
-      ],
+      [Buffer.from("candidate"), pollAccount.toBuffer(), candidateIndexBytes],
       CONFIG.programId,
     );
     const addCandidateData = getAddCandidateInstructionDataEncoder().encode({
@@ -98,9 +137,11 @@ async function main() {
     const addCandidateInstruction = new TransactionInstruction({
       keys: [
         { pubkey: signer.publicKey, isSigner: true, isWritable: true },
-        { pubkey: pollAccount.publicKey, isSigner: false, isWritable: true },
+        { pubkey: pollAccount, isSigner: false, isWritable: true },
         { pubkey: candidatePda, isSigner: false, isWritable: true },
         { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: eventAuthority, isSigner: false, isWritable: false },
+        { pubkey: CONFIG.programId, isSigner: false, isWritable: false },
       ],
       programId: CONFIG.programId,
       data: Buffer.from(addCandidateData),
@@ -119,19 +160,29 @@ async function main() {
     const [receiptPda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("receipt"),
-        pollAccount.publicKey.toBuffer(),
+        pollAccount.toBuffer(),
         signer.publicKey.toBuffer(),
       ],
       CONFIG.programId,
     );
-    const voteData = getVoteInstructionDataEncoder().encode({});
+    const voteData = getVoteInstructionDataEncoder().encode({
+      candidateIndex,
+      // 未设置 allowlist_root 的投票活动不需要白名单证明
+      allowlistProof: null,
+    });
     const voteInstruction = new TransactionInstruction({
       keys: [
         { pubkey: signer.publicKey, isSigner: true, isWritable: true },
-        { pubkey: pollAccount.publicKey, isSigner: false, isWritable: true },
+        { pubkey: pollAccount, isSigner: false, isWritable: true },
         { pubkey: candidatePda, isSigner: false, isWritable: true },
         { pubkey: receiptPda, isSigner: false, isWritable: true },
+        // voter_token_account 是可选账户，未设置 gate_mint 时用程序 ID 占位
+        { pubkey: CONFIG.programId, isSigner: false, isWritable: false },
+        // voter_pass 同样是可选账户，未设置 requires_pass 时用程序 ID 占位
+        { pubkey: CONFIG.programId, isSigner: false, isWritable: false },
         { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: eventAuthority, isSigner: false, isWritable: false },
+        { pubkey: CONFIG.programId, isSigner: false, isWritable: false },
       ],
       programId: CONFIG.programId,
       data: Buffer.from(voteData),
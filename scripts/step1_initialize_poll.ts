@@ -12,6 +12,8 @@ import * as dotenv from "dotenv";
 
 // Import ONLY the instruction data encoder. This is the most reliable method.
 import { getInitializePollInstructionDataEncoder } from "../generated/ts/voting/instructions";
+import { getPollCounterDecoder } from "../generated/ts/voting/accounts";
+import { TieBreak, ballotType } from "../generated/ts/voting/types";
 
 // Load .env file
 dotenv.config();
@@ -52,50 +54,91 @@ async function main() {
     // 1. Initialize connection and wallets
     const connection = new Connection(CONFIG.rpcUrl, "confirmed");
     const signer = loadWallet(CONFIG.walletPath!);
-    const pollAccount = Keypair.generate();
+
+    // 2. The poll is a PDA of [b"poll", authority, poll_id], where poll_id is
+    // the authority's current poll count (0 if the counter doesn't exist yet)
+    const [pollCounter] = PublicKey.findProgramAddressSync(
+      [Buffer.from("poll_counter"), signer.publicKey.toBuffer()],
+      CONFIG.programId,
+    );
+    const counterInfo = await connection.getAccountInfo(pollCounter);
+    const pollId = counterInfo
+      ? getPollCounterDecoder().decode(counterInfo.data).pollCount
+      : 0n;
+
+    const pollIdBytes = Buffer.alloc(8);
+    pollIdBytes.writeBigUInt64LE(pollId);
+    const [pollAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from("poll"), signer.publicKey.toBuffer(), pollIdBytes],
+      CONFIG.programId,
+    );
 
     console.log(`🔑 Signer (Authority): ${signer.publicKey.toBase58()}`);
     console.log(
-      `📝 New Poll Account Address: ${pollAccount.publicKey.toBase58()}`,
+      `📝 New Poll Account Address: ${pollAccount.toBase58()} (poll id ${pollId})`,
     );
 
-    // 2. Get the instruction data using the low-level encoder
+    // 3. Get the instruction data using the low-level encoder
     const instructionData = getInitializePollInstructionDataEncoder().encode({
+      pollId,
       name: "Final Poll Test",
       description:
         "This test uses the data encoder directly for max compatibility.",
       startTime: BigInt(Math.floor(Date.now() / 1000) - 60),
       endTime: BigInt(Math.floor(Date.now() / 1000) + 3600),
+      options: {
+        // On a tie, the earliest-added candidate wins
+        tieBreak: TieBreak.LowestIndex,
+        allowVoteChange: false,
+        allowVoteRetraction: false,
+        gateMint: null,
+        minBalance: 0n,
+        weightMint: null,
+        collectionMint: null,
+        allowlistRoot: null,
+        requiresPass: false,
+        ballotType: ballotType("Single"),
+        revealDuration: null,
+      },
     });
 
-    // 3. Manually define the accounts in the format @solana/web3.js expects.
+    // emit_cpi! events require the program's event authority PDA
+    const [eventAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("__event_authority")],
+      CONFIG.programId,
+    );
+
+    // 4. Manually define the accounts in the format @solana/web3.js expects.
     // The order MUST match the `InitializePoll` struct in your Rust code.
     const keys = [
       { pubkey: signer.publicKey, isSigner: true, isWritable: true },
-      { pubkey: pollAccount.publicKey, isSigner: true, isWritable: true },
+      { pubkey: pollAccount, isSigner: false, isWritable: true },
+      { pubkey: pollCounter, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: eventAuthority, isSigner: false, isWritable: false },
+      { pubkey: CONFIG.programId, isSigner: false, isWritable: false },
     ];
 
-    // 4. Create a standard TransactionInstruction
+    // 5. Create a standard TransactionInstruction
     const instruction = new TransactionInstruction({
       keys: keys,
       programId: CONFIG.programId,
       data: Buffer.from(instructionData),
     });
 
-    // 5. Create and send the transaction
+    // 6. Create and send the transaction
     const transaction = new Transaction().add(instruction);
     console.log("\n⏳ Sending transaction...");
 
     const signature = await sendAndConfirmTransaction(
       connection,
       transaction,
-      [signer, pollAccount], // Both must sign
+      [signer], // The poll is a PDA, so no extra signer is needed
     );
 
     console.log("\n✅ Success! The transaction was confirmed.");
     console.log(`   - Transaction Signature: ${signature}`);
-    console.log(`   - New Poll Account: ${pollAccount.publicKey.toBase58()}`);
+    console.log(`   - New Poll Account: ${pollAccount.toBase58()}`);
     console.log(
       `   - Review on Explorer: https://explorer.solana.com/tx/${signature}?cluster=devnet`,
     );
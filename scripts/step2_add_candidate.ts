@@ -70,11 +70,14 @@ async function main() {
     const currentCandidateCount = decodedPoll.candidateCount;
     console.log(`✅ Current candidate count is: ${currentCandidateCount}`);
 
+    // 新候选人的索引就是当前的 candidateCount（u32，小端序）
+    const candidateIndexBytes = Buffer.alloc(4);
+    candidateIndexBytes.writeUInt32LE(currentCandidateCount);
     const [candidatePda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("candidate"),
         CONFIG.pollAccountPubkey.toBuffer(),
-        Buffer.from([currentCandidateCount]),
+        candidateIndexBytes,
       ],
       CONFIG.programId,
     );
@@ -87,11 +90,19 @@ async function main() {
       candidateName: candidateName,
     });
 
+    // emit_cpi! 事件需要程序的 event authority PDA
+    const [eventAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("__event_authority")],
+      CONFIG.programId,
+    );
+
     const keys = [
       { pubkey: signer.publicKey, isSigner: true, isWritable: true },
       { pubkey: CONFIG.pollAccountPubkey, isSigner: false, isWritable: true },
       { pubkey: candidatePda, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: eventAuthority, isSigner: false, isWritable: false },
+      { pubkey: CONFIG.programId, isSigner: false, isWritable: false },
     ];
 
     const instruction = new TransactionInstruction({
@@ -12,6 +12,7 @@ import * as dotenv from "dotenv";
 import { Buffer } from "buffer";
 
 // 导入需要的 codama 生成的函数
+import { getPollAccountDecoder } from "../generated/ts/voting/accounts";
 import { getVoteInstructionDataEncoder } from "../generated/ts/voting/instructions";
 
 dotenv.config();
@@ -25,9 +26,8 @@ const CONFIG = {
   pollAccountPubkey: new PublicKey(
     "2R3tUpUfQhTjMVowcd8wKhGKzJbQ1HpKc9HPeC5xXLyq",
   ),
-  candidateAccountPubkey: new PublicKey(
-    "GZzVP862HEb4dW8VJ5Loixju4dnAFDkApzVbsu2jh6x5",
-  ),
+  // 要投票的候选人索引（从 0 开始）
+  candidateIndex: 0,
 };
 
 function loadWallet(path: string): Keypair {
@@ -56,11 +56,36 @@ async function main() {
 
     console.log(`🔑 Voter: ${voter.publicKey.toBase58()}`);
     console.log(`📝 Voting in Poll: ${CONFIG.pollAccountPubkey.toBase58()}`);
+
+    // 1. 根据索引计算候选人账户的 PDA：[b"candidate", poll_key, index]
+    // 索引为 u32 小端序；迁移自旧版布局的投票活动中，前 legacyCandidateCount 个候选人的索引为单字节
+    const pollAccountInfo = await connection.getAccountInfo(
+      CONFIG.pollAccountPubkey,
+    );
+    if (!pollAccountInfo) {
+      throw new Error("Poll account not found.");
+    }
+    const decodedPoll = getPollAccountDecoder().decode(pollAccountInfo.data);
+    let candidateIndexBytes: Buffer;
+    if (CONFIG.candidateIndex < decodedPoll.legacyCandidateCount) {
+      candidateIndexBytes = Buffer.from([CONFIG.candidateIndex]);
+    } else {
+      candidateIndexBytes = Buffer.alloc(4);
+      candidateIndexBytes.writeUInt32LE(CONFIG.candidateIndex);
+    }
+    const [candidateAccountPubkey] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("candidate"),
+        CONFIG.pollAccountPubkey.toBuffer(),
+        candidateIndexBytes,
+      ],
+      CONFIG.programId,
+    );
     console.log(
-      `👍 Voting for Candidate: ${CONFIG.candidateAccountPubkey.toBase58()}`,
+      `👍 Voting for Candidate #${CONFIG.candidateIndex}: ${candidateAccountPubkey.toBase58()}`,
     );
 
-    // 1. 计算投票回执账户的 PDA (Voter Receipt PDA)
+    // 2. 计算投票回执账户的 PDA (Voter Receipt PDA)
     // 这是为了防止同一个人重复投票
     // seeds 必须与合约匹配: [b"receipt", poll_key, voter_key]
     const [voterReceiptPda] = PublicKey.findProgramAddressSync(
@@ -73,30 +98,42 @@ async function main() {
     );
     console.log(`🧾 Voter Receipt PDA: ${voterReceiptPda.toBase58()}`);
 
-    // 2. 获取指令数据 (vote 指令没有参数)
-    const instructionData = getVoteInstructionDataEncoder().encode({});
+    // 3. 获取指令数据 (vote 指令的参数是候选人索引)
+    const instructionData = getVoteInstructionDataEncoder().encode({
+      candidateIndex: CONFIG.candidateIndex,
+      // 未设置 allowlist_root 的投票活动不需要白名单证明
+      allowlistProof: null,
+    });
+
+    // emit_cpi! 事件需要程序的 event authority PDA
+    const [eventAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("__event_authority")],
+      CONFIG.programId,
+    );
 
-    // 3. 手动定义账户列表
+    // 4. 手动定义账户列表
     const keys = [
       { pubkey: voter.publicKey, isSigner: true, isWritable: true },
       { pubkey: CONFIG.pollAccountPubkey, isSigner: false, isWritable: true },
-      {
-        pubkey: CONFIG.candidateAccountPubkey,
-        isSigner: false,
-        isWritable: true,
-      },
+      { pubkey: candidateAccountPubkey, isSigner: false, isWritable: true },
       { pubkey: voterReceiptPda, isSigner: false, isWritable: true },
+      // voter_token_account 是可选账户，未设置 gate_mint 时用程序 ID 占位
+      { pubkey: CONFIG.programId, isSigner: false, isWritable: false },
+      // voter_pass 同样是可选账户，未设置 requires_pass 时用程序 ID 占位
+      { pubkey: CONFIG.programId, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: eventAuthority, isSigner: false, isWritable: false },
+      { pubkey: CONFIG.programId, isSigner: false, isWritable: false },
     ];
 
-    // 4. 创建标准指令
+    // 5. 创建标准指令
     const instruction = new TransactionInstruction({
       keys: keys,
       programId: CONFIG.programId,
       data: Buffer.from(instructionData),
     });
 
-    // 5. 创建并发送交易
+    // 6. 创建并发送交易
     const transaction = new Transaction().add(instruction);
     console.log("\n⏳ Sending vote transaction...");
 
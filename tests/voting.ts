@@ -3,17 +3,55 @@ import { Program, BN } from "@coral-xyz/anchor";
 import { Voting } from "../target/types/voting";
 import { assert } from "chai";
 import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { createHash } from "crypto";
+import { readFileSync } from "fs";
+import {
+  ExtensionType,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  createAccount,
+  createInitializeMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  createMint,
+  getMintLen,
+  mintTo,
+  transfer,
+} from "@solana/spl-token";
+import {
+  PROGRAM_ID as TOKEN_METADATA_PROGRAM_ID,
+  createCreateMasterEditionV3Instruction,
+  createCreateMetadataAccountV3Instruction,
+  createVerifyCollectionInstruction,
+} from "@metaplex-foundation/mpl-token-metadata";
+
+type PollOptions = anchor.IdlTypes<Voting>["pollOptions"];
 
 describe("voting", () => {
   const provider = anchor.AnchorProvider.env();
   anchor.setProvider(provider);
   const program = anchor.workspace.Voting as Program<Voting>;
 
-  const pollAccount = anchor.web3.Keypair.generate();
+  // 第一个测试创建的投票活动（PDA），后续测试共用
+  let mainPoll: PublicKey;
   const authority = provider.wallet as anchor.Wallet;
   const voter1 = anchor.web3.Keypair.generate();
   const voter2 = anchor.web3.Keypair.generate();
   const unauthorizedUser = anchor.web3.Keypair.generate();
+  // 平票时最先添加的候选人获胜，可选功能默认关闭
+  const lowestIndex = { lowestIndex: {} };
+  const defaultOptions: PollOptions = {
+    tieBreak: lowestIndex,
+    allowVoteChange: false,
+    allowVoteRetraction: false,
+    gateMint: null,
+    minBalance: new BN(0),
+    weightMint: null,
+    collectionMint: null,
+    allowlistRoot: null,
+    requiresPass: false,
+    ballotType: { single: {} },
+    revealDuration: null,
+  };
 
   const confirmTx = async (txSignature: string) => {
     const latestBlockhash = await provider.connection.getLatestBlockhash();
@@ -31,6 +69,65 @@ describe("voting", () => {
     await confirmTx(sig);
   };
 
+  const sleep = (ms: number) =>
+    new Promise((resolve) => setTimeout(resolve, ms));
+
+  const getPollCounterPda = (authorityKey: PublicKey): [PublicKey, number] => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("poll_counter"), authorityKey.toBuffer()],
+      program.programId
+    );
+  };
+
+  const getPollPda = (
+    authorityKey: PublicKey,
+    pollId: BN
+  ): [PublicKey, number] => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("poll"),
+        authorityKey.toBuffer(),
+        pollId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+  };
+
+  // 下一个投票活动的 poll_id 即 authority 当前的计数，计数器不存在时为 0
+  const getNextPollId = async (): Promise<BN> => {
+    const [pollCounterPda] = getPollCounterPda(authority.publicKey);
+    const pollCounter = await program.account.pollCounter.fetchNullable(
+      pollCounterPda
+    );
+    return pollCounter ? pollCounter.pollCount : new BN(0);
+  };
+
+  // 以 PDA 创建投票活动，返回投票账户地址和交易签名
+  const createPoll = async (
+    name: string,
+    description: string,
+    startTime: BN,
+    endTime: BN,
+    options: Partial<PollOptions> = {}
+  ): Promise<[PublicKey, string]> => {
+    const pollId = await getNextPollId();
+    const [pollPda] = getPollPda(authority.publicKey, pollId);
+    const tx = await program.methods
+      .initializePoll(pollId, name, description, startTime, endTime, {
+        ...defaultOptions,
+        ...options,
+      })
+      .accounts({
+        pollAccount: pollPda,
+        pollCounter: getPollCounterPda(authority.publicKey)[0],
+        signer: authority.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+    await confirmTx(tx);
+    return [pollPda, tx];
+  };
+
   const getCandidatePda = (
     pollKey: PublicKey,
     index: number
@@ -39,13 +136,20 @@ describe("voting", () => {
       [
         Buffer.from("candidate"),
         pollKey.toBuffer(),
-        // 关键修复：合约中的 candidate_count 是 u8 (1字节)，这里必须匹配
-        new BN(index).toArrayLike(Buffer, "le", 1),
+        // 合约中的 candidate_count 是 u32 (4字节，小端序)，这里必须匹配
+        new BN(index).toArrayLike(Buffer, "le", 4),
       ],
       program.programId
     );
   };
 
+  const getPollResultPda = (pollKey: PublicKey): [PublicKey, number] => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("result"), pollKey.toBuffer()],
+      program.programId
+    );
+  };
+
   const getReceiptPda = (
     pollKey: PublicKey,
     voterKey: PublicKey
@@ -56,6 +160,72 @@ describe("voting", () => {
     );
   };
 
+  const getVoterPassPda = (
+    pollKey: PublicKey,
+    voterKey: PublicKey
+  ): [PublicKey, number] => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("pass"), pollKey.toBuffer(), voterKey.toBuffer()],
+      program.programId
+    );
+  };
+
+  const getBallotPda = (
+    pollKey: PublicKey,
+    voterKey: PublicKey
+  ): [PublicKey, number] => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("ballot"), pollKey.toBuffer(), voterKey.toBuffer()],
+      program.programId
+    );
+  };
+
+  const getContestPda = (
+    pollKey: PublicKey,
+    index: number
+  ): [PublicKey, number] => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("contest"),
+        pollKey.toBuffer(),
+        new BN(index).toArrayLike(Buffer, "le", 4),
+      ],
+      program.programId
+    );
+  };
+
+  const getNftReceiptPda = (
+    pollKey: PublicKey,
+    nftMint: PublicKey
+  ): [PublicKey, number] => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_receipt"), pollKey.toBuffer(), nftMint.toBuffer()],
+      program.programId
+    );
+  };
+
+  // emit_cpi! 的事件藏在 inner instruction 里：8 字节 EVENT_IX_TAG + 事件数据
+  const getCpiEvents = async (txSignature: string) => {
+    const tx = await provider.connection.getTransaction(txSignature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const events = [];
+    for (const inner of tx.meta.innerInstructions ?? []) {
+      for (const ix of inner.instructions) {
+        const programId =
+          tx.transaction.message.staticAccountKeys[ix.programIdIndex];
+        if (!programId.equals(program.programId)) continue;
+        const data = anchor.utils.bytes.bs58.decode(ix.data);
+        const event = program.coder.events.decode(
+          anchor.utils.bytes.base64.encode(data.subarray(8))
+        );
+        if (event) events.push(event);
+      }
+    }
+    return events;
+  };
+
   before(async () => {
     await airdrop(voter1);
     await airdrop(voter2);
@@ -68,20 +238,58 @@ describe("voting", () => {
     const startTime = new BN(Math.floor(Date.now() / 1000));
     const endTime = new BN(startTime.toNumber() + 3600);
 
+    const pollId = await getNextPollId();
+    const [pollCounterPda] = getPollCounterPda(authority.publicKey);
+    [mainPoll] = getPollPda(authority.publicKey, pollId);
+
+    // poll_id 必须等于当前计数
+    try {
+      const wrongPollId = pollId.addn(1);
+      await program.methods
+        .initializePoll(
+          wrongPollId,
+          name,
+          description,
+          startTime,
+          endTime,
+          defaultOptions
+        )
+        .accounts({
+          pollAccount: getPollPda(authority.publicKey, wrongPollId)[0],
+          pollCounter: pollCounterPda,
+          signer: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      assert.fail("Initializing with a skipped poll id should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "InvalidPollId");
+    }
+
+    // 投票账户是 PDA，无需额外的 Keypair 签名
     const tx = await program.methods
-      .initializePoll(name, description, startTime, endTime)
+      .initializePoll(
+        pollId,
+        name,
+        description,
+        startTime,
+        endTime,
+        defaultOptions
+      )
       .accounts({
-        pollAccount: pollAccount.publicKey,
+        pollAccount: mainPoll,
+        pollCounter: pollCounterPda,
         signer: authority.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
-      .signers([pollAccount])
       .rpc();
     await confirmTx(tx);
 
-    const fetchedPoll = await program.account.pollAccount.fetch(
-      pollAccount.publicKey
-    );
+    const pollCounter = await program.account.pollCounter.fetch(pollCounterPda);
+    assert.ok(pollCounter.authority.equals(authority.publicKey));
+    assert.ok(pollCounter.pollCount.eq(pollId.addn(1)));
+
+    const fetchedPoll = await program.account.pollAccount.fetch(mainPoll);
     assert.strictEqual(fetchedPoll.name, name, "Poll name does not match");
     assert.strictEqual(
       fetchedPoll.authority.toBase58(),
@@ -89,14 +297,17 @@ describe("voting", () => {
     );
     assert.ok(fetchedPoll.startTime.eq(startTime), "Start time does not match");
     assert.ok(fetchedPoll.endTime.eq(endTime), "End time does not match");
+    assert.deepEqual(fetchedPoll.options.tieBreak, lowestIndex);
+    assert.isFalse(fetchedPoll.options.allowVoteChange);
+    assert.deepEqual(fetchedPoll.status, { active: {} });
   });
 
   it("✅ Successfully adds two candidates", async () => {
-    const [candidatePda1] = getCandidatePda(pollAccount.publicKey, 0);
+    const [candidatePda1] = getCandidatePda(mainPoll, 0);
     const tx1 = await program.methods
       .addCandidate("React")
       .accounts({
-        pollAccount: pollAccount.publicKey,
+        pollAccount: mainPoll,
         candidateAccount: candidatePda1,
         signer: authority.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
@@ -104,11 +315,11 @@ describe("voting", () => {
       .rpc();
     await confirmTx(tx1);
 
-    const [candidatePda2] = getCandidatePda(pollAccount.publicKey, 1);
+    const [candidatePda2] = getCandidatePda(mainPoll, 1);
     const tx2 = await program.methods
       .addCandidate("Vue")
       .accounts({
-        pollAccount: pollAccount.publicKey,
+        pollAccount: mainPoll,
         candidateAccount: candidatePda2,
         signer: authority.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
@@ -116,14 +327,7 @@ describe("voting", () => {
       .rpc();
     await confirmTx(tx2);
 
-    const fetchedPoll = await program.account.pollAccount.fetch(
-      pollAccount.publicKey
-    );
-    assert.strictEqual(
-      fetchedPoll.candidates.length,
-      2,
-      "Candidate count should be 2"
-    );
+    const fetchedPoll = await program.account.pollAccount.fetch(mainPoll);
     assert.strictEqual(
       fetchedPoll.candidateCount,
       2,
@@ -132,19 +336,18 @@ describe("voting", () => {
   });
 
   it("✅ Two users vote successfully", async () => {
-    const [candidatePda1] = getCandidatePda(pollAccount.publicKey, 0);
-    const [candidatePda2] = getCandidatePda(pollAccount.publicKey, 1);
-    const [receiptPda1] = getReceiptPda(
-      pollAccount.publicKey,
-      voter1.publicKey
-    );
+    const [candidatePda1] = getCandidatePda(mainPoll, 0);
+    const [candidatePda2] = getCandidatePda(mainPoll, 1);
+    const [receiptPda1] = getReceiptPda(mainPoll, voter1.publicKey);
 
     const tx1 = await program.methods
-      .vote()
+      .vote(0, null)
       .accounts({
-        pollAccount: pollAccount.publicKey,
+        pollAccount: mainPoll,
         candidateAccount: candidatePda1,
         voterReceipt: receiptPda1,
+        voterTokenAccount: null,
+        voterPass: null,
         signer: voter1.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
@@ -152,16 +355,15 @@ describe("voting", () => {
       .rpc();
     await confirmTx(tx1);
 
-    const [receiptPda2] = getReceiptPda(
-      pollAccount.publicKey,
-      voter2.publicKey
-    );
+    const [receiptPda2] = getReceiptPda(mainPoll, voter2.publicKey);
     const tx2 = await program.methods
-      .vote()
+      .vote(0, null)
       .accounts({
-        pollAccount: pollAccount.publicKey,
+        pollAccount: mainPoll,
         candidateAccount: candidatePda1,
         voterReceipt: receiptPda2,
+        voterTokenAccount: null,
+        voterPass: null,
         signer: voter2.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
@@ -185,21 +387,158 @@ describe("voting", () => {
       0,
       "Vue should have 0 votes"
     );
+
+    const fetchedPoll = await program.account.pollAccount.fetch(mainPoll);
+    assert.strictEqual(
+      fetchedPoll.totalVotes.toNumber(),
+      2,
+      "Poll should count 2 votes in total"
+    );
+
+    // 回执记录了投票者的选择、时间和 slot
+    const receipt1 = await program.account.voterReceipt.fetch(receiptPda1);
+    assert.ok(receipt1.voter.equals(voter1.publicKey));
+    assert.ok(receipt1.poll.equals(mainPoll));
+    assert.ok(receipt1.candidate.equals(candidatePda1));
+    assert.isAbove(receipt1.votedAt.toNumber(), 0);
+    const voteTx = await provider.connection.getTransaction(tx1, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    assert.strictEqual(receipt1.slot.toNumber(), voteTx.slot);
+  });
+
+  it("❌ Fails to migrate a poll that already uses the current layout (expected failure)", async () => {
+    try {
+      await program.methods
+        .migratePoll()
+        .accounts({
+          signer: authority.publicKey,
+          pollAccount: mainPoll,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      assert.fail("Migrating a current poll should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "PollAlreadyMigrated");
+    }
+  });
+
+  it("✅ Migrates a legacy poll and keeps its u8 candidate seeds", async () => {
+    // 旧版布局的账户由 Anchor.toml 从 tests/fixtures 加载，authority 为固定的测试密钥
+    const legacyAuthority = anchor.web3.Keypair.fromSecretKey(
+      Uint8Array.from(
+        JSON.parse(readFileSync("tests/fixtures/legacy_authority.json", "utf8"))
+      )
+    );
+    const legacyPoll = new PublicKey(
+      "EjfpENtJutAZQ9ULGZMuqrFTh9d5CTyYp1gUpMoQfSgd"
+    );
+    const legacyCandidates = [0, 1].map(
+      (index) =>
+        PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("candidate"),
+            legacyPoll.toBuffer(),
+            Buffer.from([index]),
+          ],
+          program.programId
+        )[0]
+    );
+    await airdrop(legacyAuthority);
+
+    // 迁移时按 candidates 顺序传入全部候选人，重新统计 total_votes
+    const migrateTx = await program.methods
+      .migratePoll()
+      .accounts({
+        signer: authority.publicKey,
+        pollAccount: legacyPoll,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .remainingAccounts(
+        legacyCandidates.map((pubkey) => ({
+          pubkey,
+          isWritable: false,
+          isSigner: false,
+        }))
+      )
+      .rpc();
+    await confirmTx(migrateTx);
+    let poll = await program.account.pollAccount.fetch(legacyPoll);
+    assert.ok(poll.authority.equals(legacyAuthority.publicKey));
+    assert.strictEqual(poll.candidateCount, 2);
+    assert.strictEqual(poll.legacyCandidateCount, 2);
+    assert.strictEqual(poll.totalVotes.toNumber(), 7);
+
+    // 迁移后可以给 u8 索引的旧候选人投票
+    const voteTx = await program.methods
+      .vote(0, null)
+      .accounts({
+        pollAccount: legacyPoll,
+        candidateAccount: legacyCandidates[0],
+        voterReceipt: getReceiptPda(legacyPoll, voter1.publicKey)[0],
+        voterTokenAccount: null,
+        voterPass: null,
+        signer: voter1.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([voter1])
+      .rpc();
+    await confirmTx(voteTx);
+    const candidate = await program.account.candidateAccount.fetch(
+      legacyCandidates[0]
+    );
+    assert.strictEqual(candidate.votes.toNumber(), 4);
+
+    // 新增的候选人使用 u32 索引
+    const [newCandidate] = getCandidatePda(legacyPoll, 2);
+    const addTx = await program.methods
+      .addCandidate("Legacy 2")
+      .accounts({
+        pollAccount: legacyPoll,
+        candidateAccount: newCandidate,
+        signer: legacyAuthority.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([legacyAuthority])
+      .rpc();
+    await confirmTx(addTx);
+    poll = await program.account.pollAccount.fetch(legacyPoll);
+    assert.strictEqual(poll.candidateCount, 3);
+    assert.strictEqual(poll.totalVotes.toNumber(), 8);
+    const added = await program.account.candidateAccount.fetch(newCandidate);
+    assert.strictEqual(added.name, "Legacy 2");
+
+    // 尚未迁移、已经结束的旧投票活动，其旧版回执按旧布局的 end_time 关闭
+    const endedPoll = new PublicKey(
+      "4XX7WQTHQ6yi4eAD8x1og7MBphtuVSEFbZfKGyKZ8Dk3"
+    );
+    const [legacyReceipt] = getReceiptPda(endedPoll, legacyAuthority.publicKey);
+    const closeTx = await program.methods
+      .closeReceipt()
+      .accounts({
+        signer: legacyAuthority.publicKey,
+        pollAccount: endedPoll,
+        voterReceipt: legacyReceipt,
+      })
+      .signers([legacyAuthority])
+      .rpc();
+    await confirmTx(closeTx);
+    assert.isNull(await provider.connection.getAccountInfo(legacyReceipt));
   });
 
   it("❌ Fails to vote twice (expected failure)", async () => {
     try {
-      const [candidatePda1] = getCandidatePda(pollAccount.publicKey, 0);
-      const [receiptPda1] = getReceiptPda(
-        pollAccount.publicKey,
-        voter1.publicKey
-      );
+      const [candidatePda1] = getCandidatePda(mainPoll, 0);
+      const [receiptPda1] = getReceiptPda(mainPoll, voter1.publicKey);
       await program.methods
-        .vote()
+        .vote(0, null)
         .accounts({
-          pollAccount: pollAccount.publicKey,
+          pollAccount: mainPoll,
           candidateAccount: candidatePda1,
           voterReceipt: receiptPda1,
+          voterTokenAccount: null,
+          voterPass: null,
           signer: voter1.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
@@ -217,11 +556,11 @@ describe("voting", () => {
 
   it("❌ Unauthorized user fails to add candidate (expected failure)", async () => {
     try {
-      const [candidatePda] = getCandidatePda(pollAccount.publicKey, 2);
+      const [candidatePda] = getCandidatePda(mainPoll, 2);
       await program.methods
         .addCandidate("Svelte")
         .accounts({
-          pollAccount: pollAccount.publicKey,
+          pollAccount: mainPoll,
           candidateAccount: candidatePda,
           signer: unauthorizedUser.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
@@ -235,26 +574,16 @@ describe("voting", () => {
   });
 
   it("❌ Fails to vote before poll starts (expected failure)", async () => {
-    const futurePoll = anchor.web3.Keypair.generate();
     const startTime = new BN(Date.now() / 1000 + 3600);
     const endTime = new BN(startTime.toNumber() + 3600);
 
-    const tx1 = await program.methods
-      .initializePoll("Future", "", startTime, endTime)
-      .accounts({
-        pollAccount: futurePoll.publicKey,
-        signer: authority.publicKey,
-        systemProgram: anchor.web3.SystemProgram.programId,
-      })
-      .signers([futurePoll])
-      .rpc();
-    await confirmTx(tx1);
+    const [futurePoll] = await createPoll("Future", "", startTime, endTime);
 
-    const [candidatePda] = getCandidatePda(futurePoll.publicKey, 0);
+    const [candidatePda] = getCandidatePda(futurePoll, 0);
     const tx2 = await program.methods
       .addCandidate("Future Cand")
       .accounts({
-        pollAccount: futurePoll.publicKey,
+        pollAccount: futurePoll,
         candidateAccount: candidatePda,
         signer: authority.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
@@ -263,16 +592,15 @@ describe("voting", () => {
     await confirmTx(tx2);
 
     try {
-      const [receiptPda] = getReceiptPda(
-        futurePoll.publicKey,
-        voter1.publicKey
-      );
+      const [receiptPda] = getReceiptPda(futurePoll, voter1.publicKey);
       await program.methods
-        .vote()
+        .vote(0, null)
         .accounts({
-          pollAccount: futurePoll.publicKey,
+          pollAccount: futurePoll,
           candidateAccount: candidatePda,
           voterReceipt: receiptPda,
+          voterTokenAccount: null,
+          voterPass: null,
           signer: voter1.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
@@ -285,26 +613,17 @@ describe("voting", () => {
   });
 
   it("❌ Fails to vote after poll ends (expected failure)", async () => {
-    const pastPoll = anchor.web3.Keypair.generate();
-    const startTime = new BN(Math.floor(Date.now() / 1000) - 7200);
-    const endTime = new BN(Math.floor(Date.now() / 1000) - 3600);
+    const startTime = new BN(Math.floor(Date.now() / 1000) - 60);
+    // 结束时间不能早于当前时间，创建一个很快结束的投票活动
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 5);
 
-    const tx1 = await program.methods
-      .initializePoll("Past", "", startTime, endTime)
-      .accounts({
-        pollAccount: pastPoll.publicKey,
-        signer: authority.publicKey,
-        systemProgram: anchor.web3.SystemProgram.programId,
-      })
-      .signers([pastPoll])
-      .rpc();
-    await confirmTx(tx1);
+    const [pastPoll] = await createPoll("Past", "", startTime, endTime);
 
-    const [candidatePda] = getCandidatePda(pastPoll.publicKey, 0);
+    const [candidatePda] = getCandidatePda(pastPoll, 0);
     const tx2 = await program.methods
       .addCandidate("Past Cand")
       .accounts({
-        pollAccount: pastPoll.publicKey,
+        pollAccount: pastPoll,
         candidateAccount: candidatePda,
         signer: authority.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
@@ -312,14 +631,18 @@ describe("voting", () => {
       .rpc();
     await confirmTx(tx2);
 
+    await sleep(8000);
+
     try {
-      const [receiptPda] = getReceiptPda(pastPoll.publicKey, voter1.publicKey);
+      const [receiptPda] = getReceiptPda(pastPoll, voter1.publicKey);
       await program.methods
-        .vote()
+        .vote(0, null)
         .accounts({
-          pollAccount: pastPoll.publicKey,
+          pollAccount: pastPoll,
           candidateAccount: candidatePda,
           voterReceipt: receiptPda,
+          voterTokenAccount: null,
+          voterPass: null,
           signer: voter1.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
@@ -331,13 +654,54 @@ describe("voting", () => {
     }
   });
 
-  it("❌ Fails to add more than 15 candidates (expected failure)", async () => {
-    for (let i = 2; i < 15; i++) {
-      const [candidatePda] = getCandidatePda(pollAccount.publicKey, i);
+  it("❌ Rejects invalid poll and candidate inputs (expected failure)", async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const cases: [string, string, number, number, string][] = [
+      ["", "", now, now + 3600, "EmptyName"],
+      ["x".repeat(33), "", now, now + 3600, "NameTooLong"],
+      ["Poll", "x".repeat(281), now, now + 3600, "DescriptionTooLong"],
+      ["Poll", "", now + 3600, now + 3600, "InvalidSchedule"],
+      ["Poll", "", now - 7200, now - 3600, "InvalidSchedule"],
+    ];
+
+    for (const [name, description, startTime, endTime, code] of cases) {
+      try {
+        await createPoll(name, description, new BN(startTime), new BN(endTime));
+        assert.fail(`Initializing a poll should have failed with ${code}`);
+      } catch (err) {
+        assert.equal(err.error.errorCode.code, code);
+      }
+    }
+
+    const [candidatePda] = getCandidatePda(mainPoll, 2);
+    for (const [candidateName, code] of [
+      ["  ", "EmptyName"],
+      ["x".repeat(33), "NameTooLong"],
+    ]) {
+      try {
+        await program.methods
+          .addCandidate(candidateName)
+          .accounts({
+            pollAccount: mainPoll,
+            candidateAccount: candidatePda,
+            signer: authority.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .rpc();
+        assert.fail(`Adding a candidate should have failed with ${code}`);
+      } catch (err) {
+        assert.equal(err.error.errorCode.code, code);
+      }
+    }
+  });
+
+  it("✅ Adds more than 15 candidates", async () => {
+    for (let i = 2; i < 16; i++) {
+      const [candidatePda] = getCandidatePda(mainPoll, i);
       const tx = await program.methods
         .addCandidate(`Cand ${i}`)
         .accounts({
-          pollAccount: pollAccount.publicKey,
+          pollAccount: mainPoll,
           candidateAccount: candidatePda,
           signer: authority.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
@@ -346,20 +710,2759 @@ describe("voting", () => {
       await confirmTx(tx);
     }
 
+    const fetchedPoll = await program.account.pollAccount.fetch(mainPoll);
+    assert.strictEqual(fetchedPoll.candidateCount, 16);
+
+    // 候选人索引必须与候选人账户对应
+    const [candidatePda] = getCandidatePda(mainPoll, 15);
+    const [receiptPda] = getReceiptPda(mainPoll, unauthorizedUser.publicKey);
+    try {
+      await program.methods
+        .vote(14, null)
+        .accounts({
+          pollAccount: mainPoll,
+          candidateAccount: candidatePda,
+          voterReceipt: receiptPda,
+          voterTokenAccount: null,
+          voterPass: null,
+          signer: unauthorizedUser.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([unauthorizedUser])
+        .rpc();
+      assert.fail("Voting with a mismatched index should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "InvalidCandidateForPoll");
+    }
+
+    const tx = await program.methods
+      .vote(15, null)
+      .accounts({
+        pollAccount: mainPoll,
+        candidateAccount: candidatePda,
+        voterReceipt: receiptPda,
+        voterTokenAccount: null,
+        voterPass: null,
+        signer: unauthorizedUser.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([unauthorizedUser])
+      .rpc();
+    await confirmTx(tx);
+
+    const candidate = await program.account.candidateAccount.fetch(
+      candidatePda
+    );
+    assert.strictEqual(candidate.votes.toNumber(), 1);
+  });
+
+  it("❌ Fails to close a poll that has not ended (expected failure)", async () => {
+    const candidates = Array.from({ length: 16 }, (_, i) => ({
+      pubkey: getCandidatePda(mainPoll, i)[0],
+      isWritable: true,
+      isSigner: false,
+    }));
+
     try {
-      const [candidatePda] = getCandidatePda(pollAccount.publicKey, 15);
       await program.methods
-        .addCandidate("Cand 15")
+        .closePoll()
+        .accounts({
+          signer: authority.publicKey,
+          pollAccount: mainPoll,
+          pollResult: null,
+          vault: null,
+          tokenProgram: null,
+          weightMint: null,
+          recipientTokenAccount: null,
+          irvResult: null,
+          recipient: authority.publicKey,
+        })
+        .remainingAccounts(candidates)
+        .rpc();
+      assert.fail("Closing an active poll should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "PollNotEnded");
+    }
+  });
+
+  it("✅ Authority closes an ended poll and reclaims rent", async () => {
+    const startTime = new BN(Math.floor(Date.now() / 1000) - 60);
+    // 结束时间不能早于当前时间，创建一个很快结束的投票活动
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 5);
+
+    const [endedPoll] = await createPoll("Ended", "", startTime, endTime);
+
+    const candidatePdas: PublicKey[] = [];
+    for (let i = 0; i < 2; i++) {
+      const [candidatePda] = getCandidatePda(endedPoll, i);
+      const tx = await program.methods
+        .addCandidate(`Ended Cand ${i}`)
         .accounts({
-          pollAccount: pollAccount.publicKey,
+          pollAccount: endedPoll,
           candidateAccount: candidatePda,
           signer: authority.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
         .rpc();
-      assert.fail("Adding more than 15 candidates should have failed");
+      await confirmTx(tx);
+      candidatePdas.push(candidatePda);
+    }
+
+    await sleep(8000);
+
+    const remainingAccounts = candidatePdas.map((pubkey) => ({
+      pubkey,
+      isWritable: true,
+      isSigner: false,
+    }));
+
+    try {
+      await program.methods
+        .closePoll()
+        .accounts({
+          signer: unauthorizedUser.publicKey,
+          pollAccount: endedPoll,
+          pollResult: null,
+          vault: null,
+          tokenProgram: null,
+          weightMint: null,
+          recipientTokenAccount: null,
+          irvResult: null,
+          recipient: unauthorizedUser.publicKey,
+        })
+        .remainingAccounts(remainingAccounts)
+        .signers([unauthorizedUser])
+        .rpc();
+      assert.fail("Unauthorized close should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "Unauthorized");
+    }
+
+    try {
+      await program.methods
+        .closePoll()
+        .accounts({
+          signer: authority.publicKey,
+          pollAccount: endedPoll,
+          pollResult: null,
+          vault: null,
+          tokenProgram: null,
+          weightMint: null,
+          recipientTokenAccount: null,
+          irvResult: null,
+          recipient: authority.publicKey,
+        })
+        .remainingAccounts(remainingAccounts.slice(0, 1))
+        .rpc();
+      assert.fail("Closing without every candidate should have failed");
     } catch (err) {
-      assert.equal(err.error.errorCode.code, "MaxCandidatesReached");
+      assert.equal(err.error.errorCode.code, "CandidatesNotClosed");
+    }
+
+    // 候选人账户可以先分批关闭，剩余的在 close_poll 中一并关闭
+    const recipient = anchor.web3.Keypair.generate();
+    const tx1 = await program.methods
+      .closeCandidates()
+      .accounts({
+        signer: authority.publicKey,
+        pollAccount: endedPoll,
+        recipient: recipient.publicKey,
+      })
+      .remainingAccounts(remainingAccounts.slice(0, 1))
+      .rpc();
+    await confirmTx(tx1);
+    const fetchedPoll = await program.account.pollAccount.fetch(endedPoll);
+    assert.strictEqual(fetchedPoll.closedCandidateCount, 1);
+
+    const tx = await program.methods
+      .closePoll()
+      .accounts({
+        signer: authority.publicKey,
+        pollAccount: endedPoll,
+        pollResult: null,
+        vault: null,
+        tokenProgram: null,
+        weightMint: null,
+        recipientTokenAccount: null,
+        irvResult: null,
+        recipient: recipient.publicKey,
+      })
+      .remainingAccounts(remainingAccounts.slice(1))
+      .rpc();
+    await confirmTx(tx);
+
+    assert.isNull(
+      await provider.connection.getAccountInfo(endedPoll),
+      "Poll account should be closed"
+    );
+    for (const candidatePda of candidatePdas) {
+      assert.isNull(
+        await provider.connection.getAccountInfo(candidatePda),
+        "Candidate account should be closed"
+      );
+    }
+    assert.isAbove(
+      await provider.connection.getBalance(recipient.publicKey),
+      0,
+      "Recipient should receive the reclaimed rent"
+    );
+  });
+
+  it("✅ Voters reclaim receipt rent after the poll ends or is closed", async () => {
+    const startTime = new BN(Math.floor(Date.now() / 1000) - 60);
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 5);
+
+    const [shortPoll] = await createPoll("Short", "", startTime, endTime);
+
+    const [candidatePda] = getCandidatePda(shortPoll, 0);
+    const tx2 = await program.methods
+      .addCandidate("Short Cand")
+      .accounts({
+        pollAccount: shortPoll,
+        candidateAccount: candidatePda,
+        signer: authority.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+    await confirmTx(tx2);
+
+    const [receiptPda1] = getReceiptPda(shortPoll, voter1.publicKey);
+    const [receiptPda2] = getReceiptPda(shortPoll, voter2.publicKey);
+    for (const [voter, receiptPda] of [
+      [voter1, receiptPda1],
+      [voter2, receiptPda2],
+    ] as [anchor.web3.Keypair, PublicKey][]) {
+      const tx = await program.methods
+        .vote(0, null)
+        .accounts({
+          pollAccount: shortPoll,
+          candidateAccount: candidatePda,
+          voterReceipt: receiptPda,
+          voterTokenAccount: null,
+          voterPass: null,
+          signer: voter.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([voter])
+        .rpc();
+      await confirmTx(tx);
+    }
+
+    try {
+      await program.methods
+        .closeReceipt()
+        .accounts({
+          signer: voter2.publicKey,
+          pollAccount: shortPoll,
+          voterReceipt: receiptPda2,
+        })
+        .signers([voter2])
+        .rpc();
+      assert.fail("Closing a receipt during the poll should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "PollNotEnded");
+    }
+
+    await sleep(8000);
+
+    const tx3 = await program.methods
+      .closeReceipt()
+      .accounts({
+        signer: voter2.publicKey,
+        pollAccount: shortPoll,
+        voterReceipt: receiptPda2,
+      })
+      .signers([voter2])
+      .rpc();
+    await confirmTx(tx3);
+    assert.isNull(
+      await provider.connection.getAccountInfo(receiptPda2),
+      "Receipt should be closed"
+    );
+
+    try {
+      await program.methods
+        .vote(0, null)
+        .accounts({
+          pollAccount: shortPoll,
+          candidateAccount: candidatePda,
+          voterReceipt: receiptPda2,
+          voterTokenAccount: null,
+          voterPass: null,
+          signer: voter2.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([voter2])
+        .rpc();
+      assert.fail("Voting again after closing the receipt should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "PollEnded");
+    }
+
+    const tx4 = await program.methods
+      .closePoll()
+      .accounts({
+        signer: authority.publicKey,
+        pollAccount: shortPoll,
+        pollResult: null,
+        vault: null,
+        tokenProgram: null,
+        weightMint: null,
+        recipientTokenAccount: null,
+        irvResult: null,
+        recipient: authority.publicKey,
+      })
+      .remainingAccounts([
+        { pubkey: candidatePda, isWritable: true, isSigner: false },
+      ])
+      .rpc();
+    await confirmTx(tx4);
+
+    const tx5 = await program.methods
+      .closeReceipt()
+      .accounts({
+        signer: voter1.publicKey,
+        pollAccount: shortPoll,
+        voterReceipt: receiptPda1,
+      })
+      .signers([voter1])
+      .rpc();
+    await confirmTx(tx5);
+    assert.isNull(
+      await provider.connection.getAccountInfo(receiptPda1),
+      "Receipt should be closed after the poll is closed"
+    );
+  });
+
+  it("✅ Emits PollCreated, CandidateAdded and VoteCast events", async () => {
+    const startTime = new BN(Math.floor(Date.now() / 1000) - 60);
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 3600);
+
+    const [eventPoll, tx1] = await createPoll(
+      "Events",
+      "Event test",
+      startTime,
+      endTime
+    );
+    const [pollCreated] = await getCpiEvents(tx1);
+    assert.equal(pollCreated.name, "pollCreated");
+    assert.ok(pollCreated.data.poll.equals(eventPoll));
+    assert.ok(pollCreated.data.authority.equals(authority.publicKey));
+    assert.equal(pollCreated.data.name, "Events");
+    assert.ok(pollCreated.data.endTime.eq(endTime));
+
+    const candidates = [];
+    for (let i = 0; i < 2; i++) {
+      const [candidatePda] = getCandidatePda(eventPoll, i);
+      const tx = await program.methods
+        .addCandidate(`Event Cand ${i}`)
+        .accounts({
+          pollAccount: eventPoll,
+          candidateAccount: candidatePda,
+          signer: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      await confirmTx(tx);
+      const [candidateAdded] = await getCpiEvents(tx);
+      assert.equal(candidateAdded.name, "candidateAdded");
+      assert.ok(candidateAdded.data.candidate.equals(candidatePda));
+      assert.equal(candidateAdded.data.candidateIndex, i);
+      assert.equal(candidateAdded.data.name, `Event Cand ${i}`);
+      candidates.push(candidatePda);
+    }
+
+    let expectedVotes = 0;
+    for (const voter of [voter1, voter2]) {
+      const [receiptPda] = getReceiptPda(eventPoll, voter.publicKey);
+      const tx = await program.methods
+        .vote(1, null)
+        .accounts({
+          pollAccount: eventPoll,
+          candidateAccount: candidates[1],
+          voterReceipt: receiptPda,
+          voterTokenAccount: null,
+          voterPass: null,
+          signer: voter.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([voter])
+        .rpc();
+      await confirmTx(tx);
+      expectedVotes += 1;
+
+      const [voteCast] = await getCpiEvents(tx);
+      assert.equal(voteCast.name, "voteCast");
+      assert.ok(voteCast.data.poll.equals(eventPoll));
+      assert.ok(voteCast.data.candidate.equals(candidates[1]));
+      assert.ok(voteCast.data.voter.equals(voter.publicKey));
+      assert.equal(voteCast.data.candidateIndex, 1);
+      assert.equal(voteCast.data.votes.toNumber(), expectedVotes);
+      assert.isAbove(voteCast.data.timestamp.toNumber(), 0);
+    }
+  });
+
+  it("✅ Finalizes an ended poll and records tied winners", async () => {
+    const startTime = new BN(Math.floor(Date.now() / 1000) - 60);
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 5);
+    // 并列第一的候选人共同获胜
+    const [finalPoll] = await createPoll("Final", "", startTime, endTime, {
+      tieBreak: { shared: {} },
+    });
+
+    const candidates: PublicKey[] = [];
+    for (let i = 0; i < 3; i++) {
+      const [candidatePda] = getCandidatePda(finalPoll, i);
+      const tx = await program.methods
+        .addCandidate(`Final Cand ${i}`)
+        .accounts({
+          pollAccount: finalPoll,
+          candidateAccount: candidatePda,
+          signer: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      await confirmTx(tx);
+      candidates.push(candidatePda);
+    }
+
+    // 候选人 1 先得票，候选人 0 后得票，二者各 1 票并列
+    for (const [voter, index] of [
+      [voter1, 1],
+      [voter2, 0],
+    ] as [anchor.web3.Keypair, number][]) {
+      const [receiptPda] = getReceiptPda(finalPoll, voter.publicKey);
+      const tx = await program.methods
+        .vote(index, null)
+        .accounts({
+          pollAccount: finalPoll,
+          candidateAccount: candidates[index],
+          voterReceipt: receiptPda,
+          voterTokenAccount: null,
+          voterPass: null,
+          signer: voter.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([voter])
+        .rpc();
+      await confirmTx(tx);
+    }
+
+    const candidateMetas = candidates.map((pubkey) => ({
+      pubkey,
+      isWritable: false,
+      isSigner: false,
+    }));
+    const [pollResult] = getPollResultPda(finalPoll);
+    const finalizeAccounts = {
+      payer: authority.publicKey,
+      pollAccount: finalPoll,
+      pollResult,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    };
+
+    try {
+      await program.methods
+        .finalizePoll()
+        .accounts(finalizeAccounts)
+        .remainingAccounts(candidateMetas)
+        .rpc();
+      assert.fail("Finalizing before the poll ends should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "PollNotEnded");
+    }
+
+    await sleep(8000);
+
+    // 候选人必须从 tallied_count 开始按索引顺序传入
+    try {
+      await program.methods
+        .finalizePoll()
+        .accounts(finalizeAccounts)
+        .remainingAccounts(candidateMetas.slice(1, 2))
+        .rpc();
+      assert.fail("Finalizing out of order should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "InvalidCandidateAccounts");
+    }
+
+    // 分两批统计，第一批之后尚未结算
+    const tx1 = await program.methods
+      .finalizePoll()
+      .accounts(finalizeAccounts)
+      .remainingAccounts(candidateMetas.slice(0, 2))
+      .rpc();
+    await confirmTx(tx1);
+    let fetchedPoll = await program.account.pollAccount.fetch(finalPoll);
+    assert.deepEqual(fetchedPoll.status, { active: {} });
+    assert.strictEqual(fetchedPoll.talliedCount, 2);
+
+    try {
+      await program.methods
+        .finalizePoll()
+        .accounts(finalizeAccounts)
+        .remainingAccounts(candidateMetas)
+        .rpc();
+      assert.fail("Tallying a candidate twice should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "InvalidCandidateAccounts");
+    }
+
+    const tx2 = await program.methods
+      .finalizePoll()
+      .accounts(finalizeAccounts)
+      .remainingAccounts(candidateMetas.slice(2))
+      .rpc();
+    await confirmTx(tx2);
+
+    fetchedPoll = await program.account.pollAccount.fetch(finalPoll);
+    assert.deepEqual(fetchedPoll.status, { finalized: {} });
+    const result = await program.account.pollResult.fetch(pollResult);
+    assert.ok(result.poll.equals(finalPoll));
+    assert.deepEqual(result.winners, [0, 1]);
+    // 票数相同的候选人按添加顺序排列
+    assert.deepEqual(
+      result.rankedTallies.map((tally) => [
+        tally.candidateIndex,
+        tally.votes.toNumber(),
+      ]),
+      [
+        [0, 1],
+        [1, 1],
+        [2, 0],
+      ]
+    );
+
+    const [pollFinalized] = await getCpiEvents(tx2);
+    assert.equal(pollFinalized.name, "pollFinalized");
+    assert.ok(pollFinalized.data.poll.equals(finalPoll));
+    assert.deepEqual(pollFinalized.data.winners, [0, 1]);
+    assert.equal(pollFinalized.data.totalVotes.toNumber(), 2);
+
+    try {
+      await program.methods
+        .finalizePoll()
+        .accounts(finalizeAccounts)
+        .remainingAccounts([])
+        .rpc();
+      assert.fail("Finalizing twice should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "PollAlreadyFinalized");
+    }
+
+    const [receiptPda] = getReceiptPda(finalPoll, unauthorizedUser.publicKey);
+    try {
+      await program.methods
+        .vote(2, null)
+        .accounts({
+          pollAccount: finalPoll,
+          candidateAccount: candidates[2],
+          voterReceipt: receiptPda,
+          voterTokenAccount: null,
+          voterPass: null,
+          signer: unauthorizedUser.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([unauthorizedUser])
+        .rpc();
+      assert.fail("Voting on a finalized poll should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "PollFinalized");
+    }
+
+    // 已结算的投票活动关闭时必须一并关闭结果账户
+    const closeMetas = candidateMetas.map((meta) => ({
+      ...meta,
+      isWritable: true,
+    }));
+    try {
+      await program.methods
+        .closePoll()
+        .accounts({
+          signer: authority.publicKey,
+          pollAccount: finalPoll,
+          pollResult: null,
+          vault: null,
+          tokenProgram: null,
+          weightMint: null,
+          recipientTokenAccount: null,
+          irvResult: null,
+          recipient: authority.publicKey,
+        })
+        .remainingAccounts(closeMetas)
+        .rpc();
+      assert.fail("Closing without the result account should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "PollResultRequired");
+    }
+
+    const tx3 = await program.methods
+      .closePoll()
+      .accounts({
+        signer: authority.publicKey,
+        pollAccount: finalPoll,
+        pollResult,
+        vault: null,
+        tokenProgram: null,
+        weightMint: null,
+        recipientTokenAccount: null,
+        irvResult: null,
+        recipient: authority.publicKey,
+      })
+      .remainingAccounts(closeMetas)
+      .rpc();
+    await confirmTx(tx3);
+    assert.isNull(
+      await provider.connection.getAccountInfo(pollResult),
+      "Poll result account should be closed"
+    );
+  });
+
+  it("✅ Updates a poll before it starts", async () => {
+    const now = Math.floor(Date.now() / 1000);
+
+    const [upcomingPoll] = await createPoll(
+      "Typo Pol",
+      "Draft",
+      new BN(now + 600),
+      new BN(now + 1200)
+    );
+
+    try {
+      await program.methods
+        .updatePoll("Hijacked", null, null, null)
+        .accounts({
+          pollAccount: upcomingPoll,
+          signer: unauthorizedUser.publicKey,
+        })
+        .signers([unauthorizedUser])
+        .rpc();
+      assert.fail("Update by a non-authority should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "Unauthorized");
+    }
+
+    try {
+      await program.methods
+        .updatePoll(null, null, null, new BN(now + 300))
+        .accounts({
+          pollAccount: upcomingPoll,
+          signer: authority.publicKey,
+        })
+        .rpc();
+      assert.fail("End time before start time should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "InvalidSchedule");
+    }
+
+    try {
+      await program.methods
+        .updatePoll("x".repeat(33), null, null, null)
+        .accounts({
+          pollAccount: upcomingPoll,
+          signer: authority.publicKey,
+        })
+        .rpc();
+      assert.fail("A 33-byte name should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "NameTooLong");
+    }
+
+    const startTime = new BN(now + 900);
+    const endTime = new BN(now + 3600);
+    const tx2 = await program.methods
+      .updatePoll("Typo Poll", "Fixed description", startTime, endTime)
+      .accounts({
+        pollAccount: upcomingPoll,
+        signer: authority.publicKey,
+      })
+      .rpc();
+    await confirmTx(tx2);
+
+    const fetchedPoll = await program.account.pollAccount.fetch(upcomingPoll);
+    assert.equal(fetchedPoll.name, "Typo Poll");
+    assert.equal(fetchedPoll.description, "Fixed description");
+    assert.ok(fetchedPoll.startTime.eq(startTime));
+    assert.ok(fetchedPoll.endTime.eq(endTime));
+
+    const [pollUpdated] = await getCpiEvents(tx2);
+    assert.equal(pollUpdated.name, "pollUpdated");
+    assert.ok(pollUpdated.data.poll.equals(upcomingPoll));
+    assert.equal(pollUpdated.data.name, "Typo Poll");
+    assert.ok(pollUpdated.data.endTime.eq(endTime));
+  });
+
+  it("✅ Only extends the end time once a poll has started", async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const endTime = new BN(now + 600);
+
+    const [startedPoll] = await createPoll(
+      "Started",
+      "",
+      new BN(now - 60),
+      endTime
+    );
+
+    try {
+      await program.methods
+        .updatePoll("Renamed", null, null, null)
+        .accounts({
+          pollAccount: startedPoll,
+          signer: authority.publicKey,
+        })
+        .rpc();
+      assert.fail("Renaming a started poll should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "PollAlreadyStarted");
+    }
+
+    try {
+      await program.methods
+        .updatePoll(null, null, null, endTime.subn(60))
+        .accounts({
+          pollAccount: startedPoll,
+          signer: authority.publicKey,
+        })
+        .rpc();
+      assert.fail("Shortening a started poll should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "EndTimeNotExtended");
+    }
+
+    const extendedEndTime = endTime.addn(3600);
+    const tx2 = await program.methods
+      .updatePoll(null, null, null, extendedEndTime)
+      .accounts({
+        pollAccount: startedPoll,
+        signer: authority.publicKey,
+      })
+      .rpc();
+    await confirmTx(tx2);
+
+    const fetchedPoll = await program.account.pollAccount.fetch(startedPoll);
+    assert.equal(fetchedPoll.name, "Started");
+    assert.ok(fetchedPoll.endTime.eq(extendedEndTime));
+  });
+
+  it("✅ Changes a vote while keeping the totals unchanged", async () => {
+    const startTime = new BN(Math.floor(Date.now() / 1000) - 60);
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 3600);
+
+    const [changePoll] = await createPoll("Change", "", startTime, endTime, {
+      allowVoteChange: true,
+    });
+
+    const candidates: PublicKey[] = [];
+    for (let i = 0; i < 3; i++) {
+      const [candidatePda] = getCandidatePda(changePoll, i);
+      const tx = await program.methods
+        .addCandidate(`Change Cand ${i}`)
+        .accounts({
+          pollAccount: changePoll,
+          candidateAccount: candidatePda,
+          signer: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      await confirmTx(tx);
+      candidates.push(candidatePda);
+    }
+
+    for (const voter of [voter1, voter2]) {
+      const tx = await program.methods
+        .vote(0, null)
+        .accounts({
+          pollAccount: changePoll,
+          candidateAccount: candidates[0],
+          voterReceipt: getReceiptPda(changePoll, voter.publicKey)[0],
+          voterTokenAccount: null,
+          voterPass: null,
+          signer: voter.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([voter])
+        .rpc();
+      await confirmTx(tx);
+    }
+
+    const [receiptPda1] = getReceiptPda(changePoll, voter1.publicKey);
+    const tx = await program.methods
+      .changeVote(1)
+      .accounts({
+        signer: voter1.publicKey,
+        pollAccount: changePoll,
+        previousCandidate: candidates[0],
+        candidateAccount: candidates[1],
+        voterReceipt: receiptPda1,
+      })
+      .signers([voter1])
+      .rpc();
+    await confirmTx(tx);
+
+    const votes = [];
+    for (const candidatePda of candidates) {
+      const candidate = await program.account.candidateAccount.fetch(
+        candidatePda
+      );
+      votes.push(candidate.votes.toNumber());
+    }
+    assert.deepEqual(votes, [1, 1, 0]);
+    const fetchedPoll = await program.account.pollAccount.fetch(changePoll);
+    assert.strictEqual(fetchedPoll.totalVotes.toNumber(), 2);
+
+    const receipt = await program.account.voterReceipt.fetch(receiptPda1);
+    assert.ok(receipt.candidate.equals(candidates[1]));
+
+    const [voteChanged] = await getCpiEvents(tx);
+    assert.equal(voteChanged.name, "voteChanged");
+    assert.ok(voteChanged.data.previousCandidate.equals(candidates[0]));
+    assert.ok(voteChanged.data.candidate.equals(candidates[1]));
+    assert.equal(voteChanged.data.candidateIndex, 1);
+
+    const failures: [anchor.web3.Keypair, PublicKey, number, string][] = [
+      // 改投当前所投的候选人
+      [voter1, candidates[1], 1, "SameCandidate"],
+      // 原候选人与回执不符
+      [voter2, candidates[1], 2, "InvalidCandidateForPoll"],
+    ];
+    for (const [voter, previousCandidate, index, code] of failures) {
+      try {
+        await program.methods
+          .changeVote(index)
+          .accounts({
+            signer: voter.publicKey,
+            pollAccount: changePoll,
+            previousCandidate,
+            candidateAccount: candidates[index],
+            voterReceipt: getReceiptPda(changePoll, voter.publicKey)[0],
+          })
+          .signers([voter])
+          .rpc();
+        assert.fail(`Changing the vote should have failed with ${code}`);
+      } catch (err) {
+        assert.equal(err.error.errorCode.code, code);
+      }
+    }
+
+    // 未开启改票的投票活动不能改票
+    try {
+      await program.methods
+        .changeVote(1)
+        .accounts({
+          signer: voter1.publicKey,
+          pollAccount: mainPoll,
+          previousCandidate: getCandidatePda(mainPoll, 0)[0],
+          candidateAccount: getCandidatePda(mainPoll, 1)[0],
+          voterReceipt: getReceiptPda(mainPoll, voter1.publicKey)[0],
+        })
+        .signers([voter1])
+        .rpc();
+      assert.fail("Changing a vote should have been disabled");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "VoteChangeDisabled");
+    }
+  });
+
+  it("✅ Retracts a vote and allows voting again", async () => {
+    const startTime = new BN(Math.floor(Date.now() / 1000) - 60);
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 3600);
+
+    const [retractPoll] = await createPoll("Retract", "", startTime, endTime, {
+      allowVoteRetraction: true,
+    });
+
+    const candidates: PublicKey[] = [];
+    for (let i = 0; i < 2; i++) {
+      const [candidatePda] = getCandidatePda(retractPoll, i);
+      const tx = await program.methods
+        .addCandidate(`Retract Cand ${i}`)
+        .accounts({
+          pollAccount: retractPoll,
+          candidateAccount: candidatePda,
+          signer: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      await confirmTx(tx);
+      candidates.push(candidatePda);
+    }
+
+    const vote = async (voter: anchor.web3.Keypair, index: number) => {
+      const tx = await program.methods
+        .vote(index, null)
+        .accounts({
+          pollAccount: retractPoll,
+          candidateAccount: candidates[index],
+          voterReceipt: getReceiptPda(retractPoll, voter.publicKey)[0],
+          voterTokenAccount: null,
+          voterPass: null,
+          signer: voter.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([voter])
+        .rpc();
+      await confirmTx(tx);
+    };
+    await vote(voter1, 0);
+    await vote(voter2, 0);
+
+    // 候选人必须是回执中记录的候选人
+    try {
+      await program.methods
+        .retractVote()
+        .accounts({
+          signer: voter2.publicKey,
+          pollAccount: retractPoll,
+          candidateAccount: candidates[1],
+          voterReceipt: getReceiptPda(retractPoll, voter2.publicKey)[0],
+        })
+        .signers([voter2])
+        .rpc();
+      assert.fail("Retracting from another candidate should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "InvalidCandidateForPoll");
+    }
+
+    const [receiptPda1] = getReceiptPda(retractPoll, voter1.publicKey);
+    const tx = await program.methods
+      .retractVote()
+      .accounts({
+        signer: voter1.publicKey,
+        pollAccount: retractPoll,
+        candidateAccount: candidates[0],
+        voterReceipt: receiptPda1,
+      })
+      .signers([voter1])
+      .rpc();
+    await confirmTx(tx);
+
+    let candidate = await program.account.candidateAccount.fetch(candidates[0]);
+    assert.strictEqual(candidate.votes.toNumber(), 1);
+    let fetchedPoll = await program.account.pollAccount.fetch(retractPoll);
+    assert.strictEqual(fetchedPoll.totalVotes.toNumber(), 1);
+    assert.isNull(
+      await provider.connection.getAccountInfo(receiptPda1),
+      "Receipt should be closed after retracting"
+    );
+
+    const [voteRetracted] = await getCpiEvents(tx);
+    assert.equal(voteRetracted.name, "voteRetracted");
+    assert.ok(voteRetracted.data.candidate.equals(candidates[0]));
+    assert.ok(voteRetracted.data.voter.equals(voter1.publicKey));
+    assert.equal(voteRetracted.data.votes.toNumber(), 1);
+
+    // 撤回后可以重新投票
+    await vote(voter1, 1);
+    candidate = await program.account.candidateAccount.fetch(candidates[1]);
+    assert.strictEqual(candidate.votes.toNumber(), 1);
+    fetchedPoll = await program.account.pollAccount.fetch(retractPoll);
+    assert.strictEqual(fetchedPoll.totalVotes.toNumber(), 2);
+
+    // 未开启撤回的投票活动不能撤回投票
+    try {
+      await program.methods
+        .retractVote()
+        .accounts({
+          signer: voter1.publicKey,
+          pollAccount: mainPoll,
+          candidateAccount: getCandidatePda(mainPoll, 0)[0],
+          voterReceipt: getReceiptPda(mainPoll, voter1.publicKey)[0],
+        })
+        .signers([voter1])
+        .rpc();
+      assert.fail("Retracting a vote should have been disabled");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "VoteRetractionDisabled");
+    }
+  });
+
+  it("✅ Only lets holders of the gate token vote (Token and Token-2022)", async () => {
+    const startTime = new BN(Math.floor(Date.now() / 1000) - 60);
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 3600);
+
+    for (const tokenProgram of [TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID]) {
+      const gateMint = await createMint(
+        provider.connection,
+        authority.payer,
+        authority.publicKey,
+        null,
+        0,
+        undefined,
+        undefined,
+        tokenProgram
+      );
+      // 持有至少 100 个代币才能投票
+      const [gatedPoll] = await createPoll("Gated", "", startTime, endTime, {
+        gateMint,
+        minBalance: new BN(100),
+      });
+
+      const [candidatePda] = getCandidatePda(gatedPoll, 0);
+      const tx = await program.methods
+        .addCandidate("Gated Cand")
+        .accounts({
+          pollAccount: gatedPoll,
+          candidateAccount: candidatePda,
+          signer: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      await confirmTx(tx);
+
+      const tokenAccounts: PublicKey[] = [];
+      for (const [voter, amount] of [
+        [voter1, 150],
+        [voter2, 50],
+      ] as [anchor.web3.Keypair, number][]) {
+        const tokenAccount = await createAccount(
+          provider.connection,
+          authority.payer,
+          gateMint,
+          voter.publicKey,
+          undefined,
+          undefined,
+          tokenProgram
+        );
+        await mintTo(
+          provider.connection,
+          authority.payer,
+          gateMint,
+          tokenAccount,
+          authority.payer,
+          amount,
+          [],
+          undefined,
+          tokenProgram
+        );
+        tokenAccounts.push(tokenAccount);
+      }
+
+      const vote = (
+        voter: anchor.web3.Keypair,
+        voterTokenAccount: PublicKey | null
+      ) =>
+        program.methods
+          .vote(0, null)
+          .accounts({
+            pollAccount: gatedPoll,
+            candidateAccount: candidatePda,
+            voterReceipt: getReceiptPda(gatedPoll, voter.publicKey)[0],
+            voterTokenAccount,
+            voterPass: null,
+            signer: voter.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([voter])
+          .rpc();
+
+      const failures: [anchor.web3.Keypair, PublicKey | null, string][] = [
+        // 没有传入代币账户
+        [unauthorizedUser, null, "InsufficientTokenBalance"],
+        // 使用他人的代币账户
+        [unauthorizedUser, tokenAccounts[0], "InvalidTokenAccount"],
+        // 余额低于门槛
+        [voter2, tokenAccounts[1], "InsufficientTokenBalance"],
+      ];
+      for (const [voter, voterTokenAccount, code] of failures) {
+        try {
+          await vote(voter, voterTokenAccount);
+          assert.fail(`Voting should have failed with ${code}`);
+        } catch (err) {
+          assert.equal(err.error.errorCode.code, code);
+        }
+      }
+
+      await confirmTx(await vote(voter1, tokenAccounts[0]));
+      const candidate = await program.account.candidateAccount.fetch(
+        candidatePda
+      );
+      assert.strictEqual(candidate.votes.toNumber(), 1);
+    }
+  });
+
+  it("✅ Weights votes by escrowed tokens and returns them after the end", async () => {
+    const startTime = new BN(Math.floor(Date.now() / 1000) - 60);
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 5);
+    const weightMint = await createMint(
+      provider.connection,
+      authority.payer,
+      authority.publicKey,
+      null,
+      0
+    );
+    const [weightedPoll] = await createPoll(
+      "Weighted",
+      "",
+      startTime,
+      endTime,
+      { weightMint }
+    );
+    const [vault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), weightedPoll.toBuffer()],
+      program.programId
+    );
+
+    const [candidatePda] = getCandidatePda(weightedPoll, 0);
+    const tx = await program.methods
+      .addCandidate("Weighted Cand")
+      .accounts({
+        pollAccount: weightedPoll,
+        candidateAccount: candidatePda,
+        signer: authority.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+    await confirmTx(tx);
+
+    const balances: [anchor.web3.Keypair, PublicKey, number][] = [];
+    for (const [voter, amount] of [
+      [voter1, 150],
+      [voter2, 50],
+    ] as [anchor.web3.Keypair, number][]) {
+      const tokenAccount = await createAccount(
+        provider.connection,
+        authority.payer,
+        weightMint,
+        voter.publicKey
+      );
+      await mintTo(
+        provider.connection,
+        authority.payer,
+        weightMint,
+        tokenAccount,
+        authority.payer,
+        amount
+      );
+      balances.push([voter, tokenAccount, amount]);
+    }
+    const tokenBalance = async (tokenAccount: PublicKey) =>
+      Number(
+        (await provider.connection.getTokenAccountBalance(tokenAccount)).value
+          .amount
+      );
+
+    // 加权投票活动不接受普通投票
+    try {
+      await program.methods
+        .vote(0, null)
+        .accounts({
+          pollAccount: weightedPoll,
+          candidateAccount: candidatePda,
+          voterReceipt: getReceiptPda(weightedPoll, voter1.publicKey)[0],
+          voterTokenAccount: balances[0][1],
+          voterPass: null,
+          signer: voter1.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([voter1])
+        .rpc();
+      assert.fail("Plain voting should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "WeightedVoteRequired");
+    }
+
+    // 投票时全部余额被托管到金库，票数等于托管数量
+    for (const [voter, voterTokenAccount] of balances) {
+      const voteTx = await program.methods
+        .voteWeighted(0)
+        .accounts({
+          pollAccount: weightedPoll,
+          candidateAccount: candidatePda,
+          voterReceipt: getReceiptPda(weightedPoll, voter.publicKey)[0],
+          weightMint,
+          voterTokenAccount,
+          vault,
+          signer: voter.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([voter])
+        .rpc();
+      await confirmTx(voteTx);
+      assert.strictEqual(await tokenBalance(voterTokenAccount), 0);
+    }
+
+    const candidate = await program.account.candidateAccount.fetch(
+      candidatePda
+    );
+    assert.strictEqual(candidate.votes.toNumber(), 200);
+    const poll = await program.account.pollAccount.fetch(weightedPoll);
+    assert.strictEqual(poll.totalVotes.toNumber(), 200);
+    const receipt = await program.account.voterReceipt.fetch(
+      getReceiptPda(weightedPoll, voter1.publicKey)[0]
+    );
+    assert.strictEqual(receipt.weight.toNumber(), 150);
+    assert.strictEqual(await tokenBalance(vault), 200);
+
+    const withdraw = (
+      voter: anchor.web3.Keypair,
+      voterTokenAccount: PublicKey
+    ) =>
+      program.methods
+        .withdrawTokens()
+        .accounts({
+          pollAccount: weightedPoll,
+          voterReceipt: getReceiptPda(weightedPoll, voter.publicKey)[0],
+          weightMint,
+          voterTokenAccount,
+          vault,
+          signer: voter.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([voter])
+        .rpc();
+
+    // 投票结束前不能取回代币
+    try {
+      await withdraw(voter1, balances[0][1]);
+      assert.fail("Withdrawing before the end should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "PollNotEnded");
+    }
+
+    await sleep(8000);
+
+    // 直接转入金库的零散代币不计票，关闭时转给 recipient 的代币账户
+    const dustAccount = await createAccount(
+      provider.connection,
+      authority.payer,
+      weightMint,
+      authority.publicKey
+    );
+    await mintTo(
+      provider.connection,
+      authority.payer,
+      weightMint,
+      vault,
+      authority.payer,
+      7
+    );
+
+    // 代币未全部取回时不能关闭投票活动
+    const closePoll = () =>
+      program.methods
+        .closePoll()
+        .accounts({
+          signer: authority.publicKey,
+          pollAccount: weightedPoll,
+          pollResult: null,
+          vault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          weightMint,
+          recipientTokenAccount: dustAccount,
+          irvResult: null,
+          recipient: authority.publicKey,
+        })
+        .remainingAccounts([
+          { pubkey: candidatePda, isSigner: false, isWritable: true },
+        ])
+        .rpc();
+    try {
+      await closePoll();
+      assert.fail("Closing with escrowed tokens should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "TokensNotWithdrawn");
+    }
+
+    for (const [voter, voterTokenAccount, amount] of balances) {
+      await confirmTx(await withdraw(voter, voterTokenAccount));
+      assert.strictEqual(await tokenBalance(voterTokenAccount), amount);
+      assert.isNull(
+        await provider.connection.getAccountInfo(
+          getReceiptPda(weightedPoll, voter.publicKey)[0]
+        ),
+        "Voter receipt should be closed"
+      );
+    }
+
+    await confirmTx(await closePoll());
+    assert.isNull(
+      await provider.connection.getAccountInfo(vault),
+      "Vault should be closed"
+    );
+    assert.strictEqual(await tokenBalance(dustAccount), 7);
+  });
+
+  it("✅ Counts weighted votes after Token-2022 transfer fees", async () => {
+    const startTime = new BN(Math.floor(Date.now() / 1000) - 60);
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 5);
+
+    // 转账收取 10% 手续费的 Token-2022 mint
+    const mintKeypair = anchor.web3.Keypair.generate();
+    const weightMint = mintKeypair.publicKey;
+    const mintLen = getMintLen([ExtensionType.TransferFeeConfig]);
+    const { connection } = provider;
+    const rent = await connection.getMinimumBalanceForRentExemption(mintLen);
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.createAccount({
+          fromPubkey: authority.publicKey,
+          newAccountPubkey: weightMint,
+          space: mintLen,
+          lamports: rent,
+          programId: TOKEN_2022_PROGRAM_ID,
+        }),
+        createInitializeTransferFeeConfigInstruction(
+          weightMint,
+          authority.publicKey,
+          authority.publicKey,
+          1000,
+          BigInt(1_000_000),
+          TOKEN_2022_PROGRAM_ID
+        ),
+        createInitializeMintInstruction(
+          weightMint,
+          0,
+          authority.publicKey,
+          null,
+          TOKEN_2022_PROGRAM_ID
+        )
+      ),
+      [mintKeypair]
+    );
+
+    const [feePoll] = await createPoll("Fee", "", startTime, endTime, {
+      weightMint,
+    });
+    const [vault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), feePoll.toBuffer()],
+      program.programId
+    );
+    const [candidatePda] = getCandidatePda(feePoll, 0);
+    const addTx = await program.methods
+      .addCandidate("Fee Cand")
+      .accounts({
+        pollAccount: feePoll,
+        candidateAccount: candidatePda,
+        signer: authority.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+    await confirmTx(addTx);
+
+    const voterTokenAccount = await createAccount(
+      provider.connection,
+      authority.payer,
+      weightMint,
+      voter1.publicKey,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    await mintTo(
+      provider.connection,
+      authority.payer,
+      weightMint,
+      voterTokenAccount,
+      authority.payer,
+      100,
+      [],
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const receiptPda = getReceiptPda(feePoll, voter1.publicKey)[0];
+    const voteTx = await program.methods
+      .voteWeighted(0)
+      .accounts({
+        pollAccount: feePoll,
+        candidateAccount: candidatePda,
+        voterReceipt: receiptPda,
+        weightMint,
+        voterTokenAccount,
+        vault,
+        signer: voter1.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([voter1])
+      .rpc();
+    await confirmTx(voteTx);
+
+    // 金库只收到扣除手续费后的 90 个代币，按此计票和退还
+    const receipt = await program.account.voterReceipt.fetch(receiptPda);
+    assert.strictEqual(receipt.weight.toNumber(), 90);
+    const poll = await program.account.pollAccount.fetch(feePoll);
+    assert.strictEqual(poll.totalVotes.toNumber(), 90);
+    assert.strictEqual(poll.escrowedAmount.toNumber(), 90);
+
+    await sleep(8000);
+
+    const withdrawTx = await program.methods
+      .withdrawTokens()
+      .accounts({
+        pollAccount: feePoll,
+        voterReceipt: receiptPda,
+        weightMint,
+        voterTokenAccount,
+        vault,
+        signer: voter1.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([voter1])
+      .rpc();
+    await confirmTx(withdrawTx);
+
+    // 金库中扣留的手续费在关闭前收集到 mint
+    const closeTx = await program.methods
+      .closePoll()
+      .accounts({
+        signer: authority.publicKey,
+        pollAccount: feePoll,
+        pollResult: null,
+        vault,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        weightMint,
+        recipientTokenAccount: null,
+        irvResult: null,
+        recipient: authority.publicKey,
+      })
+      .remainingAccounts([
+        { pubkey: candidatePda, isSigner: false, isWritable: true },
+      ])
+      .rpc();
+    await confirmTx(closeTx);
+    assert.isNull(await provider.connection.getAccountInfo(vault));
+  });
+
+  it("✅ Gives each verified collection NFT one vote", async () => {
+    const startTime = new BN(Math.floor(Date.now() / 1000) - 60);
+    const metadataPda = (mint: PublicKey, ...extra: Buffer[]) =>
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("metadata"),
+          TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+          mint.toBuffer(),
+          ...extra,
+        ],
+        TOKEN_METADATA_PROGRAM_ID
+      )[0];
+    const editionPda = (mint: PublicKey) =>
+      metadataPda(mint, Buffer.from("edition"));
+
+    // 铸造供应量为 1 的 NFT，并创建元数据和 master edition
+    const createNft = async (
+      owner: PublicKey,
+      collection: PublicKey | null
+    ): Promise<[PublicKey, PublicKey]> => {
+      const mint = await createMint(
+        provider.connection,
+        authority.payer,
+        authority.publicKey,
+        authority.publicKey,
+        0
+      );
+      const tokenAccount = await createAccount(
+        provider.connection,
+        authority.payer,
+        mint,
+        owner
+      );
+      await mintTo(
+        provider.connection,
+        authority.payer,
+        mint,
+        tokenAccount,
+        authority.payer,
+        1
+      );
+      const metadataAccounts = {
+        metadata: metadataPda(mint),
+        mint,
+        mintAuthority: authority.publicKey,
+        payer: authority.publicKey,
+        updateAuthority: authority.publicKey,
+      };
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          createCreateMetadataAccountV3Instruction(metadataAccounts, {
+            createMetadataAccountArgsV3: {
+              data: {
+                name: "Voting NFT",
+                symbol: "VOTE",
+                uri: "",
+                sellerFeeBasisPoints: 0,
+                creators: null,
+                collection: collection && { verified: false, key: collection },
+                uses: null,
+              },
+              isMutable: true,
+              collectionDetails: null,
+            },
+          }),
+          createCreateMasterEditionV3Instruction(
+            { ...metadataAccounts, edition: editionPda(mint) },
+            { createMasterEditionArgs: { maxSupply: 0 } }
+          )
+        )
+      );
+      return [mint, tokenAccount];
+    };
+
+    const [collectionMint] = await createNft(authority.publicKey, null);
+    const [verifiedNft, verifiedTokenAccount] = await createNft(
+      voter1.publicKey,
+      collectionMint
+    );
+    // 只声明了集合但未经集合 authority 验证的 NFT 不能投票
+    const [unverifiedNft, unverifiedTokenAccount] = await createNft(
+      voter1.publicKey,
+      collectionMint
+    );
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        createVerifyCollectionInstruction({
+          metadata: metadataPda(verifiedNft),
+          collectionAuthority: authority.publicKey,
+          payer: authority.publicKey,
+          collectionMint,
+          collection: metadataPda(collectionMint),
+          collectionMasterEditionAccount: editionPda(collectionMint),
+        })
+      )
+    );
+
+    // NFT 回执按 NFT 记录，不能开启改票或撤回
+    const mutableVotes = [
+      { allowVoteChange: true },
+      { allowVoteRetraction: true },
+    ];
+    for (const options of mutableVotes) {
+      try {
+        await createPoll("NFT", "", startTime, startTime.addn(3600), {
+          collectionMint,
+          ...options,
+        });
+        assert.fail("NFT polls should not allow changing votes");
+      } catch (err) {
+        assert.equal(err.error.errorCode.code, "InvalidPollOptions");
+      }
+    }
+
+    // 铸造 NFT 较慢，结束时间从创建投票活动时开始计算
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 12);
+    const [nftPoll] = await createPoll("NFT", "", startTime, endTime, {
+      collectionMint,
+    });
+    const [candidatePda] = getCandidatePda(nftPoll, 0);
+    const tx = await program.methods
+      .addCandidate("NFT Cand")
+      .accounts({
+        pollAccount: nftPoll,
+        candidateAccount: candidatePda,
+        signer: authority.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+    await confirmTx(tx);
+
+    // 集合投票活动不接受普通投票
+    try {
+      await program.methods
+        .vote(0, null)
+        .accounts({
+          pollAccount: nftPoll,
+          candidateAccount: candidatePda,
+          voterReceipt: getReceiptPda(nftPoll, voter1.publicKey)[0],
+          voterTokenAccount: null,
+          voterPass: null,
+          signer: voter1.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([voter1])
+        .rpc();
+      assert.fail("Plain voting should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "NftVoteRequired");
+    }
+
+    const voteWithNft = (
+      voter: anchor.web3.Keypair,
+      nftMint: PublicKey,
+      nftTokenAccount: PublicKey
+    ) =>
+      program.methods
+        .voteWithNft(0)
+        .accounts({
+          pollAccount: nftPoll,
+          candidateAccount: candidatePda,
+          nftMint,
+          nftTokenAccount,
+          nftMetadata: metadataPda(nftMint),
+          nftReceipt: getNftReceiptPda(nftPoll, nftMint)[0],
+          signer: voter.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([voter])
+        .rpc();
+
+    try {
+      await voteWithNft(voter1, unverifiedNft, unverifiedTokenAccount);
+      assert.fail("Voting with an unverified NFT should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "NftNotInCollection");
+    }
+
+    // 同一钱包持有的每个 NFT 各投一票
+    await confirmTx(
+      await voteWithNft(voter1, verifiedNft, verifiedTokenAccount)
+    );
+    const receipt = await program.account.nftReceipt.fetch(
+      getNftReceiptPda(nftPoll, verifiedNft)[0]
+    );
+    assert.ok(receipt.voter.equals(voter1.publicKey));
+    assert.ok(receipt.candidate.equals(candidatePda));
+
+    // 投票活动结束后，只有回执记录的投票者能关闭 NFT 回执
+    const [nftReceiptPda] = getNftReceiptPda(nftPoll, verifiedNft);
+    const closeNftReceipt = (voter: anchor.web3.Keypair) =>
+      program.methods
+        .closeNftReceipt()
+        .accounts({
+          pollAccount: nftPoll,
+          nftReceipt: nftReceiptPda,
+          signer: voter.publicKey,
+        })
+        .signers([voter])
+        .rpc();
+    try {
+      await closeNftReceipt(voter1);
+      assert.fail("Closing before the poll ended should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "PollNotEnded");
+    }
+
+    // NFT 转给其他钱包后也不能再次投票
+    const voter2TokenAccount = await createAccount(
+      provider.connection,
+      authority.payer,
+      verifiedNft,
+      voter2.publicKey
+    );
+    await transfer(
+      provider.connection,
+      authority.payer,
+      verifiedTokenAccount,
+      voter2TokenAccount,
+      voter1,
+      1
+    );
+    try {
+      await voteWithNft(voter2, verifiedNft, voter2TokenAccount);
+      assert.fail("Voting twice with the same NFT should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "already in use");
+    }
+
+    const candidate = await program.account.candidateAccount.fetch(
+      candidatePda
+    );
+    assert.strictEqual(candidate.votes.toNumber(), 1);
+
+    await sleep(12000);
+    try {
+      await closeNftReceipt(voter2);
+      assert.fail("Only the recorded voter should be able to close");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "Unauthorized");
+    }
+    await confirmTx(await closeNftReceipt(voter1));
+    assert.isNull(await provider.connection.getAccountInfo(nftReceiptPda));
+  });
+
+  it("✅ Only lets allowlisted voters vote with their weight", async () => {
+    const startTime = new BN(Math.floor(Date.now() / 1000) - 60);
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 3600);
+
+    // 与程序一致：叶子 sha256(0x00 || voter || weight)，父节点对两个子节点排序后拼接
+    const sha256 = (...parts: Buffer[]) =>
+      createHash("sha256").update(Buffer.concat(parts)).digest();
+    const hashPair = (a: Buffer, b: Buffer) =>
+      Buffer.compare(a, b) <= 0
+        ? sha256(Buffer.from([1]), a, b)
+        : sha256(Buffer.from([1]), b, a);
+    const entries: [anchor.web3.Keypair, number][] = [
+      [voter1, 3],
+      [voter2, 1],
+      [authority.payer, 1],
+    ];
+    const layers = [
+      entries.map(([voter, weight]) =>
+        sha256(
+          Buffer.from([0]),
+          voter.publicKey.toBuffer(),
+          new BN(weight).toArrayLike(Buffer, "le", 8)
+        )
+      ),
+    ];
+    while (layers[layers.length - 1].length > 1) {
+      const layer = layers[layers.length - 1];
+      const next: Buffer[] = [];
+      for (let i = 0; i < layer.length; i += 2) {
+        // 奇数个节点时最后一个直接提升到上一层
+        next.push(
+          i + 1 < layer.length ? hashPair(layer[i], layer[i + 1]) : layer[i]
+        );
+      }
+      layers.push(next);
+    }
+    const proofFor = (entryIndex: number, weight = entries[entryIndex][1]) => {
+      const proof: number[][] = [];
+      let index = entryIndex;
+      for (const layer of layers.slice(0, -1)) {
+        if ((index ^ 1) < layer.length) {
+          proof.push(Array.from(layer[index ^ 1]));
+        }
+        index >>= 1;
+      }
+      return { weight: new BN(weight), proof };
+    };
+    type Proof = ReturnType<typeof proofFor> | null;
+
+    const [allowlistPoll] = await createPoll(
+      "Allowlist",
+      "",
+      startTime,
+      endTime,
+      { allowlistRoot: Array.from(layers[layers.length - 1][0]) }
+    );
+    const [candidatePda] = getCandidatePda(allowlistPoll, 0);
+    const tx = await program.methods
+      .addCandidate("Allowlist Cand")
+      .accounts({
+        pollAccount: allowlistPoll,
+        candidateAccount: candidatePda,
+        signer: authority.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+    await confirmTx(tx);
+
+    const vote = (voter: anchor.web3.Keypair, allowlistProof: Proof) =>
+      program.methods
+        .vote(0, allowlistProof)
+        .accounts({
+          pollAccount: allowlistPoll,
+          candidateAccount: candidatePda,
+          voterReceipt: getReceiptPda(allowlistPoll, voter.publicKey)[0],
+          voterTokenAccount: null,
+          voterPass: null,
+          signer: voter.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([voter])
+        .rpc();
+
+    const failures: [anchor.web3.Keypair, Proof][] = [
+      // 没有传入证明
+      [voter2, null],
+      // 虚报票数
+      [voter2, proofFor(1, 5)],
+      // 使用他人的证明
+      [unauthorizedUser, proofFor(1)],
+    ];
+    for (const [voter, allowlistProof] of failures) {
+      try {
+        await vote(voter, allowlistProof);
+        assert.fail("Voting should have failed with NotOnAllowlist");
+      } catch (err) {
+        assert.equal(err.error.errorCode.code, "NotOnAllowlist");
+      }
+    }
+
+    await confirmTx(await vote(voter1, proofFor(0)));
+    await confirmTx(await vote(voter2, proofFor(1)));
+
+    const candidate = await program.account.candidateAccount.fetch(
+      candidatePda
+    );
+    assert.strictEqual(candidate.votes.toNumber(), 4);
+    const receipt = await program.account.voterReceipt.fetch(
+      getReceiptPda(allowlistPoll, voter1.publicKey)[0]
+    );
+    assert.strictEqual(receipt.weight.toNumber(), 3);
+  });
+
+  it("✅ Requires an authority-issued voter pass to vote", async () => {
+    const startTime = new BN(Math.floor(Date.now() / 1000) - 60);
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 15);
+    const [passPoll] = await createPoll("Pass", "", startTime, endTime, {
+      requiresPass: true,
+    });
+    const [candidatePda] = getCandidatePda(passPoll, 0);
+    const tx = await program.methods
+      .addCandidate("Pass Cand")
+      .accounts({
+        pollAccount: passPoll,
+        candidateAccount: candidatePda,
+        signer: authority.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+    await confirmTx(tx);
+
+    // 单独签发一张带权重的通行证，其余通过 remaining_accounts 批量签发
+    const tx1 = await program.methods
+      .issueVoterPass(voter1.publicKey, new BN(2))
+      .accounts({
+        pollAccount: passPoll,
+        voterPass: getVoterPassPda(passPoll, voter1.publicKey)[0],
+        signer: authority.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+    await confirmTx(tx1);
+
+    // 通行证地址上已有 lamports 时只补足租金差额，仍然可以签发
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.transfer({
+          fromPubkey: authority.publicKey,
+          toPubkey: getVoterPassPda(passPoll, voter2.publicKey)[0],
+          lamports: 1_000_000,
+        })
+      )
+    );
+
+    const batch = [voter2.publicKey, unauthorizedUser.publicKey];
+    const tx2 = await program.methods
+      .issueVoterPasses(batch.map((voter) => ({ voter, weight: null })))
+      .accounts({
+        pollAccount: passPoll,
+        signer: authority.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .remainingAccounts(
+        batch.map((voter) => ({
+          pubkey: getVoterPassPda(passPoll, voter)[0],
+          isSigner: false,
+          isWritable: true,
+        }))
+      )
+      .rpc();
+    await confirmTx(tx2);
+    const pass = await program.account.voterPass.fetch(
+      getVoterPassPda(passPoll, voter2.publicKey)[0]
+    );
+    assert.strictEqual(pass.weight.toNumber(), 1);
+
+    const revoke = (voter: PublicKey) =>
+      program.methods
+        .revokeVoterPass()
+        .accounts({
+          pollAccount: passPoll,
+          voterPass: getVoterPassPda(passPoll, voter)[0],
+          signer: authority.publicKey,
+        })
+        .rpc();
+    await confirmTx(await revoke(unauthorizedUser.publicKey));
+    assert.isNull(
+      await provider.connection.getAccountInfo(
+        getVoterPassPda(passPoll, unauthorizedUser.publicKey)[0]
+      ),
+      "Revoked pass should be closed"
+    );
+
+    const vote = (voter: anchor.web3.Keypair, voterPass: PublicKey | null) =>
+      program.methods
+        .vote(0, null)
+        .accounts({
+          pollAccount: passPoll,
+          candidateAccount: candidatePda,
+          voterReceipt: getReceiptPda(passPoll, voter.publicKey)[0],
+          voterTokenAccount: null,
+          voterPass,
+          signer: voter.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([voter])
+        .rpc();
+
+    try {
+      await vote(unauthorizedUser, null);
+      assert.fail("Voting without a pass should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "VoterPassRequired");
+    }
+
+    for (const voter of [voter1, voter2]) {
+      const voterPass = getVoterPassPda(passPoll, voter.publicKey)[0];
+      await confirmTx(await vote(voter, voterPass));
+    }
+    const candidate = await program.account.candidateAccount.fetch(
+      candidatePda
+    );
+    assert.strictEqual(candidate.votes.toNumber(), 3);
+
+    // 已使用的通行证不能再撤销
+    try {
+      await revoke(voter1.publicKey);
+      assert.fail("Revoking a used pass should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "VoterPassUsed");
+    }
+
+    // 投票活动结束后，已使用的通行证也可以关闭
+    await sleep(15000);
+    await confirmTx(await revoke(voter1.publicKey));
+    assert.isNull(
+      await provider.connection.getAccountInfo(
+        getVoterPassPda(passPoll, voter1.publicKey)[0]
+      ),
+      "Used pass should be closed once the poll has ended"
+    );
+  });
+
+  it("✅ Lets approval ballots select several candidates", async () => {
+    const startTime = new BN(Math.floor(Date.now() / 1000) - 60);
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 3600);
+    const [approvalPoll] = await createPoll("Approve", "", startTime, endTime, {
+      ballotType: { approval: { maxSelections: 2 } },
+    });
+    const candidates: PublicKey[] = [];
+    for (let i = 0; i < 3; i++) {
+      const [candidatePda] = getCandidatePda(approvalPoll, i);
+      const tx = await program.methods
+        .addCandidate(`Approval ${i}`)
+        .accounts({
+          pollAccount: approvalPoll,
+          candidateAccount: candidatePda,
+          signer: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      await confirmTx(tx);
+      candidates.push(candidatePda);
+    }
+
+    // 第一个选择作为 candidate_account，其余通过 remaining_accounts 传入
+    const vote = (voter: anchor.web3.Keypair, selections: number[]) =>
+      program.methods
+        .vote(selections[0], null)
+        .accounts({
+          pollAccount: approvalPoll,
+          candidateAccount: candidates[selections[0]],
+          voterReceipt: getReceiptPda(approvalPoll, voter.publicKey)[0],
+          voterTokenAccount: null,
+          voterPass: null,
+          signer: voter.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .remainingAccounts(
+          selections.slice(1).map((index) => ({
+            pubkey: candidates[index],
+            isSigner: false,
+            isWritable: true,
+          }))
+        )
+        .signers([voter])
+        .rpc();
+
+    try {
+      await vote(voter2, [0, 1, 2]);
+      assert.fail("Selecting more than maxSelections should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "TooManySelections");
+    }
+    try {
+      await vote(voter2, [1, 1]);
+      assert.fail("Selecting a candidate twice should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "DuplicateSelection");
+    }
+
+    await confirmTx(await vote(voter1, [0, 1]));
+    await confirmTx(await vote(voter2, [2]));
+
+    const receipt = await program.account.voterReceipt.fetch(
+      getReceiptPda(approvalPoll, voter1.publicKey)[0]
+    );
+    assert.deepEqual(
+      receipt.selections.map((candidate) => candidate.toBase58()),
+      [candidates[0].toBase58(), candidates[1].toBase58()]
+    );
+    for (const candidatePda of candidates) {
+      const candidate = await program.account.candidateAccount.fetch(
+        candidatePda
+      );
+      assert.strictEqual(candidate.votes.toNumber(), 1);
+    }
+    const poll = await program.account.pollAccount.fetch(approvalPoll);
+    assert.strictEqual(poll.totalVotes.toNumber(), 3);
+  });
+
+  it("✅ Tallies ranked ballots with instant-runoff rounds", async () => {
+    const startTime = new BN(Math.floor(Date.now() / 1000) - 60);
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 10);
+    const [rankedPoll] = await createPoll("Ranked", "", startTime, endTime, {
+      ballotType: { ranked: { maxRankings: 3 } },
+    });
+    const candidates: PublicKey[] = [];
+    for (let i = 0; i < 3; i++) {
+      const [candidatePda] = getCandidatePda(rankedPoll, i);
+      const tx = await program.methods
+        .addCandidate(`Ranked ${i}`)
+        .accounts({
+          pollAccount: rankedPoll,
+          candidateAccount: candidatePda,
+          signer: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      await confirmTx(tx);
+      candidates.push(candidatePda);
+    }
+
+    const voteRanked = (voter: anchor.web3.Keypair, rankings: number[]) =>
+      program.methods
+        .voteRanked(rankings)
+        .accounts({
+          pollAccount: rankedPoll,
+          rankedBallot: getBallotPda(rankedPoll, voter.publicKey)[0],
+          voterTokenAccount: null,
+          signer: voter.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([voter])
+        .rpc();
+
+    try {
+      await voteRanked(unauthorizedUser, [2, 2]);
+      assert.fail("Ranking a candidate twice should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "DuplicateSelection");
+    }
+
+    // 第一轮三人各 1 票，候选人 2 被淘汰，其选票转给候选人 1
+    const voters: [anchor.web3.Keypair, number[]][] = [
+      [voter1, [0, 1]],
+      [voter2, [1, 0]],
+      [unauthorizedUser, [2, 1]],
+    ];
+    for (const [voter, rankings] of voters) {
+      await confirmTx(await voteRanked(voter, rankings));
+    }
+    const ballots = voters.map(([voter]) => ({
+      pubkey: getBallotPda(rankedPoll, voter.publicKey)[0],
+      isSigner: false,
+      isWritable: true,
+    }));
+
+    await sleep(10000);
+
+    // 结束后不能再添加候选人，开票时的计票表长度保持不变
+    try {
+      await program.methods
+        .addCandidate("Ranked 3")
+        .accounts({
+          pollAccount: rankedPoll,
+          candidateAccount: getCandidatePda(rankedPoll, 3)[0],
+          signer: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      assert.fail("Adding a candidate after the end should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "PollEnded");
+    }
+
+    const [irvResult] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("irv"), rankedPoll.toBuffer()],
+      program.programId
+    );
+    const tally = (batch: typeof ballots) =>
+      program.methods
+        .tallyIrv()
+        .accounts({
+          payer: authority.publicKey,
+          pollAccount: rankedPoll,
+          irvResult,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .remainingAccounts(batch)
+        .rpc();
+
+    // 选票可以分批统计，但同一轮中每张选票只能统计一次
+    await confirmTx(await tally(ballots.slice(0, 2)));
+    try {
+      await tally(ballots.slice(1, 2));
+      assert.fail("Counting a ballot twice in a round should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "BallotAlreadyCounted");
+    }
+    await confirmTx(await tally(ballots.slice(2)));
+    let result = await program.account.irvResult.fetch(irvResult);
+    assert.deepEqual(result.eliminated, [2]);
+    assert.isNull(result.winner);
+
+    await confirmTx(await tally(ballots));
+    result = await program.account.irvResult.fetch(irvResult);
+    assert.isTrue(result.complete);
+    assert.strictEqual(result.winner, 1);
+    const tallies = result.tallies.map((votes) => votes.toNumber());
+    assert.deepEqual(tallies, [1, 2, 0]);
+    const poll = await program.account.pollAccount.fetch(rankedPoll);
+    assert.deepEqual(poll.status, { finalized: {} });
+
+    // 开票完成后投票者可以关闭选票取回租金
+    const [ballotPda] = getBallotPda(rankedPoll, voter1.publicKey);
+    const closeTx = await program.methods
+      .closeBallot()
+      .accounts({
+        pollAccount: rankedPoll,
+        rankedBallot: ballotPda,
+        signer: voter1.publicKey,
+      })
+      .signers([voter1])
+      .rpc();
+    await confirmTx(closeTx);
+    assert.isNull(await provider.connection.getAccountInfo(ballotPda));
+  });
+
+  it("✅ Charges quadratic votes against the voter's credits", async () => {
+    const startTime = new BN(Math.floor(Date.now() / 1000) - 60);
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 3600);
+    const [quadPoll] = await createPoll("Quadratic", "", startTime, endTime, {
+      ballotType: { quadratic: { creditsPerVoter: new BN(10) } },
+    });
+    const candidates: PublicKey[] = [];
+    for (let i = 0; i < 2; i++) {
+      const [candidatePda] = getCandidatePda(quadPoll, i);
+      const tx = await program.methods
+        .addCandidate(`Quadratic ${i}`)
+        .accounts({
+          pollAccount: quadPoll,
+          candidateAccount: candidatePda,
+          signer: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      await confirmTx(tx);
+      candidates.push(candidatePda);
+    }
+    const [receiptPda] = getReceiptPda(quadPoll, voter1.publicKey);
+
+    try {
+      await program.methods
+        .vote(0, null)
+        .accounts({
+          pollAccount: quadPoll,
+          candidateAccount: candidates[0],
+          voterReceipt: receiptPda,
+          voterTokenAccount: null,
+          voterPass: null,
+          signer: voter1.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([voter1])
+        .rpc();
+      assert.fail("Plain votes on a quadratic poll should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "QuadraticVoteRequired");
+    }
+
+    const voteQuadratic = (index: number, votes: number) =>
+      program.methods
+        .voteQuadratic(index, new BN(votes))
+        .accounts({
+          pollAccount: quadPoll,
+          candidateAccount: candidates[index],
+          voterReceipt: receiptPda,
+          voterTokenAccount: null,
+          voterPass: null,
+          signer: voter1.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([voter1])
+        .rpc();
+
+    // 2 票花费 4 点，1 票花费 1 点，再追加 1 票时按 3² - 2² 花费 5 点
+    await confirmTx(await voteQuadratic(0, 2));
+    await confirmTx(await voteQuadratic(1, 1));
+    await confirmTx(await voteQuadratic(0, 1));
+    try {
+      await voteQuadratic(1, 1);
+      assert.fail("Spending more than the credit budget should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "InsufficientCredits");
+    }
+
+    const receipt = await program.account.voterReceipt.fetch(receiptPda);
+    assert.strictEqual(receipt.creditsSpent.toNumber(), 10);
+    const allocations = receipt.allocations.map((votes) => votes.toNumber());
+    assert.deepEqual(allocations, [3, 1]);
+    const votes = await Promise.all(
+      candidates.map(async (candidatePda) => {
+        const candidate = await program.account.candidateAccount.fetch(
+          candidatePda
+        );
+        return candidate.votes.toNumber();
+      })
+    );
+    assert.deepEqual(votes, [3, 1]);
+  });
+
+  it("✅ Counts commit-reveal votes only after they are revealed", async () => {
+    const startTime = new BN(Math.floor(Date.now() / 1000) - 60);
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 6);
+    const [secretPoll] = await createPoll("Secret", "", startTime, endTime, {
+      revealDuration: new BN(8),
+    });
+    const candidates: PublicKey[] = [];
+    for (let i = 0; i < 2; i++) {
+      const [candidatePda] = getCandidatePda(secretPoll, i);
+      const tx = await program.methods
+        .addCandidate(`Secret ${i}`)
+        .accounts({
+          pollAccount: secretPoll,
+          candidateAccount: candidatePda,
+          signer: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      await confirmTx(tx);
+      candidates.push(candidatePda);
+    }
+
+    // 承诺为 sha256(candidate_index 小端序 || salt)
+    const commitmentOf = (index: number, salt: Buffer) => {
+      const indexBytes = Buffer.alloc(4);
+      indexBytes.writeUInt32LE(index);
+      const hash = createHash("sha256").update(indexBytes).update(salt);
+      return Array.from(hash.digest());
+    };
+    const salts = [Buffer.alloc(32, 1), Buffer.alloc(32, 2)];
+
+    try {
+      await program.methods
+        .vote(0, null)
+        .accounts({
+          pollAccount: secretPoll,
+          candidateAccount: candidates[0],
+          voterReceipt: getReceiptPda(secretPoll, voter1.publicKey)[0],
+          voterTokenAccount: null,
+          voterPass: null,
+          signer: voter1.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([voter1])
+        .rpc();
+      assert.fail("Plain votes on a commit-reveal poll should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "CommitRevealRequired");
+    }
+
+    // voter1 承诺投给候选人 0，voter2 承诺投给候选人 1 但不揭示
+    for (const [voter, index] of [
+      [voter1, 0],
+      [voter2, 1],
+    ] as [anchor.web3.Keypair, number][]) {
+      const tx = await program.methods
+        .commitVote(commitmentOf(index, salts[index]), null)
+        .accounts({
+          pollAccount: secretPoll,
+          voterReceipt: getReceiptPda(secretPoll, voter.publicKey)[0],
+          voterTokenAccount: null,
+          voterPass: null,
+          signer: voter.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([voter])
+        .rpc();
+      await confirmTx(tx);
+    }
+
+    const reveal = (index: number, salt: Buffer) =>
+      program.methods
+        .revealVote(index, Array.from(salt))
+        .accounts({
+          pollAccount: secretPoll,
+          candidateAccount: candidates[index],
+          voterReceipt: getReceiptPda(secretPoll, voter1.publicKey)[0],
+          signer: voter1.publicKey,
+        })
+        .signers([voter1])
+        .rpc();
+
+    try {
+      await reveal(0, salts[0]);
+      assert.fail("Revealing before the poll ends should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "RevealWindowNotOpen");
+    }
+
+    await sleep(8000);
+
+    try {
+      await reveal(1, salts[0]);
+      assert.fail("Revealing a different candidate should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "CommitmentMismatch");
+    }
+    await confirmTx(await reveal(0, salts[0]));
+
+    const candidateMetas = candidates.map((pubkey) => ({
+      pubkey,
+      isWritable: false,
+      isSigner: false,
+    }));
+    const finalize = () =>
+      program.methods
+        .finalizePoll()
+        .accounts({
+          payer: authority.publicKey,
+          pollAccount: secretPoll,
+          pollResult: getPollResultPda(secretPoll)[0],
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .remainingAccounts(candidateMetas)
+        .rpc();
+
+    try {
+      await finalize();
+      assert.fail("Finalizing during the reveal window should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "RevealWindowOpen");
+    }
+    // 揭示期内候选人票数仍会变化，不能提前关闭候选人账户
+    try {
+      await program.methods
+        .closeCandidates()
+        .accounts({
+          signer: authority.publicKey,
+          pollAccount: secretPoll,
+          recipient: authority.publicKey,
+        })
+        .remainingAccounts(
+          candidateMetas.map((meta) => ({ ...meta, isWritable: true }))
+        )
+        .rpc();
+      assert.fail("Closing candidates during the reveal window should fail");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "RevealWindowOpen");
+    }
+
+    await sleep(8000);
+    await confirmTx(await finalize());
+
+    // 未揭示的承诺不计票
+    const poll = await program.account.pollAccount.fetch(secretPoll);
+    assert.strictEqual(poll.commitCount.toNumber(), 2);
+    assert.strictEqual(poll.revealCount.toNumber(), 1);
+    assert.strictEqual(poll.totalVotes.toNumber(), 1);
+    const result = await program.account.pollResult.fetch(
+      getPollResultPda(secretPoll)[0]
+    );
+    assert.deepEqual(result.winners, [0]);
+  });
+
+  it("✅ Records choices for several contests under one receipt", async () => {
+    const startTime = new BN(Math.floor(Date.now() / 1000) + 8);
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 3600);
+    const [boardPoll] = await createPoll("Board", "", startTime, endTime);
+
+    // 主席只能选 1 人，理事会最多选 2 人
+    const contests: [string, number, number][] = [
+      ["Chair", 1, 2],
+      ["Council", 2, 3],
+    ];
+    for (const [index, [name, maxSelections, count]] of contests.entries()) {
+      const [contestPda] = getContestPda(boardPoll, index);
+      const tx = await program.methods
+        .addContest(name, maxSelections)
+        .accounts({
+          pollAccount: boardPoll,
+          contest: contestPda,
+          signer: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      await confirmTx(tx);
+      for (let i = 0; i < count; i++) {
+        const candidateTx = await program.methods
+          .addContestCandidate(`${name} ${i}`)
+          .accounts({
+            pollAccount: boardPoll,
+            contest: contestPda,
+            candidateAccount: getCandidatePda(contestPda, i)[0],
+            signer: authority.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .rpc();
+        await confirmTx(candidateTx);
+      }
+    }
+
+    // remaining_accounts 依次为每个分项账户及其所选的候选人账户
+    const castBallot = (choices: [number, number[]][]) => {
+      const accounts = choices.flatMap(([contestIndex, candidateIndices]) => {
+        const [contestPda] = getContestPda(boardPoll, contestIndex);
+        return [
+          contestPda,
+          ...candidateIndices.map((i) => getCandidatePda(contestPda, i)[0]),
+        ];
+      });
+      const metas = accounts.map((pubkey) => ({
+        pubkey,
+        isWritable: true,
+        isSigner: false,
+      }));
+      return program.methods
+        .castBallot(
+          choices.map(([contestIndex, candidateIndices]) => ({
+            contestIndex,
+            candidateIndices,
+          })),
+          null
+        )
+        .accounts({
+          pollAccount: boardPoll,
+          voterReceipt: getReceiptPda(boardPoll, voter1.publicKey)[0],
+          voterTokenAccount: null,
+          voterPass: null,
+          signer: voter1.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .remainingAccounts(metas)
+        .signers([voter1])
+        .rpc();
+    };
+
+    await sleep(9000);
+
+    try {
+      await castBallot([[0, [0, 1]]]);
+      assert.fail("Selecting two chairs should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "TooManySelections");
+    }
+    try {
+      await castBallot([
+        [1, [0]],
+        [0, [1]],
+      ]);
+      assert.fail("Unsorted contests should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "InvalidContestChoices");
+    }
+
+    const tx = await castBallot([
+      [0, [1]],
+      [1, [0, 2]],
+    ]);
+    await confirmTx(tx);
+
+    // 一份回执记录所有分项的选择
+    const receipt = await program.account.voterReceipt.fetch(
+      getReceiptPda(boardPoll, voter1.publicKey)[0]
+    );
+    assert.strictEqual(receipt.selections.length, 3);
+    const totals = await Promise.all(
+      contests.map(async (_, index) => {
+        const contest = await program.account.contest.fetch(
+          getContestPda(boardPoll, index)[0]
+        );
+        return contest.totalVotes.toNumber();
+      })
+    );
+    assert.deepEqual(totals, [1, 2]);
+    const [councilPda] = getContestPda(boardPoll, 1);
+    const council = await program.account.candidateAccount.fetch(
+      getCandidatePda(councilPda, 2)[0]
+    );
+    assert.strictEqual(council.votes.toNumber(), 1);
+    const poll = await program.account.pollAccount.fetch(boardPoll);
+    assert.strictEqual(poll.totalVotes.toNumber(), 3);
+
+    // 分项投票按分项计票，不能用 finalize_poll 结算出空结果
+    try {
+      await program.methods
+        .finalizePoll()
+        .accounts({
+          payer: authority.publicKey,
+          pollAccount: boardPoll,
+          pollResult: getPollResultPda(boardPoll)[0],
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      assert.fail("Finalizing a contest poll should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "ContestTallyUnsupported");
+    }
+  });
+
+  it("✅ Hands the poll to a new authority in two steps", async () => {
+    const startTime = new BN(Math.floor(Date.now() / 1000) + 3600);
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 7200);
+    const [handoverPoll] = await createPoll("Handover", "", startTime, endTime);
+
+    const propose = (signer: anchor.web3.Keypair, newAuthority: PublicKey) =>
+      program.methods
+        .proposeAuthority(newAuthority)
+        .accounts({ pollAccount: handoverPoll, signer: signer.publicKey })
+        .signers([signer])
+        .rpc();
+    const accept = (signer: anchor.web3.Keypair) =>
+      program.methods
+        .acceptAuthority()
+        .accounts({ pollAccount: handoverPoll, signer: signer.publicKey })
+        .signers([signer])
+        .rpc();
+    const addCandidate = (signer: anchor.web3.Keypair, index: number) =>
+      program.methods
+        .addCandidate(`Handover ${index}`)
+        .accounts({
+          pollAccount: handoverPoll,
+          candidateAccount: getCandidatePda(handoverPoll, index)[0],
+          signer: signer.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([signer])
+        .rpc();
+
+    try {
+      await propose(unauthorizedUser, unauthorizedUser.publicKey);
+      assert.fail("Only the authority should be able to propose");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "Unauthorized");
+    }
+
+    await confirmTx(await propose(authority.payer, voter1.publicKey));
+    let poll = await program.account.pollAccount.fetch(handoverPoll);
+    assert.ok(poll.pendingAuthority.equals(voter1.publicKey));
+    // 提名之后、接受之前，原 authority 仍然有效
+    assert.ok(poll.authority.equals(authority.publicKey));
+
+    try {
+      await accept(voter2);
+      assert.fail("Only the pending authority should be able to accept");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "NotPendingAuthority");
+    }
+    await confirmTx(await accept(voter1));
+
+    poll = await program.account.pollAccount.fetch(handoverPoll);
+    assert.ok(poll.authority.equals(voter1.publicKey));
+    assert.isNull(poll.pendingAuthority);
+
+    // 管理指令改由新的 authority 签名
+    try {
+      await addCandidate(authority.payer, 0);
+      assert.fail("The previous authority should no longer add candidates");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "Unauthorized");
+    }
+    await confirmTx(await addCandidate(voter1, 0));
+  });
+
+  it("✅ Checks admin roles before candidate and schedule changes", async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const [staffPoll] = await createPoll(
+      "Staffed",
+      "",
+      new BN(now + 3600),
+      new BN(now + 7200)
+    );
+
+    const setAdmin = (
+      signer: anchor.web3.Keypair,
+      admin: PublicKey,
+      role: object | null
+    ) =>
+      program.methods
+        .setAdmin(admin, role)
+        .accounts({ pollAccount: staffPoll, signer: signer.publicKey })
+        .signers([signer])
+        .rpc();
+    const addCandidate = (signer: anchor.web3.Keypair, index: number) =>
+      program.methods
+        .addCandidate(`Staffed ${index}`)
+        .accounts({
+          pollAccount: staffPoll,
+          candidateAccount: getCandidatePda(staffPoll, index)[0],
+          signer: signer.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([signer])
+        .rpc();
+    const rename = (signer: anchor.web3.Keypair, name: string) =>
+      program.methods
+        .updatePoll(name, null, null, null)
+        .accounts({ pollAccount: staffPoll, signer: signer.publicKey })
+        .signers([signer])
+        .rpc();
+
+    const scheduleTx = await setAdmin(authority.payer, voter1.publicKey, {
+      scheduleManager: {},
+    });
+    await confirmTx(scheduleTx);
+
+    // 日程管理员可以修改投票活动，但不能添加候选人或管理管理员列表
+    await confirmTx(await rename(voter1, "Staffed 2"));
+    try {
+      await addCandidate(voter1, 0);
+      assert.fail("A schedule manager should not add candidates");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "MissingAdminRole");
+    }
+    try {
+      await setAdmin(voter1, voter2.publicKey, { owner: {} });
+      assert.fail("Only owners should manage the admin list");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "MissingAdminRole");
+    }
+
+    // 再次设置会替换原有角色
+    const candidateTx = await setAdmin(authority.payer, voter1.publicKey, {
+      candidateManager: {},
+    });
+    await confirmTx(candidateTx);
+    await confirmTx(await addCandidate(voter1, 0));
+    let poll = await program.account.pollAccount.fetch(staffPoll);
+    assert.strictEqual(poll.admins.length, 1);
+    assert.deepEqual(poll.admins[0].role, { candidateManager: {} });
+    assert.strictEqual(poll.name, "Staffed 2");
+
+    // 移除后不再是管理员
+    await confirmTx(await setAdmin(authority.payer, voter1.publicKey, null));
+    poll = await program.account.pollAccount.fetch(staffPoll);
+    assert.isEmpty(poll.admins);
+    try {
+      await addCandidate(voter1, 1);
+      assert.fail("A removed admin should not add candidates");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "Unauthorized");
+    }
+  });
+
+  it("✅ Pauses, resumes and cancels a poll", async () => {
+    const startTime = new BN(Math.floor(Date.now() / 1000) - 60);
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 3600);
+    const [pausedPoll] = await createPoll("Pausable", "", startTime, endTime);
+    const [candidatePda] = getCandidatePda(pausedPoll, 0);
+    const addTx = await program.methods
+      .addCandidate("Pausable 0")
+      .accounts({
+        pollAccount: pausedPoll,
+        candidateAccount: candidatePda,
+        signer: authority.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+    await confirmTx(addTx);
+
+    const vote = (voter: anchor.web3.Keypair) =>
+      program.methods
+        .vote(0, null)
+        .accounts({
+          pollAccount: pausedPoll,
+          candidateAccount: candidatePda,
+          voterReceipt: getReceiptPda(pausedPoll, voter.publicKey)[0],
+          voterTokenAccount: null,
+          voterPass: null,
+          signer: voter.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([voter])
+        .rpc();
+    const statusAccounts = {
+      pollAccount: pausedPoll,
+      signer: authority.publicKey,
+    };
+
+    try {
+      await program.methods
+        .pausePoll()
+        .accounts({ pollAccount: pausedPoll, signer: voter1.publicKey })
+        .signers([voter1])
+        .rpc();
+      assert.fail("Only the authority should be able to pause");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "Unauthorized");
+    }
+
+    // 暂停期间拒绝投票，恢复后可以继续投票
+    const pauseTx = await program.methods
+      .pausePoll()
+      .accounts(statusAccounts)
+      .rpc();
+    await confirmTx(pauseTx);
+    try {
+      await vote(voter1);
+      assert.fail("Voting on a paused poll should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "PollPaused");
+    }
+    const resumeTx = await program.methods
+      .resumePoll()
+      .accounts(statusAccounts)
+      .rpc();
+    await confirmTx(resumeTx);
+    await confirmTx(await vote(voter1));
+
+    // 取消后拒绝投票，且不必等到结束就能关闭
+    const cancelTx = await program.methods
+      .cancelPoll()
+      .accounts(statusAccounts)
+      .rpc();
+    await confirmTx(cancelTx);
+    try {
+      await vote(voter2);
+      assert.fail("Voting on a cancelled poll should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "PollCancelled");
+    }
+    try {
+      await program.methods.resumePoll().accounts(statusAccounts).rpc();
+      assert.fail("Resuming a cancelled poll should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "PollNotPaused");
+    }
+
+    const closeTx = await program.methods
+      .closePoll()
+      .accounts({
+        pollAccount: pausedPoll,
+        pollResult: null,
+        recipient: authority.publicKey,
+        vault: null,
+        tokenProgram: null,
+        weightMint: null,
+        recipientTokenAccount: null,
+        irvResult: null,
+        signer: authority.publicKey,
+      })
+      .remainingAccounts([
+        { pubkey: candidatePda, isWritable: true, isSigner: false },
+      ])
+      .rpc();
+    await confirmTx(closeTx);
+    assert.isNull(await provider.connection.getAccountInfo(pausedPoll));
+  });
+
+  it("✅ Refuses to pause or cancel a poll after it ends", async () => {
+    const startTime = new BN(Math.floor(Date.now() / 1000) - 60);
+    const endTime = new BN(Math.floor(Date.now() / 1000) + 2);
+    const [endedPoll] = await createPoll("Ended", "", startTime, endTime);
+    const statusAccounts = {
+      pollAccount: endedPoll,
+      signer: authority.publicKey,
+    };
+
+    // 结束后暂停会卡住揭示，取消会让已结束的投票无法结算
+    await sleep(4000);
+    try {
+      await program.methods.cancelPoll().accounts(statusAccounts).rpc();
+      assert.fail("Cancelling an ended poll should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "PollEnded");
+    }
+    try {
+      await program.methods.pausePoll().accounts(statusAccounts).rpc();
+      assert.fail("Pausing an ended poll should have failed");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "PollEnded");
     }
+    const poll = await program.account.pollAccount.fetch(endedPoll);
+    assert.deepEqual(poll.status, { active: {} });
   });
 });
@@ -46,14 +46,14 @@ def insert_poll(conn, poll_data):
                 ON CONFLICT (id) DO NOTHING
             """,
                 (
-                    poll_data.get("acctPollAccount"),  # 使用 poll account 作为 ID
+                    poll_data.get("poll"),  # 使用 poll account 作为 ID
                     poll_data.get("name"),
                     poll_data.get("description"),
                     poll_data.get("startTime"),
                     poll_data.get("endTime"),
-                    poll_data.get("acctSigner"),
-                    poll_data.get("acctPollAccount"),
-                    poll_data.get("blockNumber", 0),
+                    poll_data.get("authority"),
+                    poll_data.get("poll"),
+                    poll_data.get("timestamp", 0),
                     poll_data.get("blockNumber", 0),
                     poll_data.get("trxHash"),
                 ),
@@ -70,53 +70,154 @@ def insert_candidate(conn, candidate_data):
         with conn.cursor() as cur:
             cur.execute(
                 """
-                INSERT INTO candidates (id, name, poll_id, created_at, block_number, transaction_hash)
-                VALUES (%s, %s, %s, %s, %s, %s)
+                INSERT INTO candidates (id, name, poll_id, candidate_index, contest, created_at,
+                                      block_number, transaction_hash)
+                VALUES (%s, %s, %s, %s, %s, %s, %s, %s)
                 ON CONFLICT (id) DO NOTHING
             """,
                 (
-                    candidate_data.get(
-                        "acctCandidateAccount"
-                    ),  # 使用 candidate account 作为 ID
-                    candidate_data.get("candidateName"),
-                    candidate_data.get("acctPollAccount"),
-                    candidate_data.get("blockNumber", 0),
+                    candidate_data.get("candidate"),  # 使用 candidate account 作为 ID
+                    candidate_data.get("name"),
+                    candidate_data.get("poll"),
+                    candidate_data.get("candidateIndex", 0),
+                    # 分项候选人记录所属的分项，普通候选人为空
+                    candidate_data.get("contest"),
+                    candidate_data.get("timestamp", 0),
                     candidate_data.get("blockNumber", 0),
                     candidate_data.get("trxHash"),
                 ),
             )
             conn.commit()
-            logger.info(f"插入候选人: {candidate_data.get('candidateName')}")
+            logger.info(f"插入候选人: {candidate_data.get('name')}")
     except Exception as e:
         logger.error(f"插入候选人失败: {e}")
 
 
-def insert_vote(conn, vote_data):
-    """插入投票数据"""
+def insert_vote_row(
+    conn,
+    row_id,
+    vote_data,
+    candidate,
+    candidate_index=None,
+    candidate_votes=None,
+    weight=None,
+):
+    """插入一行投票记录，row_id 由交易、事件位置和候选人序号组成，重复处理同一区块时不会重复插入"""
     try:
         with conn.cursor() as cur:
             cur.execute(
                 """
-                INSERT INTO votes (id, voter, poll_id, candidate_id, created_at, block_number, transaction_hash)
-                VALUES (%s, %s, %s, %s, %s, %s, %s)
+                INSERT INTO votes (id, voter, poll_id, candidate_id, candidate_index, candidate_votes,
+                                 weight, created_at, block_number, transaction_hash)
+                VALUES (%s, %s, %s, %s, %s, %s, %s, %s, %s, %s)
                 ON CONFLICT (id) DO NOTHING
             """,
                 (
-                    vote_data.get("acctVoterReceipt"),  # 使用 voter receipt 作为 ID
-                    vote_data.get("acctSigner"),
-                    vote_data.get("acctPollAccount"),
-                    vote_data.get("acctCandidateAccount"),
-                    vote_data.get("blockNumber", 0),
+                    row_id,
+                    vote_data.get("voter"),
+                    vote_data.get("poll"),
+                    candidate,
+                    candidate_index,
+                    candidate_votes,
+                    weight,
+                    vote_data.get("timestamp", 0),
                     vote_data.get("blockNumber", 0),
                     vote_data.get("trxHash"),
                 ),
             )
             conn.commit()
+            logger.info(f"插入投票: {vote_data.get('voter')} -> {candidate}")
+    except Exception as e:
+        logger.error(f"插入投票失败: {e}")
+
+
+def vote_row_id(vote_data, position):
+    """同一投票者可以有多条投票（多个 NFT、多次二次方投票），因此按事件而不是按投票者生成 ID"""
+    return f"{vote_data.get('trxHash')}:{vote_data.get('eventIndex', 0)}:{position}"
+
+
+def insert_vote(conn, vote_data):
+    """插入 VoteCast 事件对应的投票"""
+    insert_vote_row(
+        conn,
+        vote_row_id(vote_data, 0),
+        vote_data,
+        vote_data.get("candidate"),
+        candidate_index=vote_data.get("candidateIndex", 0),
+        candidate_votes=vote_data.get("votes"),
+    )
+
+
+def insert_approval_vote(conn, vote_data):
+    """插入复选选票的其余候选人，第一个候选人已由同一指令的 VoteCast 事件记录"""
+    candidates = vote_data.get("candidates", [])
+    for position, candidate in enumerate(candidates[1:], start=1):
+        insert_vote_row(
+            conn,
+            vote_row_id(vote_data, position),
+            vote_data,
+            candidate,
+            weight=vote_data.get("weight"),
+        )
+
+
+def insert_ballot(conn, ballot_data):
+    """插入多分项选票中每个分项所选的候选人"""
+    for position, candidate in enumerate(ballot_data.get("candidates", [])):
+        insert_vote_row(
+            conn,
+            vote_row_id(ballot_data, position),
+            ballot_data,
+            candidate,
+            weight=ballot_data.get("weight"),
+        )
+
+
+def change_vote(conn, change_data):
+    """改票：把投票者原先投给 previousCandidate 的记录改为新的候选人"""
+    try:
+        with conn.cursor() as cur:
+            cur.execute(
+                """
+                UPDATE votes SET candidate_id = %s, candidate_index = %s, candidate_votes = NULL
+                WHERE poll_id = %s AND voter = %s AND candidate_id = %s
+            """,
+                (
+                    change_data.get("candidate"),
+                    change_data.get("candidateIndex", 0),
+                    change_data.get("poll"),
+                    change_data.get("voter"),
+                    change_data.get("previousCandidate"),
+                ),
+            )
+            conn.commit()
             logger.info(
-                f"插入投票: {vote_data.get('acctSigner')} -> {vote_data.get('acctCandidateAccount')}"
+                f"改票: {change_data.get('voter')} -> {change_data.get('candidate')}"
             )
     except Exception as e:
-        logger.error(f"插入投票失败: {e}")
+        logger.error(f"改票失败: {e}")
+
+
+def retract_vote(conn, retract_data):
+    """撤回投票：删除投票者投给该候选人的记录"""
+    try:
+        with conn.cursor() as cur:
+            cur.execute(
+                """
+                DELETE FROM votes WHERE poll_id = %s AND voter = %s AND candidate_id = %s
+            """,
+                (
+                    retract_data.get("poll"),
+                    retract_data.get("voter"),
+                    retract_data.get("candidate"),
+                ),
+            )
+            conn.commit()
+            logger.info(
+                f"撤回投票: {retract_data.get('voter')} -> {retract_data.get('candidate')}"
+            )
+    except Exception as e:
+        logger.error(f"撤回投票失败: {e}")
 
 
 def process_substreams_data():
@@ -139,20 +240,41 @@ def process_substreams_data():
                     data = data["@data"]
 
                 # 处理投票数据
-                if "initializePollInstructionList" in data:
-                    for poll in data["initializePollInstructionList"]:
+                if "pollCreatedEventList" in data:
+                    for poll in data["pollCreatedEventList"]:
                         insert_poll(conn, poll)
 
                 # 处理候选数据
-                if "addCandidateInstructionList" in data:
-                    for candidate in data["addCandidateInstructionList"]:
+                if "candidateAddedEventList" in data:
+                    for candidate in data["candidateAddedEventList"]:
                         insert_candidate(conn, candidate)
 
-                # 处理投票数据
-                if "voteInstructionList" in data:
-                    for vote in data["voteInstructionList"]:
+                # 处理分项候选人数据
+                if "contestCandidateAddedEventList" in data:
+                    for candidate in data["contestCandidateAddedEventList"]:
+                        insert_candidate(conn, candidate)
+
+                # 处理投票数据，改票和撤回在新投票之后处理
+                if "voteCastEventList" in data:
+                    for vote in data["voteCastEventList"]:
                         insert_vote(conn, vote)
 
+                if "approvalVoteCastEventList" in data:
+                    for vote in data["approvalVoteCastEventList"]:
+                        insert_approval_vote(conn, vote)
+
+                if "ballotCastEventList" in data:
+                    for ballot in data["ballotCastEventList"]:
+                        insert_ballot(conn, ballot)
+
+                if "voteChangedEventList" in data:
+                    for change in data["voteChangedEventList"]:
+                        change_vote(conn, change)
+
+                if "voteRetractedEventList" in data:
+                    for retract in data["voteRetractedEventList"]:
+                        retract_vote(conn, retract)
+
             except json.JSONDecodeError as e:
                 logger.warning(f"JSON 解析失败: {e}")
                 continue
@@ -20,6 +20,9 @@ CREATE TABLE IF NOT EXISTS candidates (
     id VARCHAR PRIMARY KEY,
     name VARCHAR NOT NULL,
     poll_id VARCHAR REFERENCES polls(id),
+    candidate_index INTEGER,
+    -- 分项候选人所属的分项账户，普通候选人为空
+    contest VARCHAR,
     created_at BIGINT,
     block_number BIGINT,
     transaction_hash VARCHAR,
@@ -27,17 +30,31 @@ CREATE TABLE IF NOT EXISTS candidates (
 );
 
 -- 投票表
+-- 每行是一次投票选中的一个候选人，id 为 交易:事件位置:候选人序号；
+-- 同一投票者可以有多行（复选和分项选票、多个 NFT、多次二次方投票）
 CREATE TABLE IF NOT EXISTS votes (
     id VARCHAR PRIMARY KEY,
     voter VARCHAR,
     poll_id VARCHAR REFERENCES polls(id),
     candidate_id VARCHAR REFERENCES candidates(id),
+    candidate_index INTEGER,
+    -- 本次投票后候选人的最新票数（来自 VoteCast 事件）
+    candidate_votes BIGINT,
+    -- 复选和分项选票计入的票数（来自 ApprovalVoteCast、BallotCast 事件）
+    weight BIGINT,
     created_at BIGINT,
     block_number BIGINT,
     transaction_hash VARCHAR,
     created_timestamp TIMESTAMP DEFAULT CURRENT_TIMESTAMP
 );
 
+-- 兼容旧库：事件里新增的字段
+ALTER TABLE candidates ADD COLUMN IF NOT EXISTS candidate_index INTEGER;
+ALTER TABLE votes ADD COLUMN IF NOT EXISTS candidate_index INTEGER;
+ALTER TABLE votes ADD COLUMN IF NOT EXISTS candidate_votes BIGINT;
+ALTER TABLE candidates ADD COLUMN IF NOT EXISTS contest VARCHAR;
+ALTER TABLE votes ADD COLUMN IF NOT EXISTS weight BIGINT;
+
 -- 创建索引
 CREATE INDEX IF NOT EXISTS idx_polls_creator ON polls(creator);
 CREATE INDEX IF NOT EXISTS idx_polls_created_at ON polls(created_at);
@@ -4,10 +4,15 @@ mod pb;
 use anchor_lang::AnchorDeserialize;
 use anchor_lang::Discriminator;
 use base64::prelude::*;
-use pb::substreams::v1::program::AddCandidateInstruction;
+use pb::substreams::v1::program::ApprovalVoteCastEvent;
+use pb::substreams::v1::program::BallotCastEvent;
+use pb::substreams::v1::program::CandidateAddedEvent;
+use pb::substreams::v1::program::ContestCandidateAddedEvent;
 use pb::substreams::v1::program::Data;
-use pb::substreams::v1::program::InitializePollInstruction;
-use pb::substreams::v1::program::VoteInstruction;
+use pb::substreams::v1::program::PollCreatedEvent;
+use pb::substreams::v1::program::VoteCastEvent;
+use pb::substreams::v1::program::VoteChangedEvent;
+use pb::substreams::v1::program::VoteRetractedEvent;
 
 use sologger_log_context::programs_selector::ProgramsSelector;
 use sologger_log_context::sologger_log_context::LogContext;
@@ -17,69 +22,155 @@ const PROGRAM_ID: &str = "Doo2arLUifZbfqGVS5Uh7nexAMmsMzaQH5zcwZhSoijz";
 
 #[substreams::handlers::map]
 fn map_program_data(blk: Block) -> Data {
-    let mut add_candidate_instruction_list: Vec<AddCandidateInstruction> = Vec::new();
-    let mut initialize_poll_instruction_list: Vec<InitializePollInstruction> = Vec::new();
-    let mut vote_instruction_list: Vec<VoteInstruction> = Vec::new();
+    let mut poll_created_event_list: Vec<PollCreatedEvent> = Vec::new();
+    let mut candidate_added_event_list: Vec<CandidateAddedEvent> = Vec::new();
+    let mut vote_cast_event_list: Vec<VoteCastEvent> = Vec::new();
+    let mut approval_vote_cast_event_list: Vec<ApprovalVoteCastEvent> = Vec::new();
+    let mut ballot_cast_event_list: Vec<BallotCastEvent> = Vec::new();
+    let mut vote_changed_event_list: Vec<VoteChangedEvent> = Vec::new();
+    let mut vote_retracted_event_list: Vec<VoteRetractedEvent> = Vec::new();
+    let mut contest_candidate_added_event_list: Vec<ContestCandidateAddedEvent> = Vec::new();
 
     blk.transactions().for_each(|transaction| {
-        // ------------- INSTRUCTIONS -------------
+        // ------------- EVENTS -------------
         transaction
             .walk_instructions()
             .into_iter()
-            .filter(|inst| inst.program_id().to_string() == PROGRAM_ID)
-            .for_each(|inst| {
+            // 事件在交易中的位置，用于区分同一投票者在同一交易中的多次投票
+            .enumerate()
+            .filter(|(_, inst)| inst.program_id().to_string() == PROGRAM_ID)
+            .for_each(|(event_index, inst)| {
                 let slice_u8: &[u8] = &inst.data()[..];
 
                 /*
-                    CPI events are contained inside the instruction data
+                    CPI events are contained inside the instruction data:
+                    EVENT_IX_TAG (8 bytes) + event discriminator (8 bytes) + borsh payload
                 */
-                if slice_u8.len() >= 16 {}
-                if &slice_u8[0..8] == idl::idl::program::client::args::AddCandidate::DISCRIMINATOR {
-                    if let Ok(instruction) =
-                        idl::idl::program::client::args::AddCandidate::deserialize(
-                            &mut &slice_u8[8..],
-                        )
+                if slice_u8.len() < 16 || &slice_u8[0..8] != anchor_lang::event::EVENT_IX_TAG_LE {
+                    return;
+                }
+                let event_data = &slice_u8[8..];
+
+                if &event_data[0..8] == idl::idl::program::events::PollCreated::DISCRIMINATOR {
+                    if let Ok(event) =
+                        idl::idl::program::events::PollCreated::deserialize(&mut &event_data[8..])
                     {
-                        let accts = inst.accounts();
-                        add_candidate_instruction_list.push(AddCandidateInstruction {
+                        poll_created_event_list.push(PollCreatedEvent {
                             trx_hash: transaction.id(),
-                            candidate_name: instruction.candidate_name,
-                            acct_signer: accts[0].to_string(),
-                            acct_poll_account: accts[1].to_string(),
-                            acct_candidate_account: accts[2].to_string(),
+                            poll: event.poll.to_string(),
+                            authority: event.authority.to_string(),
+                            name: event.name,
+                            description: event.description,
+                            start_time: event.start_time,
+                            end_time: event.end_time,
+                            timestamp: event.timestamp,
                         });
                     }
                 }
-                if &slice_u8[0..8] == idl::idl::program::client::args::InitializePoll::DISCRIMINATOR
-                {
-                    if let Ok(instruction) =
-                        idl::idl::program::client::args::InitializePoll::deserialize(
-                            &mut &slice_u8[8..],
-                        )
+                if &event_data[0..8] == idl::idl::program::events::CandidateAdded::DISCRIMINATOR {
+                    if let Ok(event) = idl::idl::program::events::CandidateAdded::deserialize(
+                        &mut &event_data[8..],
+                    ) {
+                        candidate_added_event_list.push(CandidateAddedEvent {
+                            trx_hash: transaction.id(),
+                            poll: event.poll.to_string(),
+                            candidate: event.candidate.to_string(),
+                            candidate_index: event.candidate_index,
+                            name: event.name,
+                            timestamp: event.timestamp,
+                        });
+                    }
+                }
+                if &event_data[0..8] == idl::idl::program::events::VoteCast::DISCRIMINATOR {
+                    if let Ok(event) =
+                        idl::idl::program::events::VoteCast::deserialize(&mut &event_data[8..])
+                    {
+                        vote_cast_event_list.push(VoteCastEvent {
+                            trx_hash: transaction.id(),
+                            poll: event.poll.to_string(),
+                            candidate: event.candidate.to_string(),
+                            candidate_index: event.candidate_index,
+                            voter: event.voter.to_string(),
+                            votes: event.votes,
+                            timestamp: event.timestamp,
+                            event_index: event_index as u32,
+                        });
+                    }
+                }
+                if &event_data[0..8] == idl::idl::program::events::ApprovalVoteCast::DISCRIMINATOR {
+                    if let Ok(event) = idl::idl::program::events::ApprovalVoteCast::deserialize(
+                        &mut &event_data[8..],
+                    ) {
+                        approval_vote_cast_event_list.push(ApprovalVoteCastEvent {
+                            trx_hash: transaction.id(),
+                            poll: event.poll.to_string(),
+                            voter: event.voter.to_string(),
+                            candidates: event.candidates.iter().map(|c| c.to_string()).collect(),
+                            weight: event.weight,
+                            timestamp: event.timestamp,
+                            event_index: event_index as u32,
+                        });
+                    }
+                }
+                if &event_data[0..8] == idl::idl::program::events::BallotCast::DISCRIMINATOR {
+                    if let Ok(event) =
+                        idl::idl::program::events::BallotCast::deserialize(&mut &event_data[8..])
                     {
-                        let accts = inst.accounts();
-                        initialize_poll_instruction_list.push(InitializePollInstruction {
+                        ballot_cast_event_list.push(BallotCastEvent {
                             trx_hash: transaction.id(),
-                            name: instruction.name,
-                            description: instruction.description,
-                            start_time: instruction.start_time,
-                            end_time: instruction.end_time,
-                            acct_signer: accts[0].to_string(),
-                            acct_poll_account: accts[1].to_string(),
+                            poll: event.poll.to_string(),
+                            voter: event.voter.to_string(),
+                            contests: event.contests,
+                            candidates: event.candidates.iter().map(|c| c.to_string()).collect(),
+                            weight: event.weight,
+                            timestamp: event.timestamp,
+                            event_index: event_index as u32,
                         });
                     }
                 }
-                if &slice_u8[0..8] == idl::idl::program::client::args::Vote::DISCRIMINATOR {
-                    if let Ok(instruction) =
-                        idl::idl::program::client::args::Vote::deserialize(&mut &slice_u8[8..])
+                if &event_data[0..8] == idl::idl::program::events::VoteChanged::DISCRIMINATOR {
+                    if let Ok(event) =
+                        idl::idl::program::events::VoteChanged::deserialize(&mut &event_data[8..])
                     {
-                        let accts = inst.accounts();
-                        vote_instruction_list.push(VoteInstruction {
+                        vote_changed_event_list.push(VoteChangedEvent {
+                            trx_hash: transaction.id(),
+                            poll: event.poll.to_string(),
+                            previous_candidate: event.previous_candidate.to_string(),
+                            candidate: event.candidate.to_string(),
+                            candidate_index: event.candidate_index,
+                            voter: event.voter.to_string(),
+                            timestamp: event.timestamp,
+                        });
+                    }
+                }
+                if &event_data[0..8] == idl::idl::program::events::VoteRetracted::DISCRIMINATOR {
+                    if let Ok(event) =
+                        idl::idl::program::events::VoteRetracted::deserialize(&mut &event_data[8..])
+                    {
+                        vote_retracted_event_list.push(VoteRetractedEvent {
+                            trx_hash: transaction.id(),
+                            poll: event.poll.to_string(),
+                            candidate: event.candidate.to_string(),
+                            voter: event.voter.to_string(),
+                            votes: event.votes,
+                            timestamp: event.timestamp,
+                        });
+                    }
+                }
+                if &event_data[0..8]
+                    == idl::idl::program::events::ContestCandidateAdded::DISCRIMINATOR
+                {
+                    if let Ok(event) = idl::idl::program::events::ContestCandidateAdded::deserialize(
+                        &mut &event_data[8..],
+                    ) {
+                        contest_candidate_added_event_list.push(ContestCandidateAddedEvent {
                             trx_hash: transaction.id(),
-                            acct_signer: accts[0].to_string(),
-                            acct_poll_account: accts[1].to_string(),
-                            acct_candidate_account: accts[2].to_string(),
-                            acct_voter_receipt: accts[3].to_string(),
+                            poll: event.poll.to_string(),
+                            contest: event.contest.to_string(),
+                            candidate: event.candidate.to_string(),
+                            candidate_index: event.candidate_index,
+                            name: event.name,
+                            timestamp: event.timestamp,
                         });
                     }
                 }
@@ -87,8 +178,13 @@ fn map_program_data(blk: Block) -> Data {
     });
 
     Data {
-        add_candidate_instruction_list,
-        initialize_poll_instruction_list,
-        vote_instruction_list,
+        poll_created_event_list,
+        candidate_added_event_list,
+        vote_cast_event_list,
+        approval_vote_cast_event_list,
+        ballot_cast_event_list,
+        vote_changed_event_list,
+        vote_retracted_event_list,
+        contest_candidate_added_event_list,
     }
 }
@@ -2,57 +2,169 @@
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Data {
-    #[prost(message, repeated, tag="1")]
-    pub add_candidate_instruction_list: ::prost::alloc::vec::Vec<AddCandidateInstruction>,
-    #[prost(message, repeated, tag="2")]
-    pub initialize_poll_instruction_list: ::prost::alloc::vec::Vec<InitializePollInstruction>,
-    #[prost(message, repeated, tag="3")]
-    pub vote_instruction_list: ::prost::alloc::vec::Vec<VoteInstruction>,
+    #[prost(message, repeated, tag="4")]
+    pub poll_created_event_list: ::prost::alloc::vec::Vec<PollCreatedEvent>,
+    #[prost(message, repeated, tag="5")]
+    pub candidate_added_event_list: ::prost::alloc::vec::Vec<CandidateAddedEvent>,
+    #[prost(message, repeated, tag="6")]
+    pub vote_cast_event_list: ::prost::alloc::vec::Vec<VoteCastEvent>,
+    #[prost(message, repeated, tag="7")]
+    pub approval_vote_cast_event_list: ::prost::alloc::vec::Vec<ApprovalVoteCastEvent>,
+    #[prost(message, repeated, tag="8")]
+    pub ballot_cast_event_list: ::prost::alloc::vec::Vec<BallotCastEvent>,
+    #[prost(message, repeated, tag="9")]
+    pub vote_changed_event_list: ::prost::alloc::vec::Vec<VoteChangedEvent>,
+    #[prost(message, repeated, tag="10")]
+    pub vote_retracted_event_list: ::prost::alloc::vec::Vec<VoteRetractedEvent>,
+    #[prost(message, repeated, tag="11")]
+    pub contest_candidate_added_event_list: ::prost::alloc::vec::Vec<ContestCandidateAddedEvent>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct AddCandidateInstruction {
+pub struct PollCreatedEvent {
     #[prost(string, tag="1")]
     pub trx_hash: ::prost::alloc::string::String,
     #[prost(string, tag="2")]
-    pub candidate_name: ::prost::alloc::string::String,
+    pub poll: ::prost::alloc::string::String,
     #[prost(string, tag="3")]
-    pub acct_signer: ::prost::alloc::string::String,
+    pub authority: ::prost::alloc::string::String,
     #[prost(string, tag="4")]
-    pub acct_poll_account: ::prost::alloc::string::String,
+    pub name: ::prost::alloc::string::String,
     #[prost(string, tag="5")]
-    pub acct_candidate_account: ::prost::alloc::string::String,
+    pub description: ::prost::alloc::string::String,
+    #[prost(uint64, tag="6")]
+    pub start_time: u64,
+    #[prost(uint64, tag="7")]
+    pub end_time: u64,
+    #[prost(int64, tag="8")]
+    pub timestamp: i64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct InitializePollInstruction {
+pub struct CandidateAddedEvent {
     #[prost(string, tag="1")]
     pub trx_hash: ::prost::alloc::string::String,
     #[prost(string, tag="2")]
+    pub poll: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub candidate: ::prost::alloc::string::String,
+    #[prost(uint32, tag="4")]
+    pub candidate_index: u32,
+    #[prost(string, tag="5")]
     pub name: ::prost::alloc::string::String,
+    #[prost(int64, tag="6")]
+    pub timestamp: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VoteCastEvent {
+    #[prost(string, tag="1")]
+    pub trx_hash: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub poll: ::prost::alloc::string::String,
     #[prost(string, tag="3")]
-    pub description: ::prost::alloc::string::String,
-    #[prost(uint64, tag="4")]
-    pub start_time: u64,
+    pub candidate: ::prost::alloc::string::String,
+    #[prost(uint32, tag="4")]
+    pub candidate_index: u32,
+    #[prost(string, tag="5")]
+    pub voter: ::prost::alloc::string::String,
+    #[prost(uint64, tag="6")]
+    pub votes: u64,
+    #[prost(int64, tag="7")]
+    pub timestamp: i64,
+    /// 事件在交易中的位置，同一投票者可能有多条投票记录（多个 NFT、多次二次方投票）
+    #[prost(uint32, tag="8")]
+    pub event_index: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApprovalVoteCastEvent {
+    #[prost(string, tag="1")]
+    pub trx_hash: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub poll: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub voter: ::prost::alloc::string::String,
+    /// 所选的全部候选人账户，第一个同时由 VoteCast 事件记录
+    #[prost(string, repeated, tag="4")]
+    pub candidates: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
     #[prost(uint64, tag="5")]
-    pub end_time: u64,
+    pub weight: u64,
+    #[prost(int64, tag="6")]
+    pub timestamp: i64,
+    #[prost(uint32, tag="7")]
+    pub event_index: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BallotCastEvent {
+    #[prost(string, tag="1")]
+    pub trx_hash: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub poll: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub voter: ::prost::alloc::string::String,
+    #[prost(uint32, repeated, tag="4")]
+    pub contests: ::prost::alloc::vec::Vec<u32>,
+    #[prost(string, repeated, tag="5")]
+    pub candidates: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(uint64, tag="6")]
+    pub weight: u64,
+    #[prost(int64, tag="7")]
+    pub timestamp: i64,
+    #[prost(uint32, tag="8")]
+    pub event_index: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VoteChangedEvent {
+    #[prost(string, tag="1")]
+    pub trx_hash: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub poll: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub previous_candidate: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub candidate: ::prost::alloc::string::String,
+    #[prost(uint32, tag="5")]
+    pub candidate_index: u32,
     #[prost(string, tag="6")]
-    pub acct_signer: ::prost::alloc::string::String,
-    #[prost(string, tag="7")]
-    pub acct_poll_account: ::prost::alloc::string::String,
+    pub voter: ::prost::alloc::string::String,
+    #[prost(int64, tag="7")]
+    pub timestamp: i64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct VoteInstruction {
+pub struct VoteRetractedEvent {
     #[prost(string, tag="1")]
     pub trx_hash: ::prost::alloc::string::String,
     #[prost(string, tag="2")]
-    pub acct_signer: ::prost::alloc::string::String,
+    pub poll: ::prost::alloc::string::String,
     #[prost(string, tag="3")]
-    pub acct_poll_account: ::prost::alloc::string::String,
+    pub candidate: ::prost::alloc::string::String,
     #[prost(string, tag="4")]
-    pub acct_candidate_account: ::prost::alloc::string::String,
-    #[prost(string, tag="5")]
-    pub acct_voter_receipt: ::prost::alloc::string::String,
+    pub voter: ::prost::alloc::string::String,
+    #[prost(uint64, tag="5")]
+    pub votes: u64,
+    #[prost(int64, tag="6")]
+    pub timestamp: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ContestCandidateAddedEvent {
+    #[prost(string, tag="1")]
+    pub trx_hash: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub poll: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub contest: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub candidate: ::prost::alloc::string::String,
+    #[prost(uint32, tag="5")]
+    pub candidate_index: u32,
+    #[prost(string, tag="6")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(int64, tag="7")]
+    pub timestamp: i64,
 }
 // @@protoc_insertion_point(module)